@@ -0,0 +1,73 @@
+//! Describing a borrowed vs. an owned DataFrame in a loop
+//!
+//! `describe_with` clones the frame to build the lazy query;
+//! `describe_owned` hands it over instead. The allocation counts of one call
+//! of each are printed before the timings.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use criterion::{BatchSize, Criterion, criterion_group, criterion_main};
+use polars::prelude::*;
+
+use describe_df::{Describable, DescribeOptions};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+// SAFETY: forwards to the system allocator, only counting calls
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::Relaxed);
+    f();
+    ALLOCATIONS.load(Ordering::Relaxed) - before
+}
+
+fn wide_frame() -> DataFrame {
+    let columns: Vec<Column> = (0..1_000)
+        .map(|i| Column::new(format!("c{i}").into(), [i, i + 1, i + 2]))
+        .collect();
+    DataFrame::new(columns).unwrap()
+}
+
+fn bench_owned_describe(c: &mut Criterion) {
+    let opts = DescribeOptions::new();
+    let df = wide_frame();
+    // Clone outside the measured call, as a caller handing over its frame would
+    let owned = df.clone();
+    eprintln!(
+        "allocations per call: describe_with {}, describe_owned {}",
+        allocations(|| drop(df.describe_with(&opts).unwrap())),
+        allocations(|| drop(owned.describe_owned(&opts).unwrap())),
+    );
+
+    let mut group = c.benchmark_group("describe_1000_columns");
+    group.bench_function("describe_with", |b| {
+        b.iter(|| df.describe_with(&opts).unwrap());
+    });
+    group.bench_function("describe_owned", |b| {
+        b.iter_batched(
+            || df.clone(),
+            |frame| frame.describe_owned(&opts).unwrap(),
+            BatchSize::SmallInput,
+        );
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_owned_describe);
+criterion_main!(benches);
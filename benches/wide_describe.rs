@@ -0,0 +1,41 @@
+//! Describing a wide (5,000 columns x 1,000 rows) frame
+//!
+//! `in_memory` takes the batched, parallel aggregation used for DataFrames;
+//! `lazy_batched` wraps the same data in a lazy query, which is aggregated
+//! in batches collected one after the other like any scan, and
+//! `single_select` aggregates that query in one select.
+
+use criterion::{Criterion, criterion_group, criterion_main};
+use polars::prelude::*;
+
+use describe_df::{Describable, DescribeOptions};
+
+fn wide_frame() -> DataFrame {
+    let columns: Vec<Column> = (0..5_000)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let values: Vec<f64> = (0..1_000).map(|row| ((row * 7 + i) % 113) as f64).collect();
+            Column::new(format!("c{i}").into(), values)
+        })
+        .collect();
+    DataFrame::new(columns).unwrap()
+}
+
+fn bench_wide_describe(c: &mut Criterion) {
+    let df = wide_frame();
+    let mut group = c.benchmark_group("describe_5000_columns");
+    group.sample_size(10);
+    group.bench_function("in_memory", |b| b.iter(|| df.describe(None).unwrap()));
+    let lazy = df.clone().lazy().select([all().as_expr()]);
+    group.bench_function("lazy_batched", |b| {
+        b.iter(|| lazy.describe_with(&DescribeOptions::new()).unwrap())
+    });
+    group.bench_function("single_select", |b| {
+        let opts = DescribeOptions::new().batch_columns(usize::MAX);
+        b.iter(|| lazy.describe_with(&opts).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_wide_describe);
+criterion_main!(benches);
@@ -1,11 +1,11 @@
-//! Example usage of the describe functionality
+//! Basic describe() on DataFrames and LazyFrames of mixed dtypes
+//!
+//! Run with `cargo run --example basic`
 
 use anyhow::Result;
 use polars::prelude::*;
 
-// Include the describe module
-mod describe;
-use describe::Describable;
+use describe_df::Describable;
 
 fn main() -> Result<()> {
     // Create a sample DataFrame with different data types
@@ -69,4 +69,4 @@ fn main() -> Result<()> {
     println!("{}", time_stats);
 
     Ok(())
-}
\ No newline at end of file
+}
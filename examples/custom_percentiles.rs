@@ -0,0 +1,28 @@
+//! Custom percentiles and statistics on synthetic taxi trips
+//!
+//! Run with `cargo run --example custom_percentiles`
+
+use anyhow::Result;
+
+use describe_df::samples::TaxiLike;
+use describe_df::{Describable, DescribeOptions, Statistic};
+
+fn main() -> Result<()> {
+    let trips = TaxiLike::new(10_000).null_rate(0.1).build()?;
+
+    // Tail percentiles through the `describe()` shorthand
+    println!("=== describe() with 1%, 50%, 99% and 99.9% ===");
+    println!("{}\n", trips.describe(Some(vec![0.01, 0.5, 0.99, 0.999]))?);
+
+    // The same percentiles with a few optional statistics, numeric columns only
+    println!("=== describe_with() on the fare columns ===");
+    let opts = DescribeOptions::new()
+        .percentiles(vec![0.01, 0.5, 0.99, 0.999])
+        .columns(["trip_distance", "fare_amount"])
+        .statistic(Statistic::Iqr)
+        .statistic(Statistic::ZeroCount)
+        .statistic(Statistic::OutlierCount);
+    println!("{}", trips.describe_with(&opts)?);
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+//! Describe every group of a frame
+//!
+//! Run with `cargo run --example grouped`
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use describe_df::samples::taxi_like;
+use describe_df::{Describable, DescribableGroupBy, DescribeOptions};
+
+fn main() -> Result<()> {
+    let trips = taxi_like(5_000)?.select(["vendor", "payment_type", "fare_amount"])?;
+
+    // One block of statistics per vendor, then the whole frame as "__all__"
+    println!("=== describe_partitioned() by vendor ===");
+    println!(
+        "{}\n",
+        trips.describe_partitioned(&["vendor"], Some(vec![0.5]))?
+    );
+
+    // The same through a lazy group-by, on two keys
+    println!("=== group_by([vendor, payment_type]).describe() ===");
+    let opts = DescribeOptions::new().percentiles(vec![0.1, 0.9]);
+    let grouped = trips
+        .lazy()
+        .group_by([col("vendor"), col("payment_type")])
+        .describe(&opts)?;
+    println!("{grouped}");
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//! Describe a CSV file without loading it first
+//!
+//! The bundled `examples/data/taxi_like.csv` is `samples::taxi_like(200)`
+//! written as CSV. Run with `cargo run --example lazy_scan`, or pass the
+//! path of another CSV file.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use describe_df::{Describable, DescribeOptions, describe_csv};
+
+fn main() -> Result<()> {
+    let path = std::env::args().nth(1).unwrap_or_else(|| {
+        concat!(env!("CARGO_MANIFEST_DIR"), "/examples/data/taxi_like.csv").to_string()
+    });
+
+    // One call: scan, infer the schema and describe
+    println!("=== describe_csv({path}) ===");
+    println!("{}\n", describe_csv(&path, &DescribeOptions::new())?);
+
+    // A lazy scan keeps filters and projections ahead of the aggregation
+    println!("=== card payments over two miles ===");
+    let long_card_trips = LazyCsvReader::new(PlPath::new(&path))
+        .finish()?
+        .filter(
+            col("payment_type")
+                .eq(lit("card"))
+                .and(col("trip_distance").gt(lit(2.0))),
+        )
+        .select([col("trip_distance"), col("fare_amount")]);
+    println!("{}", long_card_trips.describe(None)?);
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! `describe-df <file>`: print descriptive statistics of a CSV, Parquet,
+//! NDJSON or Arrow IPC file
+
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use anyhow::{Context, Result};
+use clap::{Parser, ValueEnum};
+use polars::prelude::*;
+
+use describe_df::{Describable, DescribeOptions, LabelStyle};
+
+#[derive(Parser)]
+#[command(name = "describe-df", version, about)]
+struct Args {
+    /// File to describe
+    path: PathBuf,
+
+    /// File format; detected from the extension when not given
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Comma-separated percentiles between 0 and 1, e.g. 0.1,0.5,0.9
+    #[arg(long, value_delimiter = ',')]
+    percentiles: Option<Vec<f64>>,
+
+    /// Comma-separated columns to describe (all columns by default)
+    #[arg(long, value_delimiter = ',')]
+    columns: Option<Vec<String>>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Output::Table)]
+    output: Output,
+
+    /// One row per described column instead of one row per statistic
+    /// (table and csv output only)
+    #[arg(long)]
+    transpose: bool,
+
+    /// Spelling of the statistic labels: `25%` (human) or `p25` (snake)
+    #[arg(long, value_enum, default_value_t = Labels::Human)]
+    labels: Labels,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Csv,
+    Parquet,
+    Ndjson,
+    Ipc,
+}
+
+impl Format {
+    fn from_extension(path: &Path) -> Result<Self> {
+        let extension = path
+            .extension()
+            .and_then(|ext| ext.to_str())
+            .map(str::to_ascii_lowercase);
+        match extension.as_deref() {
+            Some("csv") => Ok(Format::Csv),
+            Some("parquet" | "pq") => Ok(Format::Parquet),
+            Some("ndjson" | "jsonl") => Ok(Format::Ndjson),
+            Some("arrow" | "ipc" | "feather") => Ok(Format::Ipc),
+            _ => Err(anyhow::anyhow!(
+                "cannot detect the format of {}; pass --format",
+                path.display()
+            )),
+        }
+    }
+
+    fn scan(self, path: &Path) -> PolarsResult<LazyFrame> {
+        let path = PlPath::Local(Arc::from(path));
+        match self {
+            Format::Csv => LazyCsvReader::new(path).finish(),
+            Format::Parquet => LazyFrame::scan_parquet(path, ScanArgsParquet::default()),
+            Format::Ndjson => LazyJsonLineReader::new(path).finish(),
+            Format::Ipc => LazyFrame::scan_ipc(path, ScanArgsIpc::default()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Output {
+    Table,
+    Csv,
+    Json,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Labels {
+    Human,
+    Snake,
+}
+
+fn main() -> ExitCode {
+    // Print the table without Polars' row and column truncation
+    for var in ["POLARS_FMT_MAX_ROWS", "POLARS_FMT_MAX_COLS"] {
+        if std::env::var_os(var).is_none() {
+            // SAFETY: no other thread exists yet
+            unsafe { std::env::set_var(var, "-1") };
+        }
+    }
+
+    let args = Args::parse();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {err:#}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run(args: &Args) -> Result<()> {
+    if args.transpose && matches!(args.output, Output::Json | Output::Markdown) {
+        return Err(anyhow::anyhow!(
+            "--transpose only applies to table and csv output"
+        ));
+    }
+    if !args.path.is_file() {
+        return Err(anyhow::anyhow!("no such file: {}", args.path.display()));
+    }
+    let format = match args.format {
+        Some(format) => format,
+        None => Format::from_extension(&args.path)?,
+    };
+
+    let mut lazy_frame = format
+        .scan(&args.path)
+        .with_context(|| format!("failed to read {}", args.path.display()))?;
+    if let Some(columns) = &args.columns {
+        lazy_frame = lazy_frame.select(
+            columns
+                .iter()
+                .map(|name| col(name.as_str()))
+                .collect::<Vec<_>>(),
+        );
+    }
+    let mut opts = DescribeOptions::new()
+        .label_style(match args.labels {
+            Labels::Human => LabelStyle::Human,
+            Labels::Snake => LabelStyle::Snake,
+        })
+        .on_warning(|message| eprintln!("warning: {message}"));
+    if let Some(percentiles) = &args.percentiles {
+        opts = opts.percentiles(percentiles.clone());
+    }
+
+    let describe_error = || format!("failed to describe {}", args.path.display());
+    let rendered = match args.output {
+        Output::Json => lazy_frame
+            .describe_json(args.percentiles.clone(), true)
+            .with_context(describe_error)?,
+        Output::Markdown => lazy_frame
+            .describe_markdown(&opts, None)
+            .with_context(describe_error)?,
+        Output::Table | Output::Csv => {
+            let mut stats = lazy_frame
+                .describe_with(&opts)
+                .with_context(describe_error)?;
+            if args.transpose {
+                stats = transpose(&stats)?;
+            }
+            if args.output == Output::Csv {
+                let mut buffer = Vec::new();
+                CsvWriter::new(&mut buffer).finish(&mut stats)?;
+                String::from_utf8(buffer)?
+            } else {
+                stats.to_string()
+            }
+        }
+    };
+
+    let mut stdout = std::io::stdout().lock();
+    writeln!(stdout, "{}", rendered.trim_end())?;
+    Ok(())
+}
+
+/// Turn the statistic rows into columns: one row per described column,
+/// led by a `column` column
+fn transpose(stats: &DataFrame) -> Result<DataFrame> {
+    let labels = stats.column("statistic")?.str()?;
+    let described = &stats.get_columns()[1..];
+
+    let names: Vec<&str> = described.iter().map(|c| c.name().as_str()).collect();
+    let mut columns = vec![Column::new("column".into(), names)];
+    for (row, label) in labels.into_iter().enumerate() {
+        let values = described
+            .iter()
+            .map(|c| Ok(c.str()?.get(row)))
+            .collect::<Result<Vec<Option<&str>>>>()?;
+        columns.push(Column::new(label.unwrap_or_default().into(), values));
+    }
+    DataFrame::new(columns).map_err(Into::into)
+}
@@ -0,0 +1,728 @@
+//! Output compatible with the Python `describe()` implementations
+//!
+//! `Compat::PyPolars` reproduces `pl.DataFrame.describe()` of py-polars 1.33
+//! (the release built on the same Polars engine as this crate's Polars 0.51):
+//! - percentiles are sorted and computed with `nearest` interpolation, and
+//!   labelled like Python's `f"{p * 100:g}%"`
+//! - numeric, boolean, null and nested columns become Float64 columns (counts
+//!   included, booleans' min/max as 0.0/1.0); every other column becomes a
+//!   String column holding Python's `str()` of each value
+//! - temporal columns get a mean and percentiles; Categorical and Enum
+//!   columns get no min/max
+//!
+//! `Compat::Pandas` reproduces pandas' `df.describe(include="all")`:
+//! - rows `count`, `unique`, `top`, `freq`, `mean`, `std`, `min`, the
+//!   percentiles and `max`, leaving out the `unique`/`top`/`freq` rows when
+//!   every column is numeric and the `mean`..`max` rows when none is
+//! - numeric, datetime and duration columns get count, mean, std (not for
+//!   datetimes), min, percentiles and max; dates are described as midnight
+//!   timestamps, like pandas' `datetime64`
+//! - every other column (strings, booleans, categoricals, times, ...) gets
+//!   count, the number of distinct values, the most frequent value (`top`,
+//!   ties going to the value seen first) and its count (`freq`)
+//! - percentiles are sorted, always include the median and use linear
+//!   interpolation; statistics that don't apply render as `NaN`
+//!
+//! Known differences: time zone aware datetimes are rendered in UTC
+//! (`+00:00`) whatever their zone, and describe options other than
+//! `percentiles`, `columns` and the strict/structural checks are ignored.
+
+use anyhow::Result;
+#[cfg(feature = "temporal")]
+use chrono::{DateTime, NaiveDate, NaiveTime, Timelike};
+use polars::prelude::*;
+
+use crate::describe::{resolved_percentiles, select_columns, styled_percentile_label};
+use crate::error::DescribeError;
+use crate::options::{DescribeOptions, LabelStyle};
+
+/// Which describe implementation the output of `describe_with()` mimics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Compat {
+    /// This crate's own layout: every column rendered as strings
+    #[default]
+    Native,
+    /// `pl.DataFrame.describe()` of py-polars 1.33, value for value
+    PyPolars,
+    /// pandas' `df.describe(include="all")`, with `unique`/`top`/`freq`
+    /// rows instead of `null_count`
+    Pandas,
+}
+
+pub(crate) fn describe_pypolars_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let (lazy_frame, schema) = select_columns(lazy_frame, opts)?;
+    if schema.is_empty() {
+        return Err(DescribeError::EmptySchema.into());
+    }
+
+    let mut percentiles = resolved_percentiles(opts)?;
+    percentiles.sort_by(f64::total_cmp);
+
+    let mut statistic: Vec<String> = ["count", "null_count", "mean", "std", "min"]
+        .iter()
+        .map(|s| (*s).to_string())
+        .collect();
+    statistic.extend(
+        percentiles
+            .iter()
+            .map(|p| compat_percentile_label(*p, opts.label_style)),
+    );
+    statistic.push("max".to_string());
+
+    let exprs: Vec<Expr> = schema
+        .iter()
+        .flat_map(|(name, dtype)| pypolars_exprs(name, dtype, &percentiles))
+        .collect();
+    let metrics = opts.engine.collect(lazy_frame.select(exprs))?;
+
+    let n_metrics = statistic.len();
+    let mut columns = vec![Column::new("statistic".into(), statistic)];
+    for (i, (name, dtype)) in schema.iter().enumerate() {
+        let values = metrics.get_columns()[i * n_metrics..(i + 1) * n_metrics]
+            .iter()
+            .map(|c| c.get(0))
+            .collect::<PolarsResult<Vec<AnyValue>>>()?;
+
+        let column = if has_numeric_result(dtype) {
+            let floats: Vec<Option<f64>> = values.iter().map(AnyValue::extract).collect();
+            Column::new(name.clone(), floats)
+        } else {
+            let strings: Vec<Option<String>> = values.iter().map(python_str).collect();
+            Column::new(name.clone(), strings)
+        };
+        columns.push(column);
+    }
+
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+/// How pandas describes a column
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PandasKind {
+    Numeric,
+    Timestamp,
+    Timedelta,
+    /// Described with `unique`, `top` and `freq`
+    Object,
+}
+
+impl PandasKind {
+    fn of(dtype: &DataType) -> Self {
+        match dtype {
+            dtype if dtype.is_numeric() => PandasKind::Numeric,
+            DataType::Date | DataType::Datetime(..) if cfg!(feature = "temporal") => {
+                PandasKind::Timestamp
+            }
+            DataType::Duration(_) if cfg!(feature = "temporal") => PandasKind::Timedelta,
+            _ => PandasKind::Object,
+        }
+    }
+}
+
+/// Statistic rows of the pandas layout
+#[derive(Clone, Copy, PartialEq)]
+enum PandasRow {
+    Count,
+    Unique,
+    Top,
+    Freq,
+    Mean,
+    Std,
+    Min,
+    Percentile(f64),
+    Max,
+}
+
+impl PandasRow {
+    fn label(self, style: LabelStyle) -> String {
+        match self {
+            PandasRow::Count => "count".to_string(),
+            PandasRow::Unique => "unique".to_string(),
+            PandasRow::Top => "top".to_string(),
+            PandasRow::Freq => "freq".to_string(),
+            PandasRow::Mean => "mean".to_string(),
+            PandasRow::Std => "std".to_string(),
+            PandasRow::Min => "min".to_string(),
+            PandasRow::Percentile(p) => compat_percentile_label(p, style),
+            PandasRow::Max => "max".to_string(),
+        }
+    }
+}
+
+pub(crate) fn describe_pandas_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let (lazy_frame, schema) = select_columns(lazy_frame, opts)?;
+    if schema.is_empty() {
+        return Err(DescribeError::EmptySchema.into());
+    }
+
+    // pandas always reports the median
+    let mut percentiles = resolved_percentiles(opts)?;
+    if !percentiles.contains(&0.5) {
+        percentiles.push(0.5);
+    }
+    percentiles.sort_by(f64::total_cmp);
+
+    let kinds: Vec<PandasKind> = schema.iter_values().map(PandasKind::of).collect();
+    let mut rows = vec![PandasRow::Count];
+    if kinds.contains(&PandasKind::Object) {
+        rows.extend([PandasRow::Unique, PandasRow::Top, PandasRow::Freq]);
+    }
+    if kinds.iter().any(|kind| kind != &PandasKind::Object) {
+        rows.extend([PandasRow::Mean, PandasRow::Std, PandasRow::Min]);
+        rows.extend(percentiles.iter().map(|p| PandasRow::Percentile(*p)));
+        rows.push(PandasRow::Max);
+    }
+
+    let exprs: Vec<Expr> = schema
+        .iter()
+        .zip(&kinds)
+        .flat_map(|((name, dtype), kind)| {
+            rows.iter().enumerate().map(move |(i, row)| {
+                pandas_expr(name, dtype, *kind, *row).alias(format!("{i}:{name}"))
+            })
+        })
+        .collect();
+    let metrics = opts.engine.collect(lazy_frame.select(exprs))?;
+
+    let statistic: Vec<String> = rows.iter().map(|row| row.label(opts.label_style)).collect();
+    let mut columns = vec![Column::new("statistic".into(), statistic)];
+    for (i, name) in schema.iter_names().enumerate() {
+        let values = metrics.get_columns()[i * rows.len()..(i + 1) * rows.len()]
+            .iter()
+            .map(|c| c.get(0).map(|v| pandas_str(&v)))
+            .collect::<PolarsResult<Vec<String>>>()?;
+        columns.push(Column::new(name.clone(), values));
+    }
+
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+/// The expression computing one statistic row of one column, or a null
+/// literal when pandas leaves the cell empty
+fn pandas_expr(name: &str, dtype: &DataType, kind: PandasKind, row: PandasRow) -> Expr {
+    let values = match kind {
+        PandasKind::Numeric => col(name).cast(DataType::Float64),
+        PandasKind::Timestamp if dtype == &DataType::Date => {
+            col(name).cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+        }
+        _ => col(name),
+    };
+    // Temporal percentiles interpolate the physical values
+    let temporal_quantile = |p: f64| {
+        let out_dtype = match dtype {
+            DataType::Date => DataType::Datetime(TimeUnit::Milliseconds, None),
+            dtype => dtype.clone(),
+        };
+        values
+            .clone()
+            .to_physical()
+            .cast(DataType::Float64)
+            .quantile(lit(p), QuantileMethod::Linear)
+            .cast(DataType::Int64)
+            .cast(out_dtype)
+    };
+    // Occurrences of each row's value, for top/freq
+    let occurrences = || col(name).count().over([col(name)]);
+
+    match (row, kind) {
+        (PandasRow::Count, _) => col(name).count(),
+        (PandasRow::Unique, PandasKind::Object) => col(name).drop_nulls().n_unique(),
+        (PandasRow::Top, PandasKind::Object) => col(name)
+            .sort_by(
+                [occurrences()],
+                SortMultipleOptions::default()
+                    .with_order_descending(true)
+                    .with_maintain_order(true),
+            )
+            .drop_nulls()
+            .first(),
+        (PandasRow::Freq, PandasKind::Object) => occurrences().max(),
+        (PandasRow::Mean, PandasKind::Numeric | PandasKind::Timestamp | PandasKind::Timedelta) => {
+            values.mean()
+        }
+        (PandasRow::Std, PandasKind::Numeric) => values.std(1),
+        (PandasRow::Std, PandasKind::Timedelta) => values
+            .to_physical()
+            .cast(DataType::Float64)
+            .std(1)
+            .cast(DataType::Int64)
+            .cast(dtype.clone()),
+        (PandasRow::Min, PandasKind::Numeric | PandasKind::Timestamp | PandasKind::Timedelta) => {
+            values.min()
+        }
+        (PandasRow::Max, PandasKind::Numeric | PandasKind::Timestamp | PandasKind::Timedelta) => {
+            values.max()
+        }
+        (PandasRow::Percentile(p), PandasKind::Numeric) => {
+            values.quantile(lit(p), QuantileMethod::Linear)
+        }
+        (PandasRow::Percentile(p), PandasKind::Timestamp | PandasKind::Timedelta) => {
+            temporal_quantile(p)
+        }
+        _ => lit(NULL),
+    }
+}
+
+/// Render a cell the way pandas prints it; missing values are `NaN`
+fn pandas_str(val: &AnyValue<'_>) -> String {
+    match val {
+        AnyValue::Null => "NaN".to_string(),
+        AnyValue::Float64(v) if v.is_nan() => "NaN".to_string(),
+        #[cfg(feature = "temporal")]
+        AnyValue::Duration(v, unit) => pandas_timedelta(to_micros(*v, *unit)),
+        val => python_str(val).unwrap_or_else(|| "NaN".to_string()),
+    }
+}
+
+/// `str(pd.Timedelta)`, e.g. `1 days 02:03:04` or `0 days 00:00:01.500000`
+#[cfg(feature = "temporal")]
+fn pandas_timedelta(micros: i64) -> String {
+    const MICROS_PER_DAY: i64 = 86_400_000_000;
+    let days = micros.div_euclid(MICROS_PER_DAY);
+    let rest = micros.rem_euclid(MICROS_PER_DAY);
+    let (secs, frac) = (rest / 1_000_000, rest % 1_000_000);
+
+    let mut rendered = format!(
+        "{days} days {:02}:{:02}:{:02}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    );
+    if frac != 0 {
+        rendered.push_str(&format!(".{frac:06}"));
+    }
+    rendered
+}
+
+/// Columns whose statistics Python converts with `float()` rather than `str()`
+fn has_numeric_result(dtype: &DataType) -> bool {
+    dtype.is_numeric() || dtype.is_nested() || matches!(dtype, DataType::Null | DataType::Boolean)
+}
+
+/// The metric expressions of one column, in statistic row order
+fn pypolars_exprs(name: &str, dtype: &DataType, percentiles: &[f64]) -> Vec<Expr> {
+    let is_numeric = dtype.is_numeric();
+    let is_temporal = !is_numeric && dtype.is_temporal();
+    let skip_minmax = dtype.is_nested()
+        || dtype.is_categorical()
+        || dtype.is_enum()
+        || matches!(dtype, DataType::Null | DataType::Unknown(_));
+    let null = || lit(NULL);
+
+    // Casting bools and decimals to Float64 matches Python's `float(v)` and
+    // lets the quantiles run on decimals
+    let values = if is_numeric || dtype == &DataType::Boolean {
+        col(name).cast(DataType::Float64)
+    } else {
+        col(name)
+    };
+
+    let mean = if is_numeric || is_temporal || dtype == &DataType::Boolean {
+        values.clone().mean()
+    } else {
+        null()
+    };
+    let std = if is_numeric {
+        values.clone().std(1)
+    } else {
+        null()
+    };
+    let (min, max) = if skip_minmax {
+        (null(), null())
+    } else {
+        (values.clone().min(), values.clone().max())
+    };
+
+    let mut exprs = vec![col(name).count(), col(name).null_count(), mean, std, min];
+    exprs.extend(percentiles.iter().map(|p| {
+        if is_numeric {
+            values.clone().quantile(lit(*p), QuantileMethod::Nearest)
+        } else if is_temporal {
+            col(name)
+                .to_physical()
+                .quantile(lit(*p), QuantileMethod::Nearest)
+                .cast(dtype.clone())
+        } else {
+            null()
+        }
+    }));
+    exprs.push(max);
+
+    exprs
+        .into_iter()
+        .enumerate()
+        .map(|(i, expr)| expr.alias(format!("{i}:{name}")))
+        .collect()
+}
+
+/// Percentile label of the compat layouts: Python's, or the identifier of
+/// `LabelStyle::Snake`
+fn compat_percentile_label(p: f64, style: LabelStyle) -> String {
+    match style {
+        LabelStyle::Human => pypolars_percentile_label(p),
+        LabelStyle::Snake => styled_percentile_label(p, style),
+    }
+}
+
+/// Python's `f"{p * 100:g}%"`: six significant digits, trailing zeros dropped
+fn pypolars_percentile_label(p: f64) -> String {
+    let pct = p * 100.0;
+    if pct == 0.0 {
+        return "0%".to_string();
+    }
+    #[allow(clippy::cast_possible_truncation)]
+    let exponent = pct.abs().log10().floor() as i32;
+    let formatted = if exponent < -4 {
+        let scientific = format!("{pct:.5e}");
+        let (mantissa, exp) = scientific.split_once('e').unwrap_or((&scientific, "0"));
+        let exp: i32 = exp.parse().unwrap_or(0);
+        format!("{}e-{:02}", trim_zeros(mantissa), exp.abs())
+    } else {
+        #[allow(clippy::cast_sign_loss)]
+        let decimals = (5 - exponent).max(0) as usize;
+        trim_zeros(&format!("{pct:.decimals$}")).to_string()
+    };
+    format!("{formatted}%")
+}
+
+fn trim_zeros(number: &str) -> &str {
+    if number.contains('.') {
+        number.trim_end_matches('0').trim_end_matches('.')
+    } else {
+        number
+    }
+}
+
+/// Render a value like Python's `str()` of its py-polars conversion
+fn python_str(val: &AnyValue<'_>) -> Option<String> {
+    let rendered = match val {
+        AnyValue::Null | AnyValue::Struct(..) | AnyValue::StructOwned(..) => return None,
+        #[cfg(feature = "temporal")]
+        AnyValue::Date(days) => NaiveDate::from_epoch_days(*days)
+            .map_or_else(|| days.to_string(), |d| d.format("%Y-%m-%d").to_string()),
+        #[cfg(feature = "temporal")]
+        AnyValue::Datetime(v, unit, tz) => python_datetime(*v, *unit, tz.is_some()),
+        #[cfg(feature = "temporal")]
+        AnyValue::DatetimeOwned(v, unit, tz) => python_datetime(*v, *unit, tz.is_some()),
+        #[cfg(feature = "temporal")]
+        AnyValue::Time(ns) => python_time(*ns),
+        #[cfg(feature = "temporal")]
+        AnyValue::Duration(v, unit) => python_timedelta(to_micros(*v, *unit)),
+        AnyValue::Boolean(v) => if *v { "True" } else { "False" }.to_string(),
+        _ => match val.get_str() {
+            Some(s) => s.to_string(),
+            None => val.to_string(),
+        },
+    };
+    Some(rendered)
+}
+
+/// Python truncates every temporal value to microseconds
+#[cfg(feature = "temporal")]
+fn to_micros(v: i64, unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Nanoseconds => v.div_euclid(1_000),
+        TimeUnit::Microseconds => v,
+        TimeUnit::Milliseconds => v * 1_000,
+    }
+}
+
+/// `str(datetime)`: fractional seconds only when non-zero
+#[cfg(feature = "temporal")]
+fn python_datetime(v: i64, unit: TimeUnit, tz_aware: bool) -> String {
+    let Some(datetime) = DateTime::from_timestamp_micros(to_micros(v, unit)) else {
+        return v.to_string();
+    };
+    let pattern = if datetime.nanosecond() == 0 {
+        "%Y-%m-%d %H:%M:%S"
+    } else {
+        "%Y-%m-%d %H:%M:%S%.6f"
+    };
+    let mut rendered = datetime.naive_utc().format(pattern).to_string();
+    if tz_aware {
+        rendered.push_str("+00:00");
+    }
+    rendered
+}
+
+/// `str(time)`: fractional seconds only when non-zero
+#[cfg(feature = "temporal")]
+fn python_time(ns: i64) -> String {
+    let micros = ns.div_euclid(1_000);
+    let time = u32::try_from(micros.div_euclid(1_000_000))
+        .ok()
+        .zip(u32::try_from(micros.rem_euclid(1_000_000) * 1_000).ok())
+        .and_then(|(secs, nanos)| NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos));
+    match time {
+        Some(time) if time.nanosecond() == 0 => time.format("%H:%M:%S").to_string(),
+        Some(time) => time.format("%H:%M:%S%.6f").to_string(),
+        None => ns.to_string(),
+    }
+}
+
+/// `str(timedelta)`, e.g. `1 day, 2:03:04` or `-1 day, 23:59:59.500000`
+#[cfg(feature = "temporal")]
+fn python_timedelta(micros: i64) -> String {
+    const MICROS_PER_DAY: i64 = 86_400_000_000;
+    let days = micros.div_euclid(MICROS_PER_DAY);
+    let rest = micros.rem_euclid(MICROS_PER_DAY);
+    let (secs, frac) = (rest / 1_000_000, rest % 1_000_000);
+
+    let mut rendered = String::new();
+    if days != 0 {
+        let plural = if days.abs() == 1 { "" } else { "s" };
+        rendered.push_str(&format!("{days} day{plural}, "));
+    }
+    rendered.push_str(&format!(
+        "{}:{:02}:{:02}",
+        secs / 3_600,
+        secs % 3_600 / 60,
+        secs % 60
+    ));
+    if frac != 0 {
+        rendered.push_str(&format!(".{frac:06}"));
+    }
+    rendered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    /// The frame from the `pl.DataFrame.describe()` docstring of py-polars
+    /// 1.33, plus an all-null column
+    #[cfg(feature = "temporal")]
+    fn reference_frame() -> Result<DataFrame> {
+        let df = df! {
+            "float" => [1.0, 2.8, 3.0],
+            "int" => [Some(40), Some(50), None],
+            "bool" => [true, false, true],
+            "str" => ["zz", "xx", "yy"],
+            "date" => [18_262, 18_813, 19_357],
+        }?
+        .lazy()
+        .with_columns([col("date").cast(DataType::Date), lit(NULL).alias("null")])
+        .collect()?;
+        Ok(df)
+    }
+
+    #[cfg(feature = "temporal")]
+    fn assert_floats(stats: &DataFrame, name: &str, expected: &[Option<f64>]) -> Result<()> {
+        let actual: Vec<Option<f64>> = stats.column(name)?.f64()?.into_iter().collect();
+        assert_eq!(actual.len(), expected.len(), "{name}");
+        for (row, (a, e)) in actual.iter().zip(expected).enumerate() {
+            match (a, e) {
+                (Some(a), Some(e)) => assert!((a - e).abs() < 1e-6, "{name}[{row}]: {a} != {e}"),
+                _ => assert_eq!(a, e, "{name}[{row}]"),
+            }
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    fn assert_strings(stats: &DataFrame, name: &str, expected: &[Option<&str>]) -> Result<()> {
+        let actual: Vec<Option<&str>> = stats.column(name)?.str()?.into_iter().collect();
+        assert_eq!(actual, expected, "{name}");
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_pypolars_reference_output() -> Result<()> {
+        let opts = DescribeOptions::new().compat(Compat::PyPolars);
+        let stats = reference_frame()?.describe_with(&opts)?;
+
+        assert_strings(
+            &stats,
+            "statistic",
+            &[
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "25%",
+                "50%",
+                "75%",
+                "max",
+            ]
+            .map(Some),
+        )?;
+        assert_floats(
+            &stats,
+            "float",
+            &[3.0, 0.0, 2.266667, 1.101514, 1.0, 2.8, 2.8, 3.0, 3.0].map(Some),
+        )?;
+        assert_floats(
+            &stats,
+            "int",
+            &[2.0, 1.0, 45.0, 7.071068, 40.0, 40.0, 50.0, 50.0, 50.0].map(Some),
+        )?;
+        assert_floats(
+            &stats,
+            "bool",
+            &[
+                Some(3.0),
+                Some(0.0),
+                Some(0.666667),
+                None,
+                Some(0.0),
+                None,
+                None,
+                None,
+                Some(1.0),
+            ],
+        )?;
+        assert_strings(
+            &stats,
+            "str",
+            &[
+                Some("3"),
+                Some("0"),
+                None,
+                None,
+                Some("xx"),
+                None,
+                None,
+                None,
+                Some("zz"),
+            ],
+        )?;
+        assert_strings(
+            &stats,
+            "date",
+            &[
+                Some("3"),
+                Some("0"),
+                Some("2021-07-02 16:00:00"),
+                None,
+                Some("2020-01-01"),
+                Some("2021-07-05"),
+                Some("2021-07-05"),
+                Some("2022-12-31"),
+                Some("2022-12-31"),
+            ],
+        )?;
+        assert_floats(
+            &stats,
+            "null",
+            &[
+                Some(0.0),
+                Some(3.0),
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+                None,
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_pypolars_percentiles_sorted_and_labelled() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .compat(Compat::PyPolars)
+            .percentiles(vec![0.9, 0.333, 0.1]);
+        let stats = reference_frame()?.describe_with(&opts)?;
+        let labels: Vec<Option<&str>> = stats.column("statistic")?.str()?.into_iter().collect();
+        assert_eq!(labels[5..8], [Some("10%"), Some("33.3%"), Some("90%")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pandas_reference_output() -> Result<()> {
+        let df = df! {
+            "x" => [1.0, 2.0, 3.0, 4.0],
+            "n" => [Some(1), None, Some(3), Some(3)],
+            "s" => [Some("b"), Some("a"), Some("b"), None],
+            "flag" => [true, false, true, true],
+        }?;
+        let opts = DescribeOptions::new().compat(Compat::Pandas);
+        let stats = df.describe_with(&opts)?;
+
+        // pandas 2.2: df.describe(include="all")
+        let expected = df! {
+            "statistic" => ["count", "unique", "top", "freq", "mean", "std", "min", "25%", "50%", "75%", "max"],
+            "x" => ["4.0", "NaN", "NaN", "NaN", "2.5", "1.290994", "1.0", "1.75", "2.5", "3.25", "4.0"],
+            "n" => ["3.0", "NaN", "NaN", "NaN", "2.333333", "1.154701", "1.0", "2.0", "3.0", "3.0", "3.0"],
+            "s" => ["3", "2", "b", "2", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN"],
+            "flag" => ["4", "2", "True", "3", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN", "NaN"],
+        }?;
+        assert_eq!(stats.get_column_names(), expected.get_column_names());
+        for column in expected.get_columns() {
+            let actual = stats.column(column.name())?.str()?;
+            for (row, want) in column.str()?.into_iter().enumerate() {
+                let got = actual.get(row).unwrap_or_default();
+                let want = want.unwrap_or_default();
+                // pandas prints six decimals; compare floats numerically
+                match (got.parse::<f64>(), want.parse::<f64>()) {
+                    (Ok(g), Ok(w)) if !w.is_nan() => {
+                        assert!(
+                            (g - w).abs() < 1e-6,
+                            "{}[{row}]: {got} != {want}",
+                            column.name()
+                        )
+                    }
+                    _ => assert_eq!(got, want, "{}[{row}]", column.name()),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pandas_rows_follow_column_kinds() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .compat(Compat::Pandas)
+            .percentiles(vec![0.9, 0.1]);
+        let numeric = df! { "x" => [1, 2, 3] }?.describe_with(&opts)?;
+        let labels: Vec<Option<&str>> = numeric.column("statistic")?.str()?.into_iter().collect();
+        assert_eq!(
+            labels,
+            ["count", "mean", "std", "min", "10%", "50%", "90%", "max"].map(Some)
+        );
+
+        #[cfg(feature = "temporal")]
+        {
+            let days = df! { "d" => [0, 2] }?
+                .lazy()
+                .with_column(col("d").cast(DataType::Date))
+                .describe_with(&opts)?;
+            let values: Vec<Option<&str>> = days.column("d")?.str()?.into_iter().collect();
+            assert_eq!(values[1], Some("1970-01-02 00:00:00"));
+            assert_eq!(values[2], Some("NaN"));
+        }
+
+        let text = df! { "s" => ["a", "b", "b", "a"] }?.describe_with(&opts)?;
+        let values: Vec<Option<&str>> = text.column("s")?.str()?.into_iter().collect();
+        // Tie between "a" and "b": pandas keeps the value seen first
+        assert_eq!(values, ["4", "2", "a", "2"].map(Some));
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_python_str_of_temporal_values() {
+        assert_eq!(python_timedelta(90_061_000_000), "1 day, 1:01:01");
+        assert_eq!(python_timedelta(-500_000), "-1 day, 23:59:59.500000");
+        assert_eq!(python_time(3_723_000_000_000), "01:02:03");
+        assert_eq!(
+            python_datetime(1_500, TimeUnit::Milliseconds, false),
+            "1970-01-01 00:00:01.500000"
+        );
+        assert_eq!(pandas_timedelta(1_500_000), "0 days 00:00:01.500000");
+    }
+}
@@ -0,0 +1,197 @@
+//! Correlation and covariance matrices of the numeric columns
+//!
+//! Every pair of numeric columns is aggregated in a single lazy pass. Nulls
+//! are handled pairwise: a row counts for a pair when both of its values
+//! are present, so a null in one column doesn't shrink the other pairs.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+/// Correlation coefficient computed by `correlation_matrix`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CorrMethod {
+    /// Linear correlation of the values
+    #[default]
+    Pearson,
+    /// Pearson correlation of the ranks (ties get their average rank)
+    Spearman,
+}
+
+pub(crate) fn correlation_matrix_impl(
+    lazy_frame: &LazyFrame,
+    method: CorrMethod,
+) -> Result<DataFrame> {
+    pairwise_matrix(lazy_frame, |x, y| {
+        let r = match method {
+            CorrMethod::Pearson => pearson_corr(x, y),
+            CorrMethod::Spearman => spearman_rank_corr(x, y, true),
+        };
+        // Constant columns (zero variance) and NaNs give NaN; report null
+        when(r.clone().is_nan().not()).then(r).otherwise(lit(NULL))
+    })
+}
+
+pub(crate) fn covariance_matrix_impl(lazy_frame: &LazyFrame, ddof: u8) -> Result<DataFrame> {
+    pairwise_matrix(lazy_frame, |x, y| cov(x, y, ddof))
+}
+
+/// Square matrix with a leading `column` column, one row and one Float64
+/// column per numeric column, holding `pair(x, y)` computed over the rows
+/// where both columns are non-null
+///
+/// The matrix is assumed symmetric: each pair is computed once.
+pub(crate) fn pairwise_matrix(
+    lazy_frame: &LazyFrame,
+    pair: impl Fn(Expr, Expr) -> Expr,
+) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let names: Vec<&PlSmallStr> = schema
+        .iter()
+        .filter(|(_, dtype)| dtype.is_numeric())
+        .map(|(name, _)| name)
+        .collect();
+
+    let mut exprs = Vec::new();
+    for (i, a) in names.iter().enumerate() {
+        for b in &names[i..] {
+            let both = col((*a).clone())
+                .is_not_null()
+                .and(col((*b).clone()).is_not_null());
+            let values = |name: &PlSmallStr| {
+                col(name.clone())
+                    .cast(DataType::Float64)
+                    .filter(both.clone())
+            };
+            exprs.push(
+                pair(values(a), values(b))
+                    .cast(DataType::Float64)
+                    .alias(pair_alias(a, b)),
+            );
+        }
+    }
+
+    let pairs = if exprs.is_empty() {
+        DataFrame::empty()
+    } else {
+        lazy_frame.clone().select(exprs).collect()?
+    };
+
+    let mut columns = vec![Column::new(
+        "column".into(),
+        names.iter().map(|name| name.as_str()).collect::<Vec<_>>(),
+    )];
+    for (j, b) in names.iter().enumerate() {
+        let values = names
+            .iter()
+            .enumerate()
+            .map(|(i, a)| {
+                let alias = if i <= j {
+                    pair_alias(a, b)
+                } else {
+                    pair_alias(b, a)
+                };
+                Ok(pairs.column(&alias)?.f64()?.get(0))
+            })
+            .collect::<Result<Vec<Option<f64>>>>()?;
+        columns.push(Column::new((*b).clone(), values));
+    }
+
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+fn pair_alias(a: &str, b: &str) -> String {
+    format!("pair:{a}:{b}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Describable, DescribeOptions};
+
+    fn matrix_value(matrix: &DataFrame, row: &str, column: &str) -> Result<Option<f64>> {
+        let rows: Vec<Option<&str>> = matrix.column("column")?.str()?.into_iter().collect();
+        let i = rows.iter().position(|r| *r == Some(row)).unwrap();
+        Ok(matrix.column(column)?.f64()?.get(i))
+    }
+
+    fn assert_close(value: Option<f64>, expected: f64) {
+        let value = value.expect("correlation is not null");
+        assert!((value - expected).abs() < 1e-12, "{value} != {expected}");
+    }
+
+    #[test]
+    fn test_pearson_known_correlations() -> Result<()> {
+        let df = df! {
+            "x" => [1.0, 2.0, 3.0, 4.0],
+            "up" => [2, 4, 6, 8],
+            "down" => [4.0, 3.0, 2.0, 1.0],
+            "flat" => [1.0, -1.0, -1.0, 1.0],
+            "name" => ["a", "b", "c", "d"],
+        }?;
+        let matrix = df.correlation_matrix(CorrMethod::Pearson)?;
+
+        assert_eq!(matrix.shape(), (4, 5));
+        assert_close(matrix_value(&matrix, "x", "x")?, 1.0);
+        assert_close(matrix_value(&matrix, "x", "up")?, 1.0);
+        assert_close(matrix_value(&matrix, "down", "x")?, -1.0);
+        assert_close(matrix_value(&matrix, "x", "flat")?, 0.0);
+        assert_eq!(
+            matrix_value(&matrix, "up", "down")?,
+            matrix_value(&matrix, "down", "up")?
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_constant_column_and_pairwise_nulls() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(2.0), Some(3.0), Some(10.0)],
+            "y" => [Some(2.0), Some(4.0), Some(6.0), None],
+            "c" => [5.0, 5.0, 5.0, 5.0],
+        }?;
+        let matrix = df.lazy().correlation_matrix(CorrMethod::Pearson)?;
+
+        // The last row only drops out of the pairs involving y
+        assert_close(matrix_value(&matrix, "x", "y")?, 1.0);
+        assert_eq!(matrix_value(&matrix, "x", "c")?, None);
+        assert_eq!(matrix_value(&matrix, "c", "c")?, None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_covariance_diagonal_matches_describe_std() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(2.0), None, Some(7.0)],
+            "y" => [2.0, 1.0, 5.0, 10.0],
+        }?;
+        let matrix = df.covariance_matrix(1)?;
+        let report = df.describe_report(&DescribeOptions::new())?;
+
+        for name in ["x", "y"] {
+            let std = report.column(name).unwrap().std.unwrap();
+            let variance = matrix_value(&matrix, name, name)?.unwrap();
+            assert!((variance - std * std).abs() < 1e-12, "{name}");
+        }
+        // Rows with x present: x = 1, 2, 7 (mean 10/3), y = 2, 1, 10 (mean 13/3)
+        // sum of products of deviations = 49/9 + 40/9 + 187/9 = 276/9
+        assert_close(matrix_value(&matrix, "y", "x")?, 276.0 / 9.0 / 2.0);
+        // ddof = 0 divides by n instead
+        let population = df.covariance_matrix(0)?;
+        assert_close(matrix_value(&population, "x", "y")?, 276.0 / 9.0 / 3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_spearman_uses_ranks() -> Result<()> {
+        let df = df! {
+            "x" => [1.0, 2.0, 3.0, 4.0, 5.0],
+            "cubed" => [1.0, 8.0, 27.0, 64.0, 125.0],
+        }?;
+        let spearman = df.correlation_matrix(CorrMethod::Spearman)?;
+        let pearson = df.correlation_matrix(CorrMethod::Pearson)?;
+
+        assert_close(matrix_value(&spearman, "x", "cubed")?, 1.0);
+        assert!(matrix_value(&pearson, "x", "cubed")?.unwrap() < 0.99);
+        Ok(())
+    }
+}
@@ -7,17 +7,71 @@
 //! The implementation follows the Python polars pattern closely, avoiding
 //! unnecessary data collection when working with LazyFrames.
 
+use std::collections::BTreeMap;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+use std::path::Path;
+use std::time::Instant;
+
 use anyhow::Result;
+#[cfg(feature = "temporal")]
+use chrono::NaiveDate;
 use polars::prelude::*;
 
+use crate::compat::{Compat, describe_pandas_impl, describe_pypolars_impl};
+#[cfg(feature = "correlation")]
+use crate::correlation::{CorrMethod, correlation_matrix_impl, covariance_matrix_impl};
+use crate::duplicates::{DuplicatesReport, duplicates_report_impl};
+use crate::eav::describe_eav_impl;
+#[cfg(feature = "temporal")]
+use crate::ecdf::percentile_of_dates_impl;
+use crate::ecdf::percentile_of_impl;
+use crate::error::DescribeError;
+use crate::expressions::{describe_exprs_impl, describe_where_impl};
+use crate::format::{format_bytes, format_count, format_float, format_percent, format_value};
+use crate::frame::{FrameSummary, frame_summary_impl};
+use crate::frequency::{describe_string_impl, value_counts_report_impl};
+use crate::histogram::{compute_histograms, describe_histogram_impl};
+use crate::html::describe_html_impl;
+#[cfg(feature = "ipc")]
+use crate::io::describe_ipc_impl;
+#[cfg(feature = "csv")]
+use crate::io::describe_to_csv_impl;
+#[cfg(feature = "parquet")]
+use crate::io::describe_to_parquet_impl;
+#[cfg(feature = "serde")]
+use crate::json::describe_json_impl;
+use crate::ladder::describe_quantiles_impl;
+use crate::markdown::describe_markdown_impl;
+use crate::missing::{NullReport, null_report_impl};
+use crate::options::{
+    ColumnOrder, DEFAULT_PERCENTILES, DescribeOptions, Engine, LabelStyle, Progress,
+};
+use crate::partial::{PartialSummary, summarize_impl};
+use crate::partition::describe_partitioned_impl;
+use crate::pretty::describe_pretty_impl;
+use crate::report::{ColumnSummary, DescribeReport, StatValue};
+#[cfg(feature = "sampling")]
+use crate::sampling::describe_sampled_impl;
+use crate::sampling::describe_slice_impl;
+use crate::schema::describe_schema_impl;
+use crate::statistics::{Statistic, degenerate_columns_impl, quantile_input, statistic_expr};
+use crate::structs::describe_structs_impl;
+use crate::summation::{compensated_mean, compensated_std};
+use crate::validation::{Expectations, ValidationReport, validate_impl};
+use crate::weighted::{
+    WeightedValues, check_weights, describe_weighted_impl, validate_weight_column,
+    weight_check_exprs,
+};
+#[cfg(feature = "temporal")]
+use crate::window::describe_over_impl;
 
 /// Trait for types that can produce descriptive statistics
 pub trait Describable {
     /// Compute descriptive statistics
     ///
     /// # Arguments
-    /// * `percentiles` - Optional vector of percentiles to compute (values between 0.0 and 1.0)
-    ///                   Defaults to [0.25, 0.50, 0.75] if None
+    /// * `percentiles` - Optional vector of percentiles to compute (values between 0.0 and 1.0).
+    ///   Defaults to [0.25, 0.50, 0.75] if None
     ///
     /// # Returns
     /// A DataFrame containing statistics for each column:
@@ -29,11 +83,17 @@ pub trait Describable {
     /// - percentiles: requested percentiles
     /// - max: maximum value
     ///
+    /// When a List column is described, rows with list length statistics
+    /// follow (list_len_min, list_len_mean, list_len_max, list_elements,
+    /// list_null_elements); they are null for non-list columns. Binary
+    /// columns likewise add byte_len_min, byte_len_mean and byte_len_max.
+    ///
     /// # Example
     /// ```rust
     /// use polars::prelude::*;
-    /// use your_crate::Describable;
+    /// use describe_df::Describable;
     ///
+    /// # fn main() -> anyhow::Result<()> {
     /// let df = df! {
     ///     "ints" => [1, 2, 3, 4, 5],
     ///     "floats" => [1.0, 2.5, 3.0, 4.5, 5.0],
@@ -42,292 +102,4615 @@ pub trait Describable {
     ///
     /// let stats = df.describe(None)?;
     /// println!("{}", stats);
+    /// # Ok(())
+    /// # }
     /// ```
     fn describe(&self, percentiles: Option<Vec<f64>>) -> Result<DataFrame>;
+
+    /// Compute descriptive statistics using the given options
+    ///
+    /// Produces the same layout as `describe()`, with percentiles and value
+    /// formatting taken from `opts`.
+    fn describe_with(&self, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Same as `describe_with()`, but consumes the frame
+    ///
+    /// The lazy query needs an owned frame, so describing a borrowed
+    /// DataFrame clones it (one Arc per column). In a hot loop over frames
+    /// that aren't needed afterwards, this avoids the clone.
+    fn describe_owned(self, opts: &DescribeOptions) -> Result<DataFrame>
+    where
+        Self: Sized;
+
+    /// The `describe_with()` output as a LazyFrame that hasn't run yet
+    ///
+    /// The plan is the metric aggregation followed by a `describe` map
+    /// reshaping its row, so the data is only read on `collect()`. Compat
+    /// layouts, `skip_errors`, `Statistic::EstimatedSize`, progress hooks,
+    /// cancellation, null-based `DescribeOptions::order_by` orders and
+    /// sources wider than one `DescribeOptions::batch_columns` batch are
+    /// computed right away instead, and returned as an in-memory LazyFrame.
+    ///
+    /// Checks failing on `collect()` (e.g. `DescribeOptions::nan_is_error`)
+    /// surface as a Polars `ComputeError` carrying the `DescribeError`
+    /// message, since the plan can only fail with Polars errors;
+    /// `describe_with()` returns the `DescribeError` itself.
+    fn describe_lazy(&self, opts: &DescribeOptions) -> Result<LazyFrame>;
+
+    /// Describe the columns computed by `exprs` instead of the frame's own
+    ///
+    /// The expressions are selected lazily on top of the frame, so computed
+    /// columns such as `col("revenue") / col("quantity")` are never
+    /// materialized outside the aggregation pass. Aliases name the output
+    /// columns; expressions without a name of their own are called
+    /// `expr_<position>`. Add `all()` to describe the physical columns too.
+    fn describe_exprs(&self, exprs: Vec<Expr>, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Describe only the rows where `predicate` is true
+    ///
+    /// The filter is added to the lazy plan ahead of the aggregation, so
+    /// scans can push it down; the output equals describing the filtered
+    /// frame. A predicate on a column the frame doesn't have is an error.
+    fn describe_where(&self, predicate: Expr, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Describe long entity-attribute-value data as if it were wide
+    ///
+    /// Rows are grouped by `attribute_col` and the numeric metric set is
+    /// computed over `value_col` per attribute, producing one output column
+    /// per attribute (in order of first appearance) exactly like an ordinary
+    /// `describe()` of the equivalent wide frame. Text values are parsed as
+    /// Float64; attributes with values that fail to parse are skipped and a
+    /// warning is reported through `DescribeOptions::on_warning`.
+    fn describe_eav(
+        &self,
+        attribute_col: &str,
+        value_col: &str,
+        opts: &DescribeOptions,
+    ) -> Result<DataFrame>;
+
+    /// Compute descriptive statistics as a typed `DescribeReport`
+    ///
+    /// The report is built from the same aggregation pass as `describe()`.
+    /// Optional extras such as histograms are only computed when requested
+    /// through `opts`.
+    fn describe_report(&self, opts: &DescribeOptions) -> Result<DescribeReport>;
+
+    /// The `describe_report()` summaries keyed by column name
+    ///
+    /// Handy for lookups such as `describe_map(&opts)?["price"].std()`.
+    fn describe_map(&self, opts: &DescribeOptions) -> Result<BTreeMap<String, ColumnSummary>>;
+
+    /// Describe output as a single row holding one Struct column per
+    /// described column
+    ///
+    /// Each struct has one field per statistic, named after its row label
+    /// with percentiles spelled as identifiers (`p25`, `p99_9`), and keeps
+    /// the statistic's native dtype (counts UInt64, min/max in the column's
+    /// dtype). Unnest a column to get its statistics as plain columns.
+    fn describe_structs(&self, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Frequency profile of every String and Categorical column
+    ///
+    /// Returns a long DataFrame with columns `column`, `value`, `count` and
+    /// `pct` holding the `k` most frequent non-null values of each column,
+    /// followed by an `(other)` row aggregating the remaining values when
+    /// there are any. Ties at the cutoff are broken by value, ascending.
+    fn describe_string(&self, k: usize) -> Result<DataFrame>;
+
+    /// Value counts of the `columns`, stacked as a long frame
+    ///
+    /// Columns `column`, `value` (the value rendered as a string), `count`
+    /// (UInt64) and, with `normalize`, `proportion` (Float64, the share of
+    /// all rows, nulls included). Each column's values are sorted by
+    /// descending count, ties broken by value; nulls get a row of their own
+    /// with a null `value`. A `limit` keeps that many rows per column and
+    /// adds an `(other)` row aggregating the rest.
+    fn value_counts_report(
+        &self,
+        columns: &[&str],
+        normalize: bool,
+        limit: Option<usize>,
+    ) -> Result<DataFrame>;
+
+    /// Compute descriptive statistics weighted by `weight_col`
+    ///
+    /// Mean, std and percentiles of numeric columns are weighted; the other
+    /// rows are the same as in `describe()`. Null weights are treated as
+    /// zero, so those rows don't contribute to the weighted statistics, and
+    /// negative weights, or weights summing to zero over a non-empty frame,
+    /// are an error. Percentiles are the smallest value
+    /// whose cumulative weight reaches the percentile, and std treats the
+    /// weights as frequencies. The weight column is not described.
+    fn describe_weighted(
+        &self,
+        weight_col: &str,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame>;
+
+    /// Compute approximate statistics over a random sample of the rows
+    ///
+    /// `fraction` (in (0, 1]) of the rows is sampled lazily before the
+    /// aggregation, so only the sample is aggregated. The count and
+    /// null_count rows are estimates for the full table, scaled up from the
+    /// sample; all other rows are computed on the sample. A fixed `seed`
+    /// makes the result reproducible.
+    #[cfg(feature = "sampling")]
+    fn describe_sampled(
+        &self,
+        fraction: f64,
+        seed: Option<u64>,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame>;
+
+    /// Describe only the first `n` rows
+    ///
+    /// Shorthand for `describe_slice(0, n, opts)`; `n` may exceed the
+    /// number of rows.
+    fn describe_head(&self, n: usize, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Describe the `len` rows starting at `offset`
+    ///
+    /// The slice is pushed into the lazy plan before the aggregation, so
+    /// scans stop reading once the window is covered. A negative `offset`
+    /// counts from the end and windows past either end are truncated,
+    /// following Polars' `slice` semantics.
+    fn describe_slice(&self, offset: i64, len: usize, opts: &DescribeOptions) -> Result<DataFrame>;
+
+    /// Describe every column at the `n + 1` evenly spaced quantiles 0,
+    /// 1/n, ..., 1, e.g. deciles for `n = 10`
+    ///
+    /// Only the count, min, quantile and max rows are kept; `n = 4` gives
+    /// the quartile rows of `describe(None)`. `n` must lie in [1, 1000].
+    fn describe_quantiles(&self, n: usize) -> Result<DataFrame>;
+
+    /// Compute the mergeable part of the statistics as a `PartialSummary`
+    ///
+    /// Summaries of separate chunks can be merged with
+    /// `PartialSummary::merge` and rendered with `PartialSummary::finalize`,
+    /// giving the count, null_count, mean, std, min and max of the combined
+    /// data without rescanning it.
+    fn summarize(&self) -> Result<PartialSummary>;
+
+    /// Compute descriptive statistics as a JSON object keyed by column name
+    ///
+    /// Each column maps statistic names to values in describe order: numbers
+    /// as JSON numbers, missing values as `null` and dates/times as ISO-8601
+    /// strings. `pretty` selects indented output over a single line.
+    #[cfg(feature = "serde")]
+    fn describe_json(&self, percentiles: Option<Vec<f64>>, pretty: bool) -> Result<String>;
+
+    /// Render the `describe_with()` output as a GitHub-flavored markdown table
+    ///
+    /// Columns of numeric source columns are right-aligned and pipes in
+    /// values are escaped. With `max_columns`, only the first `max_columns`
+    /// described columns are rendered, followed by an `…` column.
+    fn describe_markdown(
+        &self,
+        opts: &DescribeOptions,
+        max_columns: Option<usize>,
+    ) -> Result<String>;
+
+    /// Render the `describe_with()` output as an HTML `<table>`
+    ///
+    /// Values are HTML-escaped. The table has the class `describe-table`,
+    /// null cells `stat-null`, and columns above the thresholds set with
+    /// `DescribeOptions::highlight_null_count`/`highlight_null_pct` get
+    /// `stat-high-nulls` on their header and null_count cell.
+    fn describe_html(&self, opts: &DescribeOptions) -> Result<String>;
+
+    /// Render the `describe_with()` output as an aligned terminal table
+    ///
+    /// Columns of numeric source columns are right-aligned and cells longer
+    /// than `DescribeOptions::max_cell_width` are cut with `…`. With colors
+    /// on (see `DescribeOptions::color`), the null_count of columns above
+    /// the `highlight_null_count`/`highlight_null_pct` thresholds is red and
+    /// constant columns (min equal to max) are dimmed.
+    fn describe_pretty(&self, opts: &DescribeOptions) -> Result<String>;
+
+    /// Write the `describe_with()` output to a CSV file at `path`
+    ///
+    /// The file is created or truncated; errors name the path.
+    #[cfg(feature = "csv")]
+    fn describe_to_csv(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()>;
+
+    /// Write the typed report layout (`DescribeReport::to_typed_dataframe`)
+    /// to a Parquet file at `path`
+    ///
+    /// Unlike the CSV output, counts and numeric statistics keep their
+    /// types. The file is created or truncated; errors name the path.
+    #[cfg(feature = "parquet")]
+    fn describe_to_parquet(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()>;
+
+    /// Serialize the typed report layout as an Arrow IPC stream
+    ///
+    /// Counts are UInt64 and numeric statistics Float64. The schema metadata
+    /// records the percentile list under `describe_df.percentiles`. Read it
+    /// back with `describe_from_ipc` or `pyarrow.ipc.open_stream`.
+    #[cfg(feature = "ipc")]
+    fn describe_ipc(&self, opts: &DescribeOptions) -> Result<Vec<u8>>;
+
+    /// Correlation matrix of the numeric columns
+    ///
+    /// Returns a square frame: a leading `column` column naming the row and
+    /// one Float64 column per numeric column. Nulls are dropped pairwise, so
+    /// each coefficient uses every row where both columns have a value.
+    /// Pairs involving a constant column (or a NaN) are null.
+    #[cfg(feature = "correlation")]
+    fn correlation_matrix(&self, method: CorrMethod) -> Result<DataFrame>;
+
+    /// Covariance matrix of the numeric columns, in the layout of
+    /// `correlation_matrix()`
+    ///
+    /// `ddof` is the delta degrees of freedom; with 1 (the setting of the
+    /// describe std) the diagonal equals the square of the describe std.
+    /// Nulls are dropped pairwise.
+    #[cfg(feature = "correlation")]
+    fn covariance_matrix(&self, ddof: u8) -> Result<DataFrame>;
+
+    /// Equal-width histograms of every numeric column as a long frame
+    ///
+    /// Columns: `column`, `bin_start`, `bin_end`, `count` (UInt64). Bins
+    /// span the column's min and max (a constant column gets one bin); bins
+    /// are half-open except the last. Each column ends with an overflow row
+    /// with null bounds counting its nulls and NaNs. `bins` must be at
+    /// least 1.
+    fn describe_histogram(&self, bins: usize) -> Result<DataFrame>;
+
+    /// Fraction of each column at or below a value: the inverse of the
+    /// describe percentiles
+    ///
+    /// One row per (column, value) pair: `column`, `value`, `fraction`
+    /// (Float64, the empirical CDF `mean(col <= value)` over the non-null
+    /// values; null for an all-null column) and `n_equal` (UInt64, the
+    /// values equal to `value`). All pairs are computed in one lazy pass.
+    /// Columns must be numeric or temporal; temporal columns are compared
+    /// on their physical values.
+    fn percentile_of(&self, values: &[(&str, f64)]) -> Result<DataFrame>;
+
+    /// `percentile_of()` for Date and Datetime columns, with dates as
+    /// values
+    ///
+    /// A Datetime column is compared against midnight UTC of the date. The
+    /// `value` column of the result is a Date column.
+    #[cfg(feature = "temporal")]
+    fn percentile_of_dates(&self, values: &[(&str, NaiveDate)]) -> Result<DataFrame>;
+
+    /// Per-column null counts, percentages and first/last null positions,
+    /// plus a co-missingness matrix when `co_missing` is set
+    ///
+    /// Computed in a single lazy pass; see `NullReport` for the layout.
+    fn null_report(&self, co_missing: bool) -> Result<NullReport>;
+
+    /// Number of fully duplicated rows and per-column duplicate value
+    /// counts
+    ///
+    /// Computed in a single lazy pass; see `DuplicatesReport` for the
+    /// layout. The per-column count is also available as the
+    /// `Statistic::DupCount` row of `describe_with()`.
+    fn duplicates_report(&self) -> Result<DuplicatesReport>;
+
+    /// Shape, estimated size, duplicate rows and all-null rows of the
+    /// whole frame
+    ///
+    /// The estimated size needs the data in memory, so a LazyFrame is
+    /// collected once and every field is computed from the collected frame;
+    /// the counts are then aggregated in a single pass over it.
+    fn frame_summary(&self) -> Result<FrameSummary>;
+
+    /// Names of the columns carrying no information: constant (at most one
+    /// distinct non-null value) or entirely null, in schema order
+    ///
+    /// Computed in a single lazy pass over every dtype, nested ones
+    /// included. See `Statistic::IsConstant` and `Statistic::AllNull` for the
+    /// per-column flags.
+    fn degenerate_columns(&self) -> Result<Vec<String>>;
+
+    /// One-glance structure of the frame: one row per dtype
+    ///
+    /// Columns: `dtype`, `n_columns` (UInt32), `column_names` (comma
+    /// separated, in schema order) and `n_with_nulls` (UInt32, the number
+    /// of those columns containing at least one null). Groups appear in the
+    /// order of their first column. Only null counts are computed.
+    fn describe_schema(&self) -> Result<DataFrame>;
+
+    /// Describe every column per time window of `time_col`
+    ///
+    /// Windows of length `every` (e.g. `Duration::parse("1d")`) are built
+    /// with a lazy `group_by_dynamic`. The result stacks one describe output
+    /// per window behind a `window_start` column (the dtype of `time_col`),
+    /// in window order. Windows without rows are omitted. `time_col` must
+    /// be a Date or Datetime column and is not described itself.
+    #[cfg(feature = "temporal")]
+    fn describe_over(
+        &self,
+        time_col: &str,
+        every: Duration,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame>;
+
+    /// Describe every column per partition, e.g. per `date=` hive partition
+    ///
+    /// All partitions are aggregated by one lazy `group_by` on
+    /// `partition_cols`, so scans keep their predicate and projection
+    /// pushdown. The result stacks one describe output per partition (in
+    /// sorted partition order) behind the partition columns, cast to
+    /// String, followed by the whole frame with `"__all__"` as partition
+    /// values. The partition columns are not described themselves.
+    fn describe_partitioned(
+        &self,
+        partition_cols: &[&str],
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame>;
+
+    /// Check the describe statistics against `expectations`
+    ///
+    /// Only the columns named by the rules are described, in a single pass.
+    /// Violated rules and rules on missing columns are failed entries of
+    /// the report rather than errors; see `ValidationReport::is_ok`.
+    fn validate(&self, expectations: &Expectations) -> Result<ValidationReport>;
 }
 
 /// Implementation for DataFrame
 impl Describable for DataFrame {
     fn describe(&self, percentiles: Option<Vec<f64>>) -> Result<DataFrame> {
+        self.describe_with(&DescribeOptions::from_percentiles(percentiles))
+    }
+
+    fn describe_with(&self, opts: &DescribeOptions) -> Result<DataFrame> {
         // Convert to LazyFrame and use the efficient implementation
         let lf = self.clone().lazy();
-        describe_lazy_impl(&lf, percentiles)
+        describe_lazy_impl(&lf, opts)
     }
-}
 
-/// Implementation for LazyFrame
-impl Describable for LazyFrame {
-    fn describe(&self, percentiles: Option<Vec<f64>>) -> Result<DataFrame> {
-        describe_lazy_impl(self, percentiles)
+    fn describe_owned(self, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_lazy_impl(&self.lazy(), opts)
     }
-}
 
-/// Internal implementation that works purely with LazyFrame
-/// This follows the same pattern as the Python implementation
-#[allow(clippy::too_many_lines)]
-fn describe_lazy_impl(lazy_frame: &LazyFrame, percentiles: Option<Vec<f64>>) -> Result<DataFrame> {
-    use polars::lazy::dsl;
-    use polars::prelude::{QuantileMethod, NULL};
+    fn describe_lazy(&self, opts: &DescribeOptions) -> Result<LazyFrame> {
+        let lf = self.clone().lazy();
+        describe_plan_impl(&lf, opts)
+    }
 
-    // Get schema without collecting the data
-    let mut lf_mut = lazy_frame.clone();
-    let schema = lf_mut.collect_schema()?;
+    fn describe_exprs(&self, exprs: Vec<Expr>, opts: &DescribeOptions) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_exprs_impl(&lf, exprs, opts)
+    }
 
-    if schema.is_empty() {
-        return Err(anyhow::anyhow!(
-            "cannot describe a LazyFrame that has no columns"
-        ));
+    fn describe_where(&self, predicate: Expr, opts: &DescribeOptions) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_where_impl(&lf, predicate, opts)
     }
 
-    // Default percentiles if not provided
-    let percentiles = percentiles.unwrap_or_else(|| vec![0.25, 0.50, 0.75]);
+    fn describe_eav(
+        &self,
+        attribute_col: &str,
+        value_col: &str,
+        opts: &DescribeOptions,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_eav_impl(&lf, attribute_col, value_col, opts)
+    }
 
-    // Build statistic row names (metrics)
-    let mut metrics = vec![
-        "count".to_string(),
-        "null_count".to_string(),
-        "mean".to_string(),
-        "std".to_string(),
-        "min".to_string(),
-    ];
-    for p in &percentiles {
-        #[allow(clippy::cast_possible_truncation)]
-        metrics.push(format!("{}%", (p * 100.0) as i32));
+    fn describe_report(&self, opts: &DescribeOptions) -> Result<DescribeReport> {
+        let lf = self.clone().lazy();
+        describe_report_impl(&lf, opts)
     }
-    metrics.push("max".to_string());
 
-    // Helper to check if we skip min/max
-    let skip_minmax = |dtype: &DataType| -> bool {
-        dtype.is_nested()
-            || matches!(
-                dtype,
-                DataType::Categorical(..) | DataType::Null | DataType::Unknown(_)
-            )
-    };
+    fn describe_map(&self, opts: &DescribeOptions) -> Result<BTreeMap<String, ColumnSummary>> {
+        let lf = self.clone().lazy();
+        describe_map_impl(&lf, opts)
+    }
 
-    // Build all metric expressions for all columns in a single pass
-    let mut metric_exprs = Vec::new();
+    fn describe_structs(&self, opts: &DescribeOptions) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_structs_impl(&lf, opts)
+    }
 
-    // Loop over columns and datatypes (like Python: for c, dtype in schema.items())
-    for (col_name, dtype) in schema.iter() {
-        let col_name_str = col_name.to_string();
-        let col = dsl::col(&col_name_str);
+    fn describe_string(&self, k: usize) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_string_impl(&lf, k)
+    }
 
-        // Determine if numeric or temporal
-        let is_numeric = dtype.is_numeric();
-        let is_temporal = !is_numeric && dtype.is_temporal();
+    fn value_counts_report(
+        &self,
+        columns: &[&str],
+        normalize: bool,
+        limit: Option<usize>,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        value_counts_report_impl(&lf, columns, normalize, limit)
+    }
 
-        // Count expressions - for all columns
-        let count_expr = col.clone().count().alias(format!("count:{col_name_str}"));
-        let null_count_expr = col
-            .clone()
-            .null_count()
-            .alias(format!("null_count:{col_name_str}"));
+    fn describe_weighted(
+        &self,
+        weight_col: &str,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_weighted_impl(&lf, weight_col, percentiles)
+    }
 
-        // Mean - for temporal, numeric, or boolean
-        let mean_expr = if is_temporal || is_numeric || dtype == &DataType::Boolean {
-            if dtype == &DataType::Boolean {
-                col.clone().cast(DataType::Float64).mean()
-            } else {
-                col.clone().mean()
-            }
-        } else {
-            dsl::lit(NULL).cast(DataType::Float64)
-        };
-        let mean_expr = mean_expr.alias(format!("mean:{col_name_str}"));
+    #[cfg(feature = "sampling")]
+    fn describe_sampled(
+        &self,
+        fraction: f64,
+        seed: Option<u64>,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_sampled_impl(&lf, fraction, seed, percentiles)
+    }
 
-        // Standard deviation - only for numeric
-        let std_expr = if is_numeric {
-            col.clone().std(1) // ddof=1 for sample std
-        } else {
-            dsl::lit(NULL).cast(DataType::Float64)
-        };
-        let std_expr = std_expr.alias(format!("std:{col_name_str}"));
+    fn describe_head(&self, n: usize, opts: &DescribeOptions) -> Result<DataFrame> {
+        self.describe_slice(0, n, opts)
+    }
 
-        // Min/Max - based on skip_minmax
-        let min_expr = if skip_minmax(dtype) {
-            dsl::lit(NULL).cast(DataType::Float64)
-        } else {
-            col.clone().min()
-        };
-        let min_expr = min_expr.alias(format!("min:{col_name_str}"));
+    fn describe_slice(&self, offset: i64, len: usize, opts: &DescribeOptions) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_slice_impl(&lf, offset, len, opts)
+    }
 
-        let max_expr = if skip_minmax(dtype) {
-            dsl::lit(NULL).cast(DataType::Float64)
-        } else {
-            col.clone().max()
-        };
-        let max_expr = max_expr.alias(format!("max:{col_name_str}"));
+    fn describe_quantiles(&self, n: usize) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_quantiles_impl(&lf, n)
+    }
 
-        // Percentiles - only for numeric types (temporal types don't support quantile)
-        let mut pct_exprs = Vec::new();
-        for (i, p) in percentiles.iter().enumerate() {
-            let pct_expr = if is_numeric {
-                col.clone().quantile(dsl::lit(*p), QuantileMethod::Linear)
-            } else {
-                dsl::lit(NULL).cast(DataType::Float64)
-            };
-            pct_exprs.push(pct_expr.alias(format!("{p}:{i}:{col_name_str}")));
-        }
+    fn summarize(&self) -> Result<PartialSummary> {
+        let lf = self.clone().lazy();
+        summarize_impl(&lf)
+    }
 
-        // Add all expressions for this column
-        metric_exprs.push(count_expr);
-        metric_exprs.push(null_count_expr);
-        metric_exprs.push(mean_expr);
-        metric_exprs.push(std_expr);
-        metric_exprs.push(min_expr);
-        metric_exprs.extend(pct_exprs);
-        metric_exprs.push(max_expr);
+    #[cfg(feature = "serde")]
+    fn describe_json(&self, percentiles: Option<Vec<f64>>, pretty: bool) -> Result<String> {
+        let lf = self.clone().lazy();
+        describe_json_impl(&lf, percentiles, pretty)
     }
 
-    // Execute all aggregations in a single pass
-    let df_metrics = lazy_frame.clone().select(metric_exprs).collect()?;
+    fn describe_markdown(
+        &self,
+        opts: &DescribeOptions,
+        max_columns: Option<usize>,
+    ) -> Result<String> {
+        let lf = self.clone().lazy();
+        describe_markdown_impl(&lf, opts, max_columns)
+    }
 
-    // Reshape the wide result into the final format
-    let n_metrics = metrics.len();
-    let mut result_columns = Vec::new();
+    fn describe_html(&self, opts: &DescribeOptions) -> Result<String> {
+        let lf = self.clone().lazy();
+        describe_html_impl(&lf, opts)
+    }
 
-    // Add the statistic column first
-    result_columns.push(Series::new(
-        "statistic".into(),
-        metrics.clone(),
-    ).into());
+    fn describe_pretty(&self, opts: &DescribeOptions) -> Result<String> {
+        let lf = self.clone().lazy();
+        describe_pretty_impl(&lf, opts)
+    }
 
-    // Process each column's metrics
-    for (col_name, dtype) in schema.iter() {
-        let col_name_str = col_name.to_string();
-        let mut col_values = Vec::new();
+    #[cfg(feature = "csv")]
+    fn describe_to_csv(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()> {
+        let lf = self.clone().lazy();
+        describe_to_csv_impl(&lf, path.as_ref(), opts)
+    }
 
-        // Extract values for this column from the metrics DataFrame
-        // The metrics are in groups of n_metrics per column
-        // let base_idx = idx * n_metrics;  // Not needed with column name lookup
+    #[cfg(feature = "parquet")]
+    fn describe_to_parquet(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()> {
+        let lf = self.clone().lazy();
+        describe_to_parquet_impl(&lf, path.as_ref(), opts)
+    }
 
-        // Helper to format values based on type
-        let is_numeric_result = dtype.is_numeric()
-            || dtype.is_nested()
-            || matches!(dtype, DataType::Null | DataType::Boolean);
+    #[cfg(feature = "ipc")]
+    fn describe_ipc(&self, opts: &DescribeOptions) -> Result<Vec<u8>> {
+        let lf = self.clone().lazy();
+        describe_ipc_impl(&lf, opts)
+    }
 
-        // Extract each metric for this column
-        for metric_idx in 0..n_metrics {
-            // let _col_idx = base_idx + metric_idx;  // Not needed
-            let metric_name = match metric_idx {
-                0 => format!("count:{col_name_str}"),
-                1 => format!("null_count:{col_name_str}"),
-                2 => format!("mean:{col_name_str}"),
-                3 => format!("std:{col_name_str}"),
-                4 => format!("min:{col_name_str}"),
-                i if i < n_metrics - 1 => {
-                    // Percentile
-                    let pct_idx = i - 5;
-                    let p = &percentiles[pct_idx];
-                    format!("{p}:{pct_idx}:{col_name_str}")
-                }
-                _ => format!("max:{col_name_str}"),
-            };
+    #[cfg(feature = "correlation")]
+    fn correlation_matrix(&self, method: CorrMethod) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        correlation_matrix_impl(&lf, method)
+    }
 
-            // Get the value from df_metrics
-            if let Ok(val) = df_metrics.column(&metric_name)?.get(0) {
-                // Format based on type and metric
-                let formatted = if val.is_null() {
-                    "null".to_string()
-                } else if metric_idx <= 1 {
-                    // count and null_count - always as integer
-                    format!("{val}")
-                } else if is_numeric_result && (metric_idx == 2 || metric_idx == 3) {
-                    // mean and std for numeric - format with decimals
-                    format!("{val:.6}")
-                } else if dtype == &DataType::Boolean
-                    && (metric_idx == 4 || metric_idx == n_metrics - 1)
-                {
-                    // min/max for boolean
-                    if metric_idx == 4 {
-                        "false".to_string()
-                    } else {
-                        "true".to_string()
-                    }
-                } else {
-                    format!("{val}")
-                };
+    #[cfg(feature = "correlation")]
+    fn covariance_matrix(&self, ddof: u8) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        covariance_matrix_impl(&lf, ddof)
+    }
 
-                col_values.push(formatted);
-            } else {
-                col_values.push("null".to_string());
-            }
-        }
+    fn describe_histogram(&self, bins: usize) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_histogram_impl(&lf, bins)
+    }
 
-        // Add this column's values to the result
-        result_columns.push(Series::new(col_name_str.into(), col_values).into());
+    fn percentile_of(&self, values: &[(&str, f64)]) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        percentile_of_impl(&lf, values)
     }
 
-    DataFrame::new(result_columns).map_err(Into::into)
-}
+    #[cfg(feature = "temporal")]
+    fn percentile_of_dates(&self, values: &[(&str, NaiveDate)]) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        percentile_of_dates_impl(&lf, values)
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    fn null_report(&self, co_missing: bool) -> Result<NullReport> {
+        let lf = self.clone().lazy();
+        null_report_impl(&lf, co_missing)
+    }
 
-    #[test]
-    fn test_describe_numeric() -> Result<()> {
-        let df = df! {
-            "ints" => [1, 2, 3, 4, 5],
-            "floats" => [1.0, 2.0, 3.0, 4.0, 5.0],
-        }?;
+    fn duplicates_report(&self) -> Result<DuplicatesReport> {
+        let lf = self.clone().lazy();
+        duplicates_report_impl(&lf)
+    }
 
-        let stats = df.describe(None)?;
+    fn frame_summary(&self) -> Result<FrameSummary> {
+        frame_summary_impl(self)
+    }
 
-        // Check shape
-        assert_eq!(stats.shape(), (9, 3)); // 9 stats x 3 columns (statistic + 2 data cols)
+    fn degenerate_columns(&self) -> Result<Vec<String>> {
+        let lf = self.clone().lazy();
+        degenerate_columns_impl(&lf)
+    }
 
-        // Check that statistic column exists
-        assert!(stats.column("statistic").is_ok());
+    fn describe_schema(&self) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_schema_impl(&lf)
+    }
 
-        Ok(())
+    #[cfg(feature = "temporal")]
+    fn describe_over(
+        &self,
+        time_col: &str,
+        every: Duration,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_over_impl(
+            &lf,
+            time_col,
+            every,
+            &DescribeOptions::from_percentiles(percentiles),
+        )
     }
 
-    #[test]
-    fn test_describe_with_custom_percentiles() -> Result<()> {
-        let df = df! {
-            "values" => [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
-        }?;
+    fn describe_partitioned(
+        &self,
+        partition_cols: &[&str],
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        let lf = self.clone().lazy();
+        describe_partitioned_impl(
+            &lf,
+            partition_cols,
+            &DescribeOptions::from_percentiles(percentiles),
+        )
+    }
 
-        let stats = df.describe(Some(vec![0.1, 0.5, 0.9]))?;
+    fn validate(&self, expectations: &Expectations) -> Result<ValidationReport> {
+        let lf = self.clone().lazy();
+        validate_impl(&lf, expectations)
+    }
+}
 
-        // Check that we have the right number of rows
-        // count, null_count, mean, std, min, 10%, 50%, 90%, max = 9 rows
-        assert_eq!(stats.height(), 9);
+/// Implementation for LazyFrame
+impl Describable for LazyFrame {
+    fn describe(&self, percentiles: Option<Vec<f64>>) -> Result<DataFrame> {
+        self.describe_with(&DescribeOptions::from_percentiles(percentiles))
+    }
 
-        Ok(())
+    fn describe_with(&self, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_lazy_impl(self, opts)
     }
 
-    #[test]
-    fn test_describe_mixed_types() -> Result<()> {
-        let df = df! {
-            "numbers" => [1, 2, 3],
-            "strings" => ["a", "b", "c"],
-            "bools" => [true, false, true],
-        }?;
+    fn describe_owned(self, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_lazy_impl(&self, opts)
+    }
 
-        let stats = df.describe(None)?;
+    fn describe_lazy(&self, opts: &DescribeOptions) -> Result<LazyFrame> {
+        describe_plan_impl(self, opts)
+    }
 
-        // Should not panic and should return stats for all columns
-        assert_eq!(stats.width(), 4); // statistic + 3 data columns
+    fn describe_exprs(&self, exprs: Vec<Expr>, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_exprs_impl(self, exprs, opts)
+    }
 
-        Ok(())
+    fn describe_where(&self, predicate: Expr, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_where_impl(self, predicate, opts)
     }
 
-    #[test]
-    fn test_describe_lazy_frame() -> Result<()> {
-        let df = df! {
-            "a" => [1, 2, 3, 4, 5],
-            "b" => [10.0, 20.0, 30.0, 40.0, 50.0],
-        }?;
+    fn describe_eav(
+        &self,
+        attribute_col: &str,
+        value_col: &str,
+        opts: &DescribeOptions,
+    ) -> Result<DataFrame> {
+        describe_eav_impl(self, attribute_col, value_col, opts)
+    }
+
+    fn describe_report(&self, opts: &DescribeOptions) -> Result<DescribeReport> {
+        describe_report_impl(self, opts)
+    }
+
+    fn describe_map(&self, opts: &DescribeOptions) -> Result<BTreeMap<String, ColumnSummary>> {
+        describe_map_impl(self, opts)
+    }
+
+    fn describe_structs(&self, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_structs_impl(self, opts)
+    }
+
+    fn describe_string(&self, k: usize) -> Result<DataFrame> {
+        describe_string_impl(self, k)
+    }
+
+    fn value_counts_report(
+        &self,
+        columns: &[&str],
+        normalize: bool,
+        limit: Option<usize>,
+    ) -> Result<DataFrame> {
+        value_counts_report_impl(self, columns, normalize, limit)
+    }
+
+    fn describe_weighted(
+        &self,
+        weight_col: &str,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        describe_weighted_impl(self, weight_col, percentiles)
+    }
+
+    #[cfg(feature = "sampling")]
+    fn describe_sampled(
+        &self,
+        fraction: f64,
+        seed: Option<u64>,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        describe_sampled_impl(self, fraction, seed, percentiles)
+    }
+
+    fn describe_head(&self, n: usize, opts: &DescribeOptions) -> Result<DataFrame> {
+        self.describe_slice(0, n, opts)
+    }
+
+    fn describe_slice(&self, offset: i64, len: usize, opts: &DescribeOptions) -> Result<DataFrame> {
+        describe_slice_impl(self, offset, len, opts)
+    }
+
+    fn describe_quantiles(&self, n: usize) -> Result<DataFrame> {
+        describe_quantiles_impl(self, n)
+    }
+
+    fn summarize(&self) -> Result<PartialSummary> {
+        summarize_impl(self)
+    }
+
+    #[cfg(feature = "serde")]
+    fn describe_json(&self, percentiles: Option<Vec<f64>>, pretty: bool) -> Result<String> {
+        describe_json_impl(self, percentiles, pretty)
+    }
+
+    fn describe_markdown(
+        &self,
+        opts: &DescribeOptions,
+        max_columns: Option<usize>,
+    ) -> Result<String> {
+        describe_markdown_impl(self, opts, max_columns)
+    }
+
+    fn describe_html(&self, opts: &DescribeOptions) -> Result<String> {
+        describe_html_impl(self, opts)
+    }
+
+    fn describe_pretty(&self, opts: &DescribeOptions) -> Result<String> {
+        describe_pretty_impl(self, opts)
+    }
+
+    #[cfg(feature = "csv")]
+    fn describe_to_csv(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()> {
+        describe_to_csv_impl(self, path.as_ref(), opts)
+    }
+
+    #[cfg(feature = "parquet")]
+    fn describe_to_parquet(&self, path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<()> {
+        describe_to_parquet_impl(self, path.as_ref(), opts)
+    }
+
+    #[cfg(feature = "ipc")]
+    fn describe_ipc(&self, opts: &DescribeOptions) -> Result<Vec<u8>> {
+        describe_ipc_impl(self, opts)
+    }
+
+    #[cfg(feature = "correlation")]
+    fn correlation_matrix(&self, method: CorrMethod) -> Result<DataFrame> {
+        correlation_matrix_impl(self, method)
+    }
+
+    #[cfg(feature = "correlation")]
+    fn covariance_matrix(&self, ddof: u8) -> Result<DataFrame> {
+        covariance_matrix_impl(self, ddof)
+    }
+
+    fn describe_histogram(&self, bins: usize) -> Result<DataFrame> {
+        describe_histogram_impl(self, bins)
+    }
+
+    fn percentile_of(&self, values: &[(&str, f64)]) -> Result<DataFrame> {
+        percentile_of_impl(self, values)
+    }
+
+    #[cfg(feature = "temporal")]
+    fn percentile_of_dates(&self, values: &[(&str, NaiveDate)]) -> Result<DataFrame> {
+        percentile_of_dates_impl(self, values)
+    }
+
+    fn null_report(&self, co_missing: bool) -> Result<NullReport> {
+        null_report_impl(self, co_missing)
+    }
+
+    fn duplicates_report(&self) -> Result<DuplicatesReport> {
+        duplicates_report_impl(self)
+    }
+
+    fn frame_summary(&self) -> Result<FrameSummary> {
+        frame_summary_impl(&self.clone().collect()?)
+    }
+
+    fn degenerate_columns(&self) -> Result<Vec<String>> {
+        degenerate_columns_impl(self)
+    }
+
+    fn describe_schema(&self) -> Result<DataFrame> {
+        describe_schema_impl(self)
+    }
+
+    #[cfg(feature = "temporal")]
+    fn describe_over(
+        &self,
+        time_col: &str,
+        every: Duration,
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        describe_over_impl(
+            self,
+            time_col,
+            every,
+            &DescribeOptions::from_percentiles(percentiles),
+        )
+    }
+
+    fn describe_partitioned(
+        &self,
+        partition_cols: &[&str],
+        percentiles: Option<Vec<f64>>,
+    ) -> Result<DataFrame> {
+        describe_partitioned_impl(
+            self,
+            partition_cols,
+            &DescribeOptions::from_percentiles(percentiles),
+        )
+    }
+
+    fn validate(&self, expectations: &Expectations) -> Result<ValidationReport> {
+        validate_impl(self, expectations)
+    }
+}
+
+/// Result of the single aggregation pass, shared by every output format
+pub(crate) struct Metrics {
+    pub(crate) schema: SchemaRef,
+    pub(crate) percentiles: Vec<f64>,
+    /// Output rows, in display order
+    pub(crate) rows: Vec<MetricRow>,
+    /// One row, one column per (statistic, column) pair
+    pub(crate) frame: DataFrame,
+    /// Columns left out by `DescribeOptions::max_columns`
+    pub(crate) gap: ColumnGap,
+}
+
+/// The columns left out by `DescribeOptions::max_columns`: `skipped`
+/// columns after the first `head` described ones
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) struct ColumnGap {
+    pub(crate) head: usize,
+    pub(crate) skipped: usize,
+}
+
+/// Name and cell content of the column standing in for the columns left
+/// out by `DescribeOptions::max_columns`
+pub(crate) const GAP_COLUMN: &str = "…";
+
+impl ColumnGap {
+    /// Positions of the described `names` in output order, see
+    /// `ColumnOrder::arrange`
+    ///
+    /// The head and tail columns are ordered on their own, so the head ones
+    /// still come first and the gap column goes right after them.
+    pub(crate) fn arrange(
+        &self,
+        order_by: &ColumnOrder,
+        names: &[&str],
+        counts: &[(u64, u64)],
+    ) -> Vec<usize> {
+        if self.skipped == 0 {
+            return order_by.arrange(names, counts);
+        }
+        let head = self.head.min(names.len());
+        let (head_counts, tail_counts) = if counts.is_empty() {
+            (counts, counts)
+        } else {
+            counts.split_at(head)
+        };
+        let mut order = order_by.arrange(&names[..head], head_counts);
+        order.extend(
+            order_by
+                .arrange(&names[head..], tail_counts)
+                .into_iter()
+                .map(|i| head + i),
+        );
+        order
+    }
+}
+
+impl Metrics {
+    /// Values of the metrics of `col_name`, in `rows` order
+    ///
+    /// The aggregation emits the metrics of each column next to each other
+    /// and in `rows` order, so a single name lookup locates all of them.
+    pub(crate) fn column_values(&self, col_name: &str) -> Result<Vec<AnyValue<'_>>> {
+        let first = MetricRow::Count.alias(&self.percentiles, col_name);
+        let start = self.frame.try_get_column_index(&first)?;
+        let columns = self.frame.get_columns();
+        self.rows
+            .iter()
+            .enumerate()
+            .map(|(i, row)| {
+                let column = columns.get(start + i).ok_or_else(|| {
+                    DescribeError::Internal(format!(
+                        "missing metric '{}'",
+                        row.alias(&self.percentiles, col_name)
+                    ))
+                })?;
+                debug_assert_eq!(
+                    column.name().as_str(),
+                    row.alias(&self.percentiles, col_name)
+                );
+                Ok(column.get(0)?)
+            })
+            .collect()
+    }
+
+    /// The described columns in the output order set by
+    /// `DescribeOptions::order_by`
+    pub(crate) fn ordered_columns(
+        &self,
+        opts: &DescribeOptions,
+    ) -> Result<Vec<(&PlSmallStr, &DataType)>> {
+        let columns: Vec<(&PlSmallStr, &DataType)> = self.schema.iter().collect();
+        let names: Vec<&str> = columns.iter().map(|(name, _)| name.as_str()).collect();
+        let mut counts = Vec::new();
+        if opts.order_by.needs_counts() {
+            for name in &names {
+                let count = |row: MetricRow| -> Result<u64> {
+                    Ok(self
+                        .frame
+                        .column(&row.alias(&self.percentiles, name))?
+                        .get(0)?
+                        .extract::<u64>()
+                        .unwrap_or(0))
+                };
+                counts.push((count(MetricRow::Count)?, count(MetricRow::NullCount)?));
+            }
+        }
+        Ok(self
+            .gap
+            .arrange(&opts.order_by, &names, &counts)
+            .into_iter()
+            .map(|i| columns[i])
+            .collect())
+    }
+}
+
+/// A single statistic row of the describe output
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum MetricRow {
+    Count,
+    NullCount,
+    Mean,
+    Std,
+    Min,
+    /// Index into the percentile list
+    Percentile(usize),
+    Max,
+    /// Optional statistic requested through `DescribeOptions::statistic`
+    Extra(Statistic),
+    /// Index into `DescribeOptions::custom_stats`
+    Custom(usize),
+    /// List length statistic, only present when a List column is described
+    List(ListMetric),
+    /// Byte length statistic, only present when a Binary column is described
+    Binary(BinaryMetric),
+}
+
+/// Statistics describing the lengths and elements of List columns
+///
+/// Null lists count towards `null_count` but have no length; empty lists
+/// have length 0.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ListMetric {
+    LenMin,
+    LenMean,
+    LenMax,
+    /// Total number of elements across all lists
+    Elements,
+    /// Number of null elements across all lists
+    NullElements,
+}
+
+impl ListMetric {
+    const ALL: [ListMetric; 5] = [
+        ListMetric::LenMin,
+        ListMetric::LenMean,
+        ListMetric::LenMax,
+        ListMetric::Elements,
+        ListMetric::NullElements,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            ListMetric::LenMin => "list_len_min",
+            ListMetric::LenMean => "list_len_mean",
+            ListMetric::LenMax => "list_len_max",
+            ListMetric::Elements => "list_elements",
+            ListMetric::NullElements => "list_null_elements",
+        }
+    }
+}
+
+/// Byte length statistics of Binary columns
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum BinaryMetric {
+    Min,
+    Mean,
+    Max,
+}
+
+impl BinaryMetric {
+    const ALL: [BinaryMetric; 3] = [BinaryMetric::Min, BinaryMetric::Mean, BinaryMetric::Max];
+
+    fn label(self) -> &'static str {
+        match self {
+            BinaryMetric::Min => "byte_len_min",
+            BinaryMetric::Mean => "byte_len_mean",
+            BinaryMetric::Max => "byte_len_max",
+        }
+    }
+}
+
+impl MetricRow {
+    /// Name of this row's value for `col_name` in the aggregation result
+    pub(crate) fn alias(self, percentiles: &[f64], col_name: &str) -> String {
+        match self {
+            MetricRow::Count => format!("count:{col_name}"),
+            MetricRow::NullCount => format!("null_count:{col_name}"),
+            MetricRow::Mean => format!("mean:{col_name}"),
+            MetricRow::Std => format!("std:{col_name}"),
+            MetricRow::Min => format!("min:{col_name}"),
+            MetricRow::Percentile(i) => format!("{}:{i}:{col_name}", percentiles[i]),
+            MetricRow::Max => format!("max:{col_name}"),
+            MetricRow::Extra(stat) => format!("{}:{col_name}", stat.name()),
+            MetricRow::Custom(i) => format!("custom:{i}:{col_name}"),
+            MetricRow::List(metric) => format!("{}:{col_name}", metric.label()),
+            MetricRow::Binary(metric) => format!("{}:{col_name}", metric.label()),
+        }
+    }
+
+    /// Label shown in the statistic column
+    pub(crate) fn label(self, percentiles: &[f64], opts: &DescribeOptions) -> String {
+        match self {
+            MetricRow::Count => "count".to_string(),
+            MetricRow::NullCount => "null_count".to_string(),
+            MetricRow::Mean => "mean".to_string(),
+            MetricRow::Std => "std".to_string(),
+            MetricRow::Min => "min".to_string(),
+            MetricRow::Percentile(i) => styled_percentile_label(percentiles[i], opts.label_style),
+            MetricRow::Max => "max".to_string(),
+            MetricRow::Extra(stat) => stat.name().to_string(),
+            MetricRow::Custom(i) => opts.custom_stats[i].name.clone(),
+            MetricRow::List(metric) => metric.label().to_string(),
+            MetricRow::Binary(metric) => metric.label().to_string(),
+        }
+    }
+}
+
+/// Statistic row label for a percentile (e.g. 0.25 -> "25%", 0.999 ->
+/// "99.9%")
+///
+/// Percents are rounded to four decimals, which absorbs float noise such
+/// as 0.29 * 100 = 28.999999999999996.
+pub(crate) fn percentile_label(p: f64) -> String {
+    let pct = (p * 100.0 * 10_000.0).round() / 10_000.0;
+    format!("{pct}%")
+}
+
+/// Percentile label in `style`: `percentile_label()`, or an identifier
+/// such as "p25" or "p99_9" for `LabelStyle::Snake`
+pub(crate) fn styled_percentile_label(p: f64, style: LabelStyle) -> String {
+    let label = percentile_label(p);
+    match style {
+        LabelStyle::Human => label,
+        LabelStyle::Snake => format!("p{}", label.trim_end_matches('%').replace('.', "_")),
+    }
+}
+
+/// Build the statistic rows (metrics) for the given percentiles, followed
+/// by the optional and custom statistics of `opts`
+///
+/// Fails when a custom statistic reuses the label of a built-in row or of
+/// another custom statistic, or when the trimmed mean fraction or the
+/// confidence level is invalid.
+pub(crate) fn metric_rows(percentiles: &[f64], opts: &DescribeOptions) -> Result<Vec<MetricRow>> {
+    if let Some(fraction) = opts.trim_fraction
+        && !(0.0..0.5).contains(&fraction)
+    {
+        return Err(DescribeError::InvalidArgument(format!(
+            "trimmed mean fraction {fraction} is outside the range [0, 0.5)"
+        ))
+        .into());
+    }
+    if let Some(level) = opts.confidence_level
+        && !(level > 0.0 && level < 1.0)
+    {
+        return Err(DescribeError::InvalidArgument(format!(
+            "confidence level {level} is outside the range (0, 1)"
+        ))
+        .into());
+    }
+
+    let mut taken: Vec<String> = ["count", "null_count", "mean", "std", "min", "max"]
+        .iter()
+        .map(|label| (*label).to_string())
+        .chain(
+            percentiles
+                .iter()
+                .map(|p| styled_percentile_label(*p, opts.label_style)),
+        )
+        .chain(Statistic::ALL.iter().map(|stat| stat.name().to_string()))
+        .chain(ListMetric::ALL.iter().map(|m| m.label().to_string()))
+        .chain(BinaryMetric::ALL.iter().map(|m| m.label().to_string()))
+        .collect();
+    for custom in &opts.custom_stats {
+        if taken.contains(&custom.name) {
+            return Err(DescribeError::InvalidArgument(format!(
+                "custom statistic '{}' collides with an existing statistic",
+                custom.name
+            ))
+            .into());
+        }
+        taken.push(custom.name.clone());
+    }
+
+    let mut rows = vec![
+        MetricRow::Count,
+        MetricRow::NullCount,
+        MetricRow::Mean,
+        MetricRow::Std,
+        MetricRow::Min,
+    ];
+    rows.extend((0..percentiles.len()).map(MetricRow::Percentile));
+    rows.push(MetricRow::Max);
+    rows.extend(opts.all_statistics().into_iter().map(MetricRow::Extra));
+    rows.extend((0..opts.custom_stats.len()).map(MetricRow::Custom));
+    Ok(rows)
+}
+
+/// Percentiles to compute, falling back to the defaults
+///
+/// Values must lie in [0, 1]. Duplicates are dropped (keeping the first
+/// occurrence) unless `strict_percentiles` is set, in which case they are an
+/// error. Percentiles only requested through `DescribeOptions::for_column`
+/// are added to the list, which is then ordered by value.
+pub(crate) fn resolved_percentiles(opts: &DescribeOptions) -> Result<Vec<f64>> {
+    let requested = opts
+        .percentiles
+        .clone()
+        .unwrap_or_else(|| DEFAULT_PERCENTILES.to_vec());
+
+    let mut percentiles: Vec<f64> = Vec::with_capacity(requested.len());
+    for p in requested {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(DescribeError::InvalidPercentile(p).into());
+        }
+        if percentiles.contains(&p) {
+            if opts.strict_percentiles {
+                return Err(DescribeError::DuplicatePercentile(p).into());
+            }
+            continue;
+        }
+        percentiles.push(p);
+    }
+
+    let overrides = opts
+        .column_overrides
+        .iter()
+        .filter_map(|(_, column_opts)| column_opts.percentiles.as_ref())
+        .flatten();
+    let mut extended = false;
+    for &p in overrides {
+        if !(0.0..=1.0).contains(&p) {
+            return Err(DescribeError::InvalidPercentile(p).into());
+        }
+        if !percentiles.contains(&p) {
+            percentiles.push(p);
+            extended = true;
+        }
+    }
+    if extended {
+        percentiles.sort_by(f64::total_cmp);
+    }
+    Ok(percentiles)
+}
+
+/// Resolve the columns to describe, applying the `columns` selection
+///
+/// Returns the (possibly narrowed) LazyFrame together with the schema of the
+/// columns to describe. A weight column stays in the LazyFrame but is left
+/// out of the schema.
+pub(crate) fn select_columns(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<(LazyFrame, SchemaRef)> {
+    let (lazy_frame, schema, _) = select_columns_with_gap(lazy_frame, opts)?;
+    Ok((lazy_frame, schema))
+}
+
+/// `select_columns`, also returning the columns `DescribeOptions::max_columns`
+/// left out
+///
+/// The limit applies to the columns left after unnesting structs and
+/// doesn't count a weight column; an explicit `columns` selection lifts it.
+/// Leaving columns out is reported through a warning.
+pub(crate) fn select_columns_with_gap(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<(LazyFrame, SchemaRef, ColumnGap)> {
+    let (lazy_frame, schema) = select_requested_columns(lazy_frame, opts)?;
+    let schema = match &opts.weight_col {
+        Some(weight_col) => {
+            let mut described = (*schema).clone();
+            described.shift_remove(weight_col.as_str());
+            Arc::new(described)
+        }
+        None => schema,
+    };
+
+    let Some((head, tail)) = opts.column_limit.filter(|_| opts.columns.is_none()) else {
+        return Ok((lazy_frame, schema, ColumnGap::default()));
+    };
+    if head + tail == 0 {
+        return Err(DescribeError::InvalidArgument(
+            "max_columns must keep at least one column".to_string(),
+        )
+        .into());
+    }
+    if head + tail >= schema.len() {
+        return Ok((lazy_frame, schema, ColumnGap::default()));
+    }
+
+    let skipped = schema.len() - head - tail;
+    let kept: Schema = schema
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| *i < head || *i >= head + skipped)
+        .map(|(_, (name, dtype))| (name.clone(), dtype.clone()))
+        .collect();
+    opts.warn(&format!(
+        "describing {} of {} columns (max_columns); select others with columns()",
+        kept.len(),
+        schema.len()
+    ));
+    let mut selected: Vec<Expr> = kept.iter_names().map(|name| col(name.clone())).collect();
+    if let Some(weight_col) = &opts.weight_col {
+        selected.push(col(weight_col.as_str()));
+    }
+    Ok((
+        lazy_frame.select(selected),
+        Arc::new(kept),
+        ColumnGap { head, skipped },
+    ))
+}
+
+fn select_requested_columns(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<(LazyFrame, SchemaRef)> {
+    // Get schema without collecting the data
+    let mut lf_mut = lazy_frame.clone();
+    let schema = lf_mut.collect_schema()?;
+
+    if let Some(weight_col) = &opts.weight_col {
+        validate_weight_column(&schema, weight_col)?;
+    }
+
+    if opts.reject_describe_output && looks_like_describe_output(&schema) {
+        return Err(DescribeError::InvalidArgument(
+            "refusing to describe a frame that looks like describe() output".to_string(),
+        )
+        .into());
+    }
+
+    let Some(requested) = &opts.columns else {
+        return unnest_struct_columns(lf_mut, schema, opts);
+    };
+
+    let mut selected = Vec::with_capacity(requested.len());
+    for name in requested {
+        if schema.contains(name) {
+            selected.push(col(name.as_str()));
+        } else if opts.strict_columns {
+            return Err(DescribeError::ColumnNotFound(name.clone()).into());
+        } else {
+            opts.warn(&format!("skipping unknown column '{name}'"));
+        }
+    }
+    // The weights are needed even when the weight column isn't requested
+    if let Some(weight_col) = &opts.weight_col
+        && !requested.contains(weight_col)
+    {
+        selected.push(col(weight_col.as_str()));
+    }
+
+    // Selecting up front lets projection pushdown keep scans from reading
+    // the other columns
+    let mut narrowed = lf_mut.select(selected);
+    let schema = narrowed.collect_schema()?;
+    unnest_struct_columns(narrowed, schema, opts)
+}
+
+/// Replace struct columns by their (recursively flattened) fields when
+/// `unnest_structs` is set, then check the output names with
+/// `output_names()`
+fn unnest_struct_columns(
+    lazy_frame: LazyFrame,
+    schema: SchemaRef,
+    opts: &DescribeOptions,
+) -> Result<(LazyFrame, SchemaRef)> {
+    let has_structs = schema
+        .iter_values()
+        .any(|dtype| matches!(dtype, DataType::Struct(_)));
+    let unnest = opts.unnest_structs && has_structs;
+
+    let mut columns = Vec::with_capacity(schema.len());
+    for (col_name, dtype) in schema.iter() {
+        if unnest {
+            flatten_struct(
+                col(col_name.clone()),
+                col_name,
+                dtype,
+                opts.unnest_depth,
+                &mut columns,
+            );
+        } else {
+            columns.push((col_name.to_string(), col(col_name.clone())));
+        }
+    }
+
+    let names: Vec<&str> = columns.iter().map(|(name, _)| name.as_str()).collect();
+    let renamed = output_names(&names, opts)?;
+    if !unnest && renamed.iter().zip(&names).all(|(new, old)| new == old) {
+        return Ok((lazy_frame, schema));
+    }
+
+    let exprs: Vec<Expr> = columns
+        .into_iter()
+        .zip(renamed)
+        .map(|((_, expr), name)| expr.alias(name))
+        .collect();
+    let mut flattened = lazy_frame.select(exprs);
+    let schema = flattened.collect_schema()?;
+    Ok((flattened, schema))
+}
+
+/// Push `expr` named `name`, or the fields of `expr` named `name.field` if
+/// it is a struct and `depth_left` allows expanding it
+fn flatten_struct(
+    expr: Expr,
+    name: &str,
+    dtype: &DataType,
+    depth_left: Option<usize>,
+    out: &mut Vec<(String, Expr)>,
+) {
+    match dtype {
+        DataType::Struct(fields) if !fields.is_empty() && depth_left != Some(0) => {
+            for field in fields {
+                flatten_struct(
+                    expr.clone().struct_().field_by_name(field.name()),
+                    &format!("{name}.{}", field.name()),
+                    field.dtype(),
+                    depth_left.map(|depth| depth - 1),
+                    out,
+                );
+            }
+        }
+        _ => out.push((name.to_string(), expr)),
+    }
+}
+
+/// Names of the described columns in the output
+///
+/// A name taken by an earlier column, by a field unnested from a struct or
+/// by the `statistic` column of the output is a `DuplicateColumns` error,
+/// or gets the first free `_1`, `_2`, ... suffix under
+/// `DescribeOptions::dedupe_names`. Names differing only by case are
+/// distinct.
+fn output_names(names: &[&str], opts: &DescribeOptions) -> Result<Vec<String>> {
+    let mut taken: PlHashSet<String> = PlHashSet::with_capacity(names.len() + 1);
+    taken.insert("statistic".to_string());
+    let mut duplicates = Vec::new();
+    let mut renamed = Vec::with_capacity(names.len());
+    for name in names {
+        if taken.insert((*name).to_string()) {
+            renamed.push((*name).to_string());
+            continue;
+        }
+        if !duplicates.contains(name) {
+            duplicates.push(*name);
+        }
+        let free = (1..)
+            .map(|i| format!("{name}_{i}"))
+            .find(|candidate| !taken.contains(candidate) && !names.contains(&candidate.as_str()))
+            .unwrap_or_default();
+        taken.insert(free.clone());
+        renamed.push(free);
+    }
+    if !duplicates.is_empty() && !opts.dedupe_names {
+        return Err(DescribeError::DuplicateColumns(
+            duplicates.iter().map(|name| (*name).to_string()).collect(),
+        )
+        .into());
+    }
+    Ok(renamed)
+}
+
+/// A leading String `statistic` column with only String columns after it
+fn looks_like_describe_output(schema: &Schema) -> bool {
+    schema
+        .get_at_index(0)
+        .is_some_and(|(name, _)| name.as_str() == "statistic")
+        && schema.iter_values().all(|dtype| dtype == &DataType::String)
+}
+
+/// List length expressions for a column, aliased like the other metrics
+///
+/// Non-list columns get typed nulls so every described column has a value
+/// for each list row.
+fn list_metric_exprs(col: Expr, col_name: &str, dtype: &DataType) -> Vec<Expr> {
+    let is_list = matches!(dtype, DataType::List(_));
+    let lengths = col.clone().list().len();
+    ListMetric::ALL
+        .into_iter()
+        .map(|metric| {
+            let expr = if !is_list {
+                lit(NULL).cast(DataType::UInt64)
+            } else {
+                match metric {
+                    ListMetric::LenMin => lengths.clone().min(),
+                    ListMetric::LenMean => lengths.clone().mean(),
+                    ListMetric::LenMax => lengths.clone().max(),
+                    ListMetric::Elements => lengths.clone().sum().cast(DataType::UInt64),
+                    // explode() turns null and empty lists into nulls too, so
+                    // count the non-null elements and subtract
+                    ListMetric::NullElements => {
+                        lengths.clone().sum().cast(DataType::UInt64)
+                            - col.clone().explode().count().cast(DataType::UInt64)
+                    }
+                }
+            };
+            expr.alias(MetricRow::List(metric).alias(&[], col_name))
+        })
+        .collect()
+}
+
+/// Byte length expressions for a column, aliased like the other metrics
+///
+/// Non-binary columns get typed nulls, as for the list rows.
+fn binary_metric_exprs(col: Expr, col_name: &str, dtype: &DataType) -> Vec<Expr> {
+    let is_binary = dtype == &DataType::Binary;
+    let lengths = col.binary().size_bytes();
+    BinaryMetric::ALL
+        .into_iter()
+        .map(|metric| {
+            let expr = if !is_binary {
+                lit(NULL).cast(DataType::UInt32)
+            } else {
+                match metric {
+                    BinaryMetric::Min => lengths.clone().min(),
+                    BinaryMetric::Mean => lengths.clone().mean(),
+                    BinaryMetric::Max => lengths.clone().max(),
+                }
+            };
+            expr.alias(MetricRow::Binary(metric).alias(&[], col_name))
+        })
+        .collect()
+}
+
+/// Alias of the NaN check run alongside the metrics under `nan_is_error`
+fn nan_check_alias(col_name: &str) -> String {
+    format!("__has_nan:{col_name}")
+}
+
+/// Alias of the frame height read alongside the metrics under
+/// `strict_counts`
+fn total_rows_check_alias(col_name: &str) -> String {
+    format!("__total_rows:{col_name}")
+}
+
+/// Build and run the aggregation for every column in a single pass
+/// This follows the same pattern as the Python implementation
+pub(crate) fn compute_metrics(lazy_frame: &LazyFrame, opts: &DescribeOptions) -> Result<Metrics> {
+    let started = Instant::now();
+    let plan = MetricsPlan::new(lazy_frame, opts)?;
+    compute_planned_metrics(plan, opts, started)
+}
+
+/// Number of columns aggregated per select when describing an in-memory
+/// frame, unless set by `DescribeOptions::batch_columns`
+///
+/// Planning a single select over tens of thousands of metric expressions is
+/// superlinear in their number, so wide in-memory frames are aggregated in
+/// batches of columns, collected in parallel.
+pub(crate) const METRIC_BATCH_COLUMNS: usize = 256;
+
+/// Number of columns aggregated per select for other sources (scans, lazy
+/// queries), unless set by `DescribeOptions::batch_columns`
+///
+/// Their batches are collected one after the other, each reading only its
+/// own columns, so narrower frames keep a single select and a single read.
+pub(crate) const SCAN_BATCH_COLUMNS: usize = 2_000;
+
+/// Run the aggregation of `plan`; `started` is when the describe began, for
+/// `DescribeOptions::timeout`
+pub(crate) fn compute_planned_metrics(
+    mut plan: MetricsPlan,
+    opts: &DescribeOptions,
+    started: Instant,
+) -> Result<Metrics> {
+    opts.progress(Progress::SchemaResolved);
+    opts.check_interrupted(started)?;
+    let batches = plan.batches(opts);
+    opts.progress(Progress::ExpressionsBuilt);
+    opts.check_interrupted(started)?;
+
+    // Execute all aggregations in a single pass over the data
+    opts.progress(Progress::CollectionStarted);
+    let aggregated = if batches.len() > 1
+        && (!plan.parallel(opts) || opts.progress_hook.is_some() || opts.interruptible())
+    {
+        collect_batches(batches, plan.schema.len(), opts, started)
+    } else {
+        concat_batches(batches)
+            .and_then(|aggregation| opts.engine.collect(aggregation))
+            .map_err(Into::into)
+    };
+    let df_metrics = match aggregated {
+        Ok(df) => df,
+        Err(err) if opts.skip_errors && !err.is::<DescribeError>() => {
+            // Find the offending columns by describing each one on its own
+            opts.warn(&format!(
+                "describe failed ({err}); retrying column by column"
+            ));
+            let mut kept = Schema::default();
+            let mut columns = Vec::new();
+            let weight_checks = plan.weight_check_exprs(opts);
+            if !weight_checks.is_empty() {
+                let checks = opts
+                    .engine
+                    .collect(plan.source.clone().select(weight_checks))?;
+                columns.extend(checks.take_columns());
+            }
+            for (col_name, dtype) in plan.schema.iter() {
+                let exprs = plan.column_exprs(col_name, dtype, opts);
+                match opts.engine.collect(plan.source.clone().select(exprs)) {
+                    Ok(df) => {
+                        kept.insert(col_name.clone(), dtype.clone());
+                        columns.extend(df.take_columns());
+                    }
+                    Err(err) => opts.warn(&format!("skipping column '{col_name}': {err}")),
+                }
+            }
+            if kept.is_empty() {
+                return Err(err);
+            }
+            plan.schema = Arc::new(kept);
+            DataFrame::new(columns)?
+        }
+        Err(err) => return Err(err),
+    };
+    opts.check_interrupted(started)?;
+    let mut metrics = checked_metrics(
+        df_metrics,
+        plan.schema,
+        plan.percentiles,
+        plan.rows,
+        plan.gap,
+        opts,
+    )?;
+
+    if opts.all_statistics().contains(&Statistic::EstimatedSize) {
+        let described: Vec<Expr> = metrics
+            .schema
+            .iter_names()
+            .map(|name| col(name.clone()))
+            .collect();
+        let materialized = opts.engine.collect(plan.source.clone().select(described))?;
+        for column in materialized.get_columns() {
+            if !opts.statistic_requested(column.name(), Statistic::EstimatedSize) {
+                continue;
+            }
+            let alias = MetricRow::Extra(Statistic::EstimatedSize)
+                .alias(&metrics.percentiles, column.name());
+            let size = column.as_materialized_series().estimated_size() as u64;
+            metrics
+                .frame
+                .with_column(Column::new(alias.into(), [size]))?;
+        }
+    }
+    opts.progress(Progress::CollectionFinished);
+
+    Ok(metrics)
+}
+
+/// Run the strict checks on a collected aggregation, shared by
+/// `compute_planned_metrics` and the `describe` UDF of `describe_plan_impl`
+fn checked_metrics(
+    mut frame: DataFrame,
+    schema: SchemaRef,
+    percentiles: Vec<f64>,
+    rows: Vec<MetricRow>,
+    gap: ColumnGap,
+    opts: &DescribeOptions,
+) -> Result<Metrics> {
+    check_metrics(&frame, &schema, opts)?;
+    check_quantile_order(&mut frame, &schema, &percentiles, opts)?;
+    Ok(Metrics {
+        rows,
+        schema,
+        percentiles,
+        frame,
+        gap,
+    })
+}
+
+/// Everything `compute_metrics` decides before running the aggregation
+pub(crate) struct MetricsPlan {
+    /// The frame narrowed to the selected columns
+    source: LazyFrame,
+    /// Whether `source` is a DataFrame held in memory
+    in_memory: bool,
+    schema: SchemaRef,
+    gap: ColumnGap,
+    percentiles: Vec<f64>,
+    rows: Vec<MetricRow>,
+    has_lists: bool,
+    has_binary: bool,
+}
+
+impl MetricsPlan {
+    pub(crate) fn new(lazy_frame: &LazyFrame, opts: &DescribeOptions) -> Result<Self> {
+        let in_memory = matches!(lazy_frame.logical_plan, DslPlan::DataFrameScan { .. });
+        let (source, schema, gap) = select_columns_with_gap(lazy_frame, opts)?;
+
+        if schema.is_empty() {
+            return Err(DescribeError::EmptySchema.into());
+        }
+        if opts.strict_dtypes
+            && let Some((name, dtype)) = schema.iter().find(|(_, dtype)| is_degraded_dtype(dtype))
+        {
+            return Err(DescribeError::UnsupportedDtype {
+                column: name.to_string(),
+                dtype: dtype.clone(),
+                expected: "describable (not nested, binary, unknown or feature-gated)",
+            }
+            .into());
+        }
+
+        // Default percentiles if not provided
+        let percentiles = resolved_percentiles(opts)?;
+
+        // List and byte length rows only appear when there is a column of
+        // that dtype to describe
+        let has_lists = schema
+            .iter_values()
+            .any(|dtype| matches!(dtype, DataType::List(_)));
+        let has_binary = schema.iter_values().any(|dtype| dtype == &DataType::Binary);
+
+        let mut rows = metric_rows(&percentiles, opts)?;
+        if has_lists {
+            rows.extend(ListMetric::ALL.into_iter().map(MetricRow::List));
+        }
+        if has_binary {
+            rows.extend(BinaryMetric::ALL.into_iter().map(MetricRow::Binary));
+        }
+
+        Ok(Self {
+            source,
+            in_memory,
+            schema,
+            gap,
+            percentiles,
+            rows,
+            has_lists,
+            has_binary,
+        })
+    }
+
+    /// Metric expressions of one column, in `rows` order, followed by the
+    /// columns read by `check_metrics`
+    fn column_exprs(
+        &self,
+        col_name: &PlSmallStr,
+        dtype: &DataType,
+        opts: &DescribeOptions,
+    ) -> Vec<Expr> {
+        let mut exprs = column_metric_exprs(
+            polars::lazy::dsl::col(col_name.clone()),
+            col_name,
+            dtype,
+            &self.percentiles,
+            opts,
+        );
+        if self.has_lists {
+            exprs.extend(list_metric_exprs(col(col_name.clone()), col_name, dtype));
+        }
+        if self.has_binary {
+            exprs.extend(binary_metric_exprs(col(col_name.clone()), col_name, dtype));
+        }
+        if opts.nan_is_error && dtype.is_float() {
+            exprs.push(
+                col(col_name.clone())
+                    .is_nan()
+                    .any(true)
+                    .alias(nan_check_alias(col_name)),
+            );
+        }
+        if opts.strict_counts {
+            exprs.push(
+                len()
+                    .cast(DataType::UInt64)
+                    .alias(total_rows_check_alias(col_name)),
+            );
+        }
+        exprs
+    }
+
+    /// The checks of `DescribeOptions::weight_col`, run once with the first
+    /// batch rather than per column
+    fn weight_check_exprs(&self, opts: &DescribeOptions) -> Vec<Expr> {
+        opts.weight_col
+            .as_deref()
+            .map(|weight_col| weight_check_exprs(weight_col).to_vec())
+            .unwrap_or_default()
+    }
+
+    /// Whether the batches run in parallel: in-memory frames on the
+    /// in-memory engine
+    fn parallel(&self, opts: &DescribeOptions) -> bool {
+        self.in_memory && opts.engine == Engine::InMemory
+    }
+
+    /// Whether `batches` are collected one after the other, which a lazy
+    /// plan can't express
+    pub(crate) fn sequential(&self, opts: &DescribeOptions) -> bool {
+        !self.parallel(opts) && self.schema.len() > self.batch_columns(opts)
+    }
+
+    /// Columns per batch: `DescribeOptions::batch_columns`, by default
+    /// `METRIC_BATCH_COLUMNS` when the batches run in parallel and
+    /// `SCAN_BATCH_COLUMNS` otherwise
+    fn batch_columns(&self, opts: &DescribeOptions) -> usize {
+        opts.batch_columns.unwrap_or(if self.parallel(opts) {
+            METRIC_BATCH_COLUMNS
+        } else {
+            SCAN_BATCH_COLUMNS
+        })
+    }
+
+    /// The single-row aggregation of every metric, as one query over the
+    /// `batches` concatenated horizontally
+    fn aggregation(&self, opts: &DescribeOptions) -> Result<LazyFrame> {
+        Ok(concat_batches(self.batches(opts))?)
+    }
+
+    /// The selects of `aggregation`, each with its number of columns
+    fn batches(&self, opts: &DescribeOptions) -> Vec<(usize, LazyFrame)> {
+        let columns: Vec<(&PlSmallStr, &DataType)> = self.schema.iter().collect();
+        let batch_columns = self.batch_columns(opts);
+        columns
+            .chunks(batch_columns)
+            .enumerate()
+            .map(|(i, batch)| {
+                let mut exprs: Vec<Expr> = batch
+                    .iter()
+                    .flat_map(|(col_name, dtype)| self.column_exprs(col_name, dtype, opts))
+                    .collect();
+                if i == 0 {
+                    exprs.extend(self.weight_check_exprs(opts));
+                }
+                (batch.len(), self.source.clone().select(exprs))
+            })
+            .collect()
+    }
+}
+
+/// Concatenate the batch selects horizontally into one query
+fn concat_batches(mut batches: Vec<(usize, LazyFrame)>) -> PolarsResult<LazyFrame> {
+    if batches.len() == 1 {
+        return Ok(batches.pop().unwrap().1);
+    }
+    let batches = batches
+        .into_iter()
+        .map(|(_, batch)| batch)
+        .collect::<Vec<_>>();
+    concat_lf_horizontal(batches, UnionArgs::default())
+}
+
+/// Collect the batch selects one after the other, reporting each batch and
+/// checking for cancellation in between
+fn collect_batches(
+    batches: Vec<(usize, LazyFrame)>,
+    columns_total: usize,
+    opts: &DescribeOptions,
+    started: Instant,
+) -> Result<DataFrame> {
+    let mut columns = Vec::new();
+    let mut columns_done = 0;
+    for (n_columns, batch) in batches {
+        opts.check_interrupted(started)?;
+        columns.extend(opts.engine.collect(batch)?.take_columns());
+        columns_done += n_columns;
+        opts.progress(Progress::Batch {
+            columns_done,
+            columns_total,
+        });
+    }
+    Ok(DataFrame::new(columns)?)
+}
+
+/// Fail on what the check columns of the aggregation found: bad weights,
+/// with `nan_is_error` NaN values and with `strict_counts` counts
+/// not adding up to the frame height
+fn check_metrics(df_metrics: &DataFrame, schema: &Schema, opts: &DescribeOptions) -> Result<()> {
+    if let Some(weight_col) = &opts.weight_col {
+        check_weights(df_metrics, weight_col)?;
+    }
+
+    if opts.nan_is_error {
+        for (col_name, dtype) in schema.iter() {
+            if dtype.is_float()
+                && df_metrics
+                    .column(&nan_check_alias(col_name))?
+                    .get(0)?
+                    .extract_bool()
+                    == Some(true)
+            {
+                return Err(DescribeError::NanValues(col_name.to_string()).into());
+            }
+        }
+    }
+
+    if opts.strict_counts {
+        for col_name in schema.iter_names() {
+            let value = |alias: &str| -> Result<u64> {
+                Ok(df_metrics
+                    .column(alias)?
+                    .get(0)?
+                    .extract::<u64>()
+                    .unwrap_or(0))
+            };
+            let count = value(&MetricRow::Count.alias(&[], col_name))?;
+            let null_count = value(&MetricRow::NullCount.alias(&[], col_name))?;
+            let total_rows = value(&total_rows_check_alias(col_name))?;
+            if count + null_count != total_rows {
+                return Err(DescribeError::InconsistentCounts {
+                    column: col_name.to_string(),
+                    count,
+                    null_count,
+                    total_rows,
+                }
+                .into());
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Keep the percentiles of numeric columns in order: min <= p_i <= p_j <=
+/// max for p_i < p_j
+///
+/// Interpolation between floats can land a percentile slightly outside its
+/// neighbours. An offending percentile is a `NonMonotoneQuantiles` error
+/// under `strict_quantiles`; otherwise it is clamped into place and
+/// reported through the warning hook.
+fn check_quantile_order(
+    df_metrics: &mut DataFrame,
+    schema: &Schema,
+    percentiles: &[f64],
+    opts: &DescribeOptions,
+) -> Result<()> {
+    let mut order: Vec<usize> = (0..percentiles.len()).collect();
+    order.sort_by(|a, b| percentiles[*a].total_cmp(&percentiles[*b]));
+    let value = |frame: &DataFrame, alias: &str| -> Result<Option<f64>> {
+        Ok(frame
+            .column(alias)?
+            .get(0)?
+            .extract::<f64>()
+            .filter(|v| !v.is_nan()))
+    };
+
+    for (col_name, dtype) in schema.iter() {
+        if !dtype.is_primitive_numeric() {
+            continue;
+        }
+        let bounds = (
+            value(df_metrics, &MetricRow::Min.alias(percentiles, col_name))?,
+            value(df_metrics, &MetricRow::Max.alias(percentiles, col_name))?,
+        );
+        let (Some(mut floor), Some(max)) = bounds else {
+            continue;
+        };
+        for &i in &order {
+            let alias = MetricRow::Percentile(i).alias(percentiles, col_name);
+            let Some(v) = value(df_metrics, &alias)? else {
+                continue;
+            };
+            if (floor..=max).contains(&v) {
+                floor = v;
+                continue;
+            }
+            let label = percentile_label(percentiles[i]);
+            if opts.strict_quantiles {
+                return Err(DescribeError::NonMonotoneQuantiles {
+                    column: col_name.to_string(),
+                    percentile: label,
+                }
+                .into());
+            }
+            let clamped = v.clamp(floor, max);
+            opts.warn(&format!(
+                "clamped the {label} percentile of column '{col_name}' from {v} to {clamped}"
+            ));
+            let dtype = df_metrics.column(&alias)?.dtype().clone();
+            df_metrics.with_column(Column::new(alias.into(), [clamped]).cast(&dtype)?)?;
+            floor = clamped;
+        }
+    }
+    Ok(())
+}
+
+/// Whether columns of `dtype` only get counts, with null value statistics
+///
+/// Nested and unknown dtypes have no value statistics at all. Raw bytes have
+/// no meaningful ordering; Binary columns get byte length rows instead, as
+/// List columns get list length rows. Null columns aren't degraded: they
+/// have no values to describe. Dtypes whose cargo feature is disabled
+/// are degraded too, see `is_dtype_enabled()`.
+pub(crate) fn is_degraded_dtype(dtype: &DataType) -> bool {
+    dtype.is_nested()
+        || dtype.is_object()
+        || matches!(dtype, DataType::Binary | DataType::Unknown(_))
+        || !is_dtype_enabled(dtype)
+}
+
+/// Whether the cargo feature describing `dtype` is enabled
+///
+/// Temporal, Decimal and Categorical/Enum values are described with the
+/// `temporal`, `decimal` and `categorical` features. Without them, such
+/// columns only get counts, and `strict()` rejects them.
+pub(crate) fn is_dtype_enabled(dtype: &DataType) -> bool {
+    (cfg!(feature = "temporal") || !dtype.is_temporal())
+        && (cfg!(feature = "decimal") || !dtype.is_decimal())
+        && (cfg!(feature = "categorical") || !(dtype.is_categorical() || dtype.is_enum()))
+}
+
+/// Metric expressions for a single column, aliased `<metric>:<col_name>`
+///
+/// `col` is the expression being described; it is usually `col(col_name)`
+/// but can be any expression evaluating to `dtype`.
+pub(crate) fn column_metric_exprs(
+    col: Expr,
+    col_name: &str,
+    dtype: &DataType,
+    percentiles: &[f64],
+    opts: &DescribeOptions,
+) -> Vec<Expr> {
+    use polars::lazy::dsl;
+    use polars::prelude::{NULL, QuantileMethod};
+
+    // Helper to check if we skip min/max
+    let skip_minmax =
+        |dtype: &DataType| -> bool { is_degraded_dtype(dtype) || dtype == &DataType::Null };
+
+    let col_name_str = col_name.to_string();
+
+    // Determine if numeric or temporal
+    let is_numeric = dtype.is_numeric();
+    let is_temporal = !is_numeric && dtype.is_temporal() && is_dtype_enabled(dtype);
+
+    // Count expressions - for all columns, as UInt64 whatever IdxSize is
+    let count_expr = col
+        .clone()
+        .count()
+        .cast(DataType::UInt64)
+        .alias(format!("count:{col_name_str}"));
+    let null_count_expr = col
+        .clone()
+        .null_count()
+        .cast(DataType::UInt64)
+        .alias(format!("null_count:{col_name_str}"));
+
+    // Decimal aggregations go through Float64; min/max below stay native
+    // so they keep the column's scale
+    let float_input = if dtype.is_decimal() {
+        col.clone().cast(DataType::Float64)
+    } else {
+        col.clone()
+    };
+
+    // describe_weighted: weighted mean, std and percentiles for numeric columns
+    let weighted = opts
+        .weight_col
+        .as_deref()
+        .filter(|_| is_numeric)
+        .map(|weight_col| WeightedValues::new(float_input.clone(), weight_col));
+    let weighted_quantiles = opts
+        .weight_col
+        .as_deref()
+        .filter(|_| is_numeric)
+        .map(|weight_col| WeightedValues::new(quantile_input(&float_input), weight_col));
+
+    // Mean - for temporal, numeric, or boolean
+    let high_precision = opts.high_precision && is_numeric;
+    let mean_expr = if let Some(weighted) = &weighted {
+        weighted.mean()
+    } else if high_precision {
+        compensated_mean(&float_input)
+    } else if is_temporal || is_numeric || dtype == &DataType::Boolean {
+        if dtype == &DataType::Boolean {
+            col.clone().cast(DataType::Float64).mean()
+        } else {
+            float_input.clone().mean()
+        }
+    } else {
+        dsl::lit(NULL).cast(DataType::Float64)
+    };
+    let mean_expr = mean_expr.alias(format!("mean:{col_name_str}"));
+
+    // Durations and times are aggregated on their physical integers and
+    // cast back, so the results render in the column's own unit
+    let is_duration = is_temporal && matches!(dtype, DataType::Duration(_));
+    let is_time = is_temporal && dtype == &DataType::Time;
+    let physical = |expr: Expr| expr.cast(DataType::Int64).cast(dtype.clone());
+
+    // Standard deviation - for numeric and durations
+    let std_expr = if let Some(weighted) = &weighted {
+        weighted.std()
+    } else if high_precision {
+        compensated_std(&float_input)
+    } else if is_numeric {
+        float_input.clone().std(1) // ddof=1 for sample std
+    } else if is_duration {
+        physical(col.clone().to_physical().cast(DataType::Float64).std(1))
+    } else {
+        dsl::lit(NULL).cast(DataType::Float64)
+    };
+    let std_expr = std_expr.alias(format!("std:{col_name_str}"));
+
+    // String statistics see the normalized values; counts above stay raw
+    let values = string_metric_input(col.clone(), dtype, opts);
+
+    // Min/Max - based on skip_minmax
+    let min_expr = if skip_minmax(dtype) {
+        dsl::lit(NULL).cast(DataType::Float64)
+    } else {
+        values.clone().min()
+    };
+    let min_expr = min_expr.alias(format!("min:{col_name_str}"));
+
+    let max_expr = if skip_minmax(dtype) {
+        dsl::lit(NULL).cast(DataType::Float64)
+    } else {
+        values.clone().max()
+    };
+    let max_expr = max_expr.alias(format!("max:{col_name_str}"));
+
+    // Per-column overrides (`for_column`) can leave out the value rows
+    let counts_only = opts
+        .column_opts(col_name)
+        .is_some_and(|column_opts| column_opts.counts_only);
+
+    // Null-dtype columns hold no values: besides the counts (and the flags
+    // defined without values, such as all_null) every row is an untyped
+    // null rather than a Float64 one
+    let is_null_dtype = dtype == &DataType::Null;
+
+    // Percentiles - for numeric types, durations and times
+    let mut pct_exprs = Vec::new();
+    for (i, p) in percentiles.iter().enumerate() {
+        let pct_expr = if is_null_dtype {
+            dsl::lit(NULL)
+        } else if counts_only || !opts.percentile_requested(col_name, *p) {
+            dsl::lit(NULL).cast(DataType::Float64)
+        } else if let Some(weighted) = &weighted_quantiles {
+            weighted.quantile(*p)
+        } else if is_numeric {
+            quantile_input(&float_input).quantile(dsl::lit(*p), QuantileMethod::Linear)
+        } else if is_duration || is_time {
+            physical(
+                col.clone()
+                    .to_physical()
+                    .quantile(dsl::lit(*p), QuantileMethod::Linear),
+            )
+        } else {
+            dsl::lit(NULL).cast(DataType::Float64)
+        };
+        pct_exprs.push(pct_expr.alias(format!("{p}:{i}:{col_name_str}")));
+    }
+
+    let [mean_expr, std_expr, min_expr, max_expr] = if counts_only || is_null_dtype {
+        [
+            MetricRow::Mean,
+            MetricRow::Std,
+            MetricRow::Min,
+            MetricRow::Max,
+        ]
+        .map(|row| {
+            let null = if is_null_dtype {
+                dsl::lit(NULL)
+            } else {
+                dsl::lit(NULL).cast(DataType::Float64)
+            };
+            null.alias(row.alias(percentiles, col_name))
+        })
+    } else {
+        [mean_expr, std_expr, min_expr, max_expr]
+    };
+
+    // Add all expressions for this column
+    let mut metric_exprs = vec![count_expr, null_count_expr, mean_expr, std_expr, min_expr];
+    metric_exprs.extend(pct_exprs);
+    metric_exprs.push(max_expr);
+
+    // Optional statistics requested through the options
+    for stat in opts.all_statistics() {
+        let expr = if opts.statistic_requested(col_name, stat) {
+            statistic_expr(stat, &values, dtype, opts)
+        } else {
+            dsl::lit(NULL)
+        };
+        metric_exprs.push(expr.alias(MetricRow::Extra(stat).alias(percentiles, col_name)));
+    }
+    for (i, custom) in opts.custom_stats.iter().enumerate() {
+        let expr = if counts_only || is_null_dtype {
+            dsl::lit(NULL)
+        } else {
+            (custom.expr)(col.clone(), dtype)
+        };
+        metric_exprs.push(expr.alias(MetricRow::Custom(i).alias(percentiles, col_name)));
+    }
+    metric_exprs
+}
+
+/// The values string statistics (min/max, n_unique) are computed over
+///
+/// This is the single place string normalization such as trimming is
+/// applied. Categorical columns are compared as their category strings so
+/// min/max are lexical regardless of the physical encoding; Enum columns keep
+/// their declared category order. Other columns pass through unchanged.
+fn string_metric_input(col: Expr, dtype: &DataType, opts: &DescribeOptions) -> Expr {
+    match dtype {
+        DataType::String => opts.trim_strings.apply(col),
+        dtype if dtype.is_categorical() => col.cast(DataType::String),
+        _ => col,
+    }
+}
+
+/// Internal implementation that works purely with LazyFrame
+pub(crate) fn describe_lazy_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    match opts.compat {
+        // Checked outside the `describe` UDF of `describe_plan_impl`, whose
+        // errors can only be Polars errors, so failed checks stay
+        // `DescribeError`s
+        Compat::Native => reshape(&compute_metrics(lazy_frame, opts)?, opts),
+        Compat::PyPolars | Compat::Pandas => {
+            Ok(opts.engine.collect(describe_plan_impl(lazy_frame, opts)?)?)
+        }
+    }
+}
+
+/// The native string layout of `describe_with()`, whatever
+/// `DescribeOptions::compat` asks for
+///
+/// Renderers (HTML, Markdown, pretty tables, CSV files) read the statistics
+/// as strings, which the Float64 columns and extra rows of the compat
+/// layouts don't fit.
+pub(crate) fn describe_native_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    if opts.compat == Compat::Native {
+        return describe_lazy_impl(lazy_frame, opts);
+    }
+    let native = DescribeOptions {
+        compat: Compat::Native,
+        ..opts.clone()
+    };
+    describe_lazy_impl(lazy_frame, &native)
+}
+
+/// The describe output as a lazy plan: the aggregation followed by a
+/// `describe` UDF reshaping its single row
+///
+/// Compat layouts, `skip_errors`, `Statistic::EstimatedSize` and lazy
+/// sources too wide for a single batch need more than one pass, progress
+/// hooks and cancellation act on the stages of the
+/// aggregation, and null-based column orders decide the output schema from
+/// the data; they are computed right away and wrapped in a LazyFrame.
+pub(crate) fn describe_plan_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<LazyFrame> {
+    match opts.compat {
+        Compat::Native
+            if opts.skip_errors
+                || opts.progress_hook.is_some()
+                || opts.interruptible()
+                || opts.order_by.needs_counts()
+                || opts.all_statistics().contains(&Statistic::EstimatedSize) =>
+        {
+            Ok(reshape(&compute_metrics(lazy_frame, opts)?, opts)?.lazy())
+        }
+        Compat::Native => {
+            let started = Instant::now();
+            let plan = MetricsPlan::new(lazy_frame, opts)?;
+            if plan.sequential(opts) {
+                let metrics = compute_planned_metrics(plan, opts, started)?;
+                return Ok(reshape(&metrics, opts)?.lazy());
+            }
+            let aggregation = plan.aggregation(opts)?;
+
+            let mut output = Schema::default();
+            output.insert("statistic".into(), DataType::String);
+            let names: Vec<&str> = plan.schema.iter_names().map(PlSmallStr::as_str).collect();
+            let order = plan.gap.arrange(&opts.order_by, &names, &[]);
+            for (position, i) in order.into_iter().enumerate() {
+                if plan.gap.skipped > 0 && position == plan.gap.head {
+                    output.insert(GAP_COLUMN.into(), DataType::String);
+                }
+                output.insert(names[i].into(), DataType::String);
+            }
+            let output = Arc::new(output);
+
+            let opts = opts.clone();
+            let udf = move |frame: DataFrame| -> PolarsResult<DataFrame> {
+                checked_metrics(
+                    frame,
+                    plan.schema.clone(),
+                    plan.percentiles.clone(),
+                    plan.rows.clone(),
+                    plan.gap,
+                    &opts,
+                )
+                .and_then(|metrics| reshape(&metrics, &opts))
+                .map_err(|err| polars_err!(ComputeError: "{err:#}"))
+            };
+            // The UDF changes every column, so nothing may be pushed through it
+            Ok(aggregation.map(
+                udf,
+                AllowedOptimizations::empty(),
+                Some(Arc::new(move |_: &Schema| Ok(output.clone()))),
+                Some("describe"),
+            ))
+        }
+        Compat::PyPolars => Ok(describe_pypolars_impl(lazy_frame, opts)?.lazy()),
+        Compat::Pandas => Ok(describe_pandas_impl(lazy_frame, opts)?.lazy()),
+    }
+}
+
+/// Render checked metrics and report the reshape, the last step of both
+/// the eager and the lazy native describe
+fn reshape(metrics: &Metrics, opts: &DescribeOptions) -> Result<DataFrame> {
+    let stats = render_metrics(metrics, opts)?;
+    opts.progress(Progress::ReshapeDone);
+    Ok(stats)
+}
+
+/// Reshape the single-row aggregation result into the string describe layout
+pub(crate) fn render_metrics(metrics: &Metrics, opts: &DescribeOptions) -> Result<DataFrame> {
+    let Metrics {
+        percentiles, rows, ..
+    } = metrics;
+
+    // Reshape the wide result into the final format
+    let mut result_columns = Vec::new();
+
+    // Add the statistic column first
+    let labels: Vec<String> = rows
+        .iter()
+        .map(|row| row.label(percentiles, opts))
+        .collect();
+    result_columns.push(Series::new("statistic".into(), labels).into());
+
+    // Process each column's metrics
+    for (col_name, dtype) in metrics.ordered_columns(opts)? {
+        let col_name_str = col_name.to_string();
+        let mut col_values = Vec::new();
+
+        // Helper to format values based on type
+        let is_numeric_result = dtype.is_numeric()
+            || dtype.is_nested()
+            || matches!(dtype, DataType::Null | DataType::Boolean);
+
+        // Extract each metric for this column
+        for (row, val) in rows.iter().zip(metrics.column_values(col_name)?) {
+            // Format based on type and metric
+            let formatted = if val.is_null() {
+                "null".to_string()
+            } else if matches!(
+                row,
+                MetricRow::Count | MetricRow::NullCount | MetricRow::Extra(Statistic::TotalRows)
+            ) {
+                format_count(&val)
+            } else if *row == MetricRow::Extra(Statistic::EstimatedSize) {
+                format_bytes(val.extract::<u64>().unwrap_or(0))
+            } else if matches!(
+                row,
+                MetricRow::Extra(Statistic::UniqueRatio | Statistic::TopFreqRatio)
+            ) {
+                format_percent(&val, opts)
+            } else if is_numeric_result
+                && matches!(
+                    row,
+                    MetricRow::Mean
+                        | MetricRow::Std
+                        | MetricRow::Percentile(_)
+                        | MetricRow::Extra(
+                            Statistic::Iqr
+                                | Statistic::Range
+                                | Statistic::Cv
+                                | Statistic::Sem
+                                | Statistic::Mad
+                                | Statistic::GeoMean
+                                | Statistic::HarmonicMean
+                                | Statistic::TrimmedMean
+                                | Statistic::MeanCiLow
+                                | Statistic::MeanCiHigh
+                                | Statistic::JarqueBera
+                                | Statistic::JarqueBeraPValue
+                        )
+                )
+                || matches!(
+                    row,
+                    MetricRow::List(ListMetric::LenMean)
+                        | MetricRow::Binary(BinaryMetric::Mean)
+                        | MetricRow::Extra(Statistic::LenMean)
+                )
+                || matches!(row, MetricRow::Custom(_))
+                    && matches!(val, AnyValue::Float32(_) | AnyValue::Float64(_))
+            {
+                // mean, std and percentiles for numeric - configured float formatting
+                format_float(&val, opts)
+            } else {
+                // min/max, percentiles and temporal means in the
+                // column's native representation
+                format_value(&val)
+            };
+
+            col_values.push(formatted);
+        }
+
+        // Add this column's values to the result
+        result_columns.push(Series::new(col_name_str.into(), col_values).into());
+    }
+
+    if metrics.gap.skipped > 0 {
+        let gap = vec![GAP_COLUMN; rows.len()];
+        result_columns.insert(
+            1 + metrics.gap.head,
+            Series::new(GAP_COLUMN.into(), gap).into(),
+        );
+    }
+
+    DataFrame::new(result_columns).map_err(Into::into)
+}
+
+/// Render one describe output per row of a grouped aggregation result and
+/// stack them, each prefixed with the row's `keys` columns
+///
+/// `groups` holds the key columns followed by the metric columns of
+/// `template` (whose `frame` is ignored). Without groups the result is an
+/// empty frame with the same columns.
+pub(crate) fn render_grouped_metrics(
+    groups: &DataFrame,
+    keys: &[&str],
+    template: &Metrics,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let key_columns = keys
+        .iter()
+        .map(|key| groups.column(key))
+        .collect::<PolarsResult<Vec<_>>>()?;
+    let render = |frame: DataFrame| {
+        render_metrics(
+            &Metrics {
+                schema: template.schema.clone(),
+                percentiles: template.percentiles.clone(),
+                rows: template.rows.clone(),
+                frame,
+                gap: template.gap,
+            },
+            opts,
+        )
+    };
+
+    if groups.height() == 0 {
+        let mut columns: Vec<Column> = key_columns.iter().map(|key| key.clear()).collect();
+        columns.extend(
+            std::iter::once(&PlSmallStr::from("statistic"))
+                .chain(template.schema.iter_names())
+                .map(|name| Column::new_empty(name.clone(), &DataType::String)),
+        );
+        return DataFrame::new(columns).map_err(Into::into);
+    }
+
+    let mut frames = Vec::with_capacity(groups.height());
+    for i in 0..groups.height() {
+        #[allow(clippy::cast_possible_wrap)]
+        let frame = groups.slice(i as i64, 1).drop_many(keys.iter().copied());
+        let mut stats = render(frame)?;
+        for (j, key) in key_columns.iter().enumerate() {
+            stats.insert_column(j, key.new_from_index(i, stats.height()))?;
+        }
+        frames.push(stats.lazy());
+    }
+    concat(frames, UnionArgs::default())?
+        .collect()
+        .map_err(Into::into)
+}
+
+/// Build a typed report from the same aggregation pass used by `describe()`
+pub(crate) fn describe_report_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DescribeReport> {
+    let metrics = compute_metrics(lazy_frame, opts)?;
+    let Metrics {
+        schema,
+        percentiles,
+        rows,
+        frame: df_metrics,
+        ..
+    } = &metrics;
+
+    let mut columns = Vec::with_capacity(schema.len());
+    for (col_name, dtype) in schema.iter() {
+        let is_numeric = dtype.is_numeric() || dtype == &DataType::Boolean;
+        let values = metrics.column_values(col_name)?;
+        let metric = |row: MetricRow| -> Result<AnyValue<'_>> {
+            let i = rows.iter().position(|r| *r == row).ok_or_else(|| {
+                DescribeError::Internal(format!("no {} metric", row.label(percentiles, opts)))
+            })?;
+            Ok(values[i].clone())
+        };
+
+        let mut pct_values = std::collections::BTreeMap::new();
+        for (i, p) in percentiles.iter().enumerate() {
+            if let Some(v) = metric(MetricRow::Percentile(i))?.extract::<f64>() {
+                pct_values.insert(percentile_label(*p), v);
+            }
+        }
+
+        // Only numeric values are meaningful as f64: extract() would happily
+        // parse strings or return the physical value of temporal columns
+        let as_f64 = |val: AnyValue<'_>| {
+            if is_numeric {
+                val.extract::<f64>()
+            } else {
+                None
+            }
+        };
+
+        columns.push(ColumnSummary {
+            name: col_name.to_string(),
+            dtype: dtype.to_string(),
+            count: metric(MetricRow::Count)?.extract::<u64>().unwrap_or(0),
+            null_count: metric(MetricRow::NullCount)?.extract::<u64>().unwrap_or(0),
+            mean: as_f64(metric(MetricRow::Mean)?),
+            std: as_f64(metric(MetricRow::Std)?),
+            min: StatValue::from_any_value(&metric(MetricRow::Min)?),
+            max: StatValue::from_any_value(&metric(MetricRow::Max)?),
+            percentiles: pct_values,
+            histogram: None,
+            carried_forward: false,
+            degraded: is_degraded_dtype(dtype),
+            estimated_size: if opts.all_statistics().contains(&Statistic::EstimatedSize) {
+                metric(MetricRow::Extra(Statistic::EstimatedSize))?.extract::<u64>()
+            } else {
+                None
+            },
+            source_digest: None,
+        });
+    }
+
+    if let Some(n_bins) = opts.histogram_bins {
+        attach_histograms(lazy_frame, schema, df_metrics, &mut columns, n_bins)?;
+    }
+    let names: Vec<&str> = columns.iter().map(|c| c.name.as_str()).collect();
+    let counts: Vec<(u64, u64)> = columns.iter().map(|c| (c.count, c.null_count)).collect();
+    let order = metrics.gap.arrange(&opts.order_by, &names, &counts);
+    let mut columns: Vec<Option<ColumnSummary>> = columns.into_iter().map(Some).collect();
+    let columns = order
+        .into_iter()
+        .filter_map(|i| columns[i].take())
+        .collect();
+    opts.progress(Progress::ReshapeDone);
+
+    Ok(DescribeReport {
+        columns,
+        skipped_columns: metrics.gap.skipped,
+    })
+}
+
+fn describe_map_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<BTreeMap<String, ColumnSummary>> {
+    let report = describe_report_impl(lazy_frame, opts)?;
+    Ok(report
+        .columns
+        .into_iter()
+        .map(|summary| (summary.name.clone(), summary))
+        .collect())
+}
+
+/// Compute histograms for the numeric columns of the report in one extra pass
+fn attach_histograms(
+    lazy_frame: &LazyFrame,
+    schema: &Schema,
+    df_metrics: &DataFrame,
+    columns: &mut [ColumnSummary],
+    n_bins: usize,
+) -> Result<()> {
+    // Columns without a finite range (all null, NaN extremes) get no histogram
+    let mut targets = Vec::new();
+    let mut ranges = Vec::new();
+    for (idx, (col_name, dtype)) in schema.iter().enumerate() {
+        if !dtype.is_numeric() {
+            continue;
+        }
+        let min = df_metrics
+            .column(&MetricRow::Min.alias(&[], col_name))?
+            .get(0)?
+            .extract::<f64>();
+        let max = df_metrics
+            .column(&MetricRow::Max.alias(&[], col_name))?
+            .get(0)?
+            .extract::<f64>();
+        if let (Some(min), Some(max)) = (min, max)
+            && min.is_finite()
+            && max.is_finite()
+        {
+            targets.push(idx);
+            ranges.push((col_name.to_string(), min, max));
+        }
+    }
+
+    let histograms = compute_histograms(lazy_frame, &ranges, n_bins)?;
+    for (idx, histogram) in targets.into_iter().zip(histograms) {
+        columns[idx].histogram = Some(histogram);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::options::{CancellationToken, ColumnOpts, ColumnOrder, TrimMode};
+    use crate::statistics::z_value;
+
+    #[test]
+    fn test_describe_numeric() -> Result<()> {
+        let df = df! {
+            "ints" => [1, 2, 3, 4, 5],
+            "floats" => [1.0, 2.0, 3.0, 4.0, 5.0],
+        }?;
+
+        let stats = df.describe(None)?;
+
+        // Check shape
+        assert_eq!(stats.shape(), (9, 3)); // 9 stats x 3 columns (statistic + 2 data cols)
+
+        // Check that statistic column exists
+        assert!(stats.column("statistic").is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_with_custom_percentiles() -> Result<()> {
+        let df = df! {
+            "values" => [1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+        }?;
+
+        let stats = df.describe(Some(vec![0.1, 0.5, 0.9]))?;
+
+        // Check that we have the right number of rows
+        // count, null_count, mean, std, min, 10%, 50%, 90%, max = 9 rows
+        assert_eq!(stats.height(), 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_label_style() -> Result<()> {
+        let df = df! { "x" => [1.0, 2.0, 3.0, 4.0] }?;
+        let percentiles = vec![0.25, 0.5, 0.999];
+        let labels = |opts: DescribeOptions| -> Result<Vec<String>> {
+            let stats = df.describe_with(&opts.percentiles(percentiles.clone()))?;
+            Ok(stats
+                .column("statistic")?
+                .str()?
+                .into_no_null_iter()
+                .map(str::to_string)
+                .collect())
+        };
+
+        assert_eq!(
+            labels(DescribeOptions::new())?,
+            [
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "25%",
+                "50%",
+                "99.9%",
+                "max"
+            ]
+        );
+        let snake = labels(DescribeOptions::new().label_style(LabelStyle::Snake))?;
+        assert_eq!(
+            snake,
+            [
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "p25",
+                "p50",
+                "p99_9",
+                "max"
+            ]
+        );
+        let compat = labels(
+            DescribeOptions::new()
+                .compat(Compat::PyPolars)
+                .label_style(LabelStyle::Snake),
+        )?;
+        assert_eq!(compat, snake);
+
+        // Custom statistics collide with the labels of the chosen style
+        let custom = |style: LabelStyle| {
+            DescribeOptions::new()
+                .label_style(style)
+                .custom_stat("p25", |values, _| values.max())
+        };
+        assert!(labels(custom(LabelStyle::Human)).is_ok());
+        assert!(labels(custom(LabelStyle::Snake)).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_mixed_types() -> Result<()> {
+        let df = df! {
+            "numbers" => [1, 2, 3],
+            "strings" => ["a", "b", "c"],
+            "bools" => [true, false, true],
+        }?;
+
+        let stats = df.describe(None)?;
+
+        // Should not panic and should return stats for all columns
+        assert_eq!(stats.width(), 4); // statistic + 3 data columns
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_lazy_frame() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 3, 4, 5],
+            "b" => [10.0, 20.0, 30.0, 40.0, 50.0],
+        }?;
+
+        let lf = df.lazy();
+        let stats = lf.describe(None)?;
+
+        // Should work with LazyFrame without collecting first
+        assert_eq!(stats.shape(), (9, 3));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_owned_matches_describe_with() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "s" => ["x", "y", "z"],
+        }?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let expected = df.describe_with(&opts)?;
+
+        assert!(df.clone().describe_owned(&opts)?.equals_missing(&expected));
+        assert!(df.lazy().describe_owned(&opts)?.equals_missing(&expected));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_lazy_matches_eager() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3), Some(8)],
+            "f" => [Some(0.5), Some(-1.5), None, Some(2.0)],
+            "s" => [Some("x"), None, Some("z"), Some("y")],
+            "b" => [Some(true), Some(false), None, Some(true)],
+        }?;
+        for opts in [
+            DescribeOptions::new(),
+            DescribeOptions::new().percentiles(vec![0.1, 0.9]),
+            DescribeOptions::new().precision(2),
+            DescribeOptions::new().compat(Compat::PyPolars),
+        ] {
+            let lazy = df.describe_lazy(&opts)?;
+            assert_eq!(
+                lazy.clone()
+                    .collect_schema()?
+                    .iter_names()
+                    .collect::<Vec<_>>(),
+                df.describe_with(&opts)?.get_column_names()
+            );
+            assert!(lazy.collect()?.equals_missing(&df.describe_with(&opts)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_lazy_checks_match_eager() -> Result<()> {
+        let valid = df! {
+            "a" => [Some(1), None, Some(3)],
+            "f" => [Some(0.5), Some(-1.5), Some(2.0)],
+        }?;
+        let with_nan = df! {
+            "a" => [Some(1), None, Some(3)],
+            "f" => [Some(0.5), Some(f64::NAN), Some(2.0)],
+        }?;
+        for opts in [
+            DescribeOptions::new().strict(true),
+            DescribeOptions::new().nan_is_error(true),
+            DescribeOptions::new().strict_counts(true),
+            DescribeOptions::new().strict_quantiles(true),
+        ] {
+            let lazy = describe_plan_impl(&valid.clone().lazy(), &opts)?.collect()?;
+            assert!(lazy.equals_missing(&valid.describe_with(&opts)?));
+
+            let eager = with_nan.describe_with(&opts);
+            let lazy = describe_plan_impl(&with_nan.clone().lazy(), &opts)?.collect();
+            assert_eq!(eager.is_err(), lazy.is_err(), "{opts:?}");
+            if let (Err(eager), Err(lazy)) = (eager, lazy) {
+                assert!(lazy.to_string().contains(&eager.to_string()), "{lazy}");
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_lazy_plan_is_not_collected() -> Result<()> {
+        let lf = df! { "a" => [1, 2, 3], "s" => ["x", "y", "z"] }?
+            .lazy()
+            .filter(col("a").gt(lit(1)));
+        let plan = lf.describe_lazy(&DescribeOptions::new())?.explain(true)?;
+        assert!(plan.contains("describe"), "{plan}");
+        // An eager describe would show up as a scan of the finished output
+        assert!(!plan.contains("\"statistic\""), "{plan}");
+
+        let stats = lf.describe_lazy(&DescribeOptions::new())?.collect()?;
+        assert_eq!(cell(&stats, "count", "a")?, "2");
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_stat_spread() -> Result<()> {
+        let df = df! {
+            "x" => [Some(4.0), Some(-1.5), None, Some(2.0)],
+            "n" => [3, 10, 7, 1],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::NUnique)
+            .custom_stat("spread", |col, _| col.clone().max() - col.min());
+        let stats = df.describe_with(&opts)?;
+
+        let labels = stats.column("statistic")?.str()?;
+        assert_eq!(labels.get(stats.height() - 2), Some("n_unique"));
+        assert_eq!(labels.get(stats.height() - 1), Some("spread"));
+        assert_eq!(cell(&stats, "spread", "x")?, "5.5");
+        assert_eq!(cell(&stats, "spread", "n")?, "9");
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_stat_null_for_other_dtypes() -> Result<()> {
+        let df = df! {
+            "x" => [1.0, 2.0, 3.0, 10.0],
+            "s" => ["a", "b", "c", "d"],
+        }?;
+        let opts = DescribeOptions::new().custom_stat("p90_minus_p50", |col, dtype| {
+            if dtype.is_numeric() {
+                col.clone().quantile(lit(0.9), QuantileMethod::Linear) - col.median()
+            } else {
+                lit(NULL)
+            }
+        });
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "p90_minus_p50", "x")?, "5.4");
+        assert_eq!(cell(&stats, "p90_minus_p50", "s")?, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn test_custom_stat_name_collisions() -> Result<()> {
+        let df = df! { "x" => [1.0, 2.0] }?;
+        for name in ["mean", "25%", "n_unique", "range"] {
+            let opts = DescribeOptions::new().custom_stat(name, |col, _| col.sum());
+            let err = df.describe_with(&opts).unwrap_err();
+            assert!(err.to_string().contains("collides"), "{name}: {err}");
+        }
+        let twice = DescribeOptions::new()
+            .custom_stat("total", |col, _| col.sum())
+            .custom_stat("total", |col, _| col.sum());
+        assert!(df.describe_with(&twice).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_column_overrides_union_rows() -> Result<()> {
+        let latency: Vec<f64> = (1..=1000).map(f64::from).collect();
+        let ids: Vec<i64> = (0..1000).collect();
+        let df = df! {
+            "latency_ms" => latency,
+            "user_id" => ids,
+            "score" => vec![1.0; 1000],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::NUnique)
+            .for_column(
+                "latency_ms",
+                ColumnOpts::new()
+                    .percentiles(vec![0.5, 0.9, 0.99, 0.999])
+                    .statistics(vec![]),
+            )
+            .for_column("user_id", ColumnOpts::new().counts_only());
+        let stats = df.describe_with(&opts)?;
+
+        let labels: Vec<&str> = stats
+            .column("statistic")?
+            .str()?
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "25%",
+                "50%",
+                "75%",
+                "90%",
+                "99%",
+                "99.9%",
+                "max",
+                "n_unique"
+            ]
+        );
+        assert_eq!(cell(&stats, "99.9%", "latency_ms")?, "999.001");
+        assert_eq!(cell(&stats, "99.9%", "score")?, "null");
+        assert_eq!(cell(&stats, "25%", "latency_ms")?, "null");
+        assert_eq!(cell(&stats, "25%", "score")?, "1.0");
+        assert_eq!(cell(&stats, "n_unique", "latency_ms")?, "null");
+        assert_eq!(cell(&stats, "n_unique", "score")?, "1");
+
+        assert_eq!(cell(&stats, "count", "user_id")?, "1000");
+        for row in ["mean", "min", "50%", "max", "n_unique"] {
+            assert_eq!(cell(&stats, row, "user_id")?, "null", "{row}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_arg_min_arg_max_positions() -> Result<()> {
+        let df = df! {
+            "tie" => [5, 1, 9, 1, 9],
+            "leading_nulls" => [None, None, Some(3.5), Some(-2.0), Some(8.0)],
+            "name" => ["kiwi", "apple", "pear", "fig", "apple"],
+            "flag" => [true, false, true, true, false],
+            "nested" => [
+                Series::new("".into(), [1]),
+                Series::new("".into(), [2]),
+                Series::new("".into(), [3]),
+                Series::new("".into(), [4]),
+                Series::new("".into(), [5]),
+            ],
+        }?;
+        #[cfg(feature = "temporal")]
+        let df = df
+            .lazy()
+            .with_column(col("leading_nulls").cast(DataType::Date).alias("day"))
+            .collect()?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::ArgMin)
+            .statistic(Statistic::ArgMax);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "arg_min", "tie")?, "1");
+        assert_eq!(cell(&stats, "arg_max", "tie")?, "2");
+        assert_eq!(cell(&stats, "arg_min", "leading_nulls")?, "3");
+        assert_eq!(cell(&stats, "arg_max", "leading_nulls")?, "4");
+        assert_eq!(cell(&stats, "arg_min", "name")?, "1");
+        assert_eq!(cell(&stats, "arg_max", "name")?, "2");
+        assert_eq!(cell(&stats, "arg_min", "flag")?, "1");
+        assert_eq!(cell(&stats, "arg_max", "flag")?, "0");
+        #[cfg(feature = "temporal")]
+        assert_eq!(cell(&stats, "arg_min", "day")?, "3");
+        assert_eq!(cell(&stats, "arg_min", "nested")?, "null");
+
+        // Positions refer to the lazy frame's own row order
+        let sorted = df
+            .lazy()
+            .sort(
+                ["tie"],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .describe_with(&opts)?;
+        assert_eq!(cell(&sorted, "arg_min", "tie")?, "0");
+        assert_eq!(cell(&sorted, "arg_max", "tie")?, "3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_first_and_last_valid_index() -> Result<()> {
+        let df = df! {
+            "late_start" => [None, None, None, Some(1.5), Some(2.5), None],
+            "full" => ["a", "b", "c", "d", "e", "f"],
+            "all_null" => [None::<i32>, None, None, None, None, None],
+            "nested" => [
+                None,
+                Some(Series::new("".into(), [1])),
+                None,
+                None,
+                Some(Series::new("".into(), [2])),
+                None,
+            ],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::FirstValidIndex)
+            .statistic(Statistic::LastValidIndex);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "first_valid_index", "late_start")?, "3");
+        assert_eq!(cell(&stats, "last_valid_index", "late_start")?, "4");
+        assert_eq!(cell(&stats, "first_valid_index", "full")?, "0");
+        assert_eq!(cell(&stats, "last_valid_index", "full")?, "5");
+        assert_eq!(cell(&stats, "first_valid_index", "all_null")?, "null");
+        assert_eq!(cell(&stats, "last_valid_index", "all_null")?, "null");
+        assert_eq!(cell(&stats, "first_valid_index", "nested")?, "1");
+        assert_eq!(cell(&stats, "last_valid_index", "nested")?, "4");
+
+        // Indices refer to the collected row order of a LazyFrame
+        let reversed = df.clone().lazy().reverse().describe_with(&opts)?;
+        assert_eq!(cell(&reversed, "first_valid_index", "late_start")?, "1");
+        assert_eq!(cell(&reversed, "last_valid_index", "late_start")?, "2");
+
+        let empty = df.lazy().limit(0).describe_with(&opts)?;
+        assert_eq!(cell(&empty, "last_valid_index", "full")?, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn test_iqr_and_range() -> Result<()> {
+        let df = df! {
+            "x" => [Some(7.5), Some(1.0), None, Some(3.25), Some(12.0), Some(4.0)],
+            "n" => [Some(3), Some(9), Some(1), Some(4), None, Some(6)],
+            "day" => [Some(19_000), Some(19_010), Some(19_003), None, Some(19_001), Some(19_004)],
+            "wait" => [Some(60_000i64), Some(0), Some(90_000), Some(30_000), None, Some(15_000)],
+            "s" => ["a", "b", "c", "d", "e", "f"],
+        }?;
+        #[cfg(feature = "temporal")]
+        let df = df
+            .lazy()
+            .with_columns([
+                col("day").cast(DataType::Date),
+                col("wait").cast(DataType::Duration(TimeUnit::Milliseconds)),
+            ])
+            .collect()?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::Iqr)
+            .statistic(Statistic::Range);
+        let stats = df.describe_with(&opts)?;
+
+        let number =
+            |row: &str, column: &str| -> Result<f64> { Ok(cell(&stats, row, column)?.parse()?) };
+        for column in ["x", "n"] {
+            assert_eq!(
+                number("iqr", column)?,
+                number("75%", column)? - number("25%", column)?
+            );
+            assert_eq!(
+                number("range", column)?,
+                number("max", column)? - number("min", column)?
+            );
+        }
+        assert_eq!(cell(&stats, "range", "x")?, "11.0");
+        #[cfg(feature = "temporal")]
+        {
+            assert_eq!(cell(&stats, "range", "day")?, "10d");
+            assert_eq!(cell(&stats, "iqr", "day")?, "null");
+            assert_eq!(cell(&stats, "range", "wait")?, "1m 30s");
+            assert_eq!(cell(&stats, "iqr", "wait")?, "45s");
+        }
+        assert_eq!(cell(&stats, "range", "s")?, "null");
+
+        let rounded = df.describe_with(&opts.clone().precision(3))?;
+        assert_eq!(cell(&rounded, "iqr", "x")?, "4.250");
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_span_and_interval_statistics() -> Result<()> {
+        // Daily from 2024-01-01 to 2024-01-10 without the 4th and the 7th,
+        // out of order and with a null
+        let days = [
+            19_732, 19_723, 19_724, 19_725, 19_727, 19_728, 19_730, 19_731,
+        ];
+        let day: Vec<Option<i32>> = days.iter().map(|d| Some(*d)).chain([None]).collect();
+        let df = df! { "day" => day, "n" => [1, 2, 3, 4, 5, 6, 7, 8, 9] }?
+            .lazy()
+            .with_columns([
+                col("day").cast(DataType::Date),
+                (col("day")
+                    .cast(DataType::Date)
+                    .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                    + lit(Duration::parse("6h")))
+                .alias("ts"),
+            ])
+            .collect()?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::Span)
+            .statistic(Statistic::MedianInterval)
+            .statistic(Statistic::GapCount);
+        let stats = df.describe_with(&opts)?;
+
+        for column in ["day", "ts"] {
+            assert_eq!(cell(&stats, "span", column)?, "9d");
+            assert_eq!(cell(&stats, "median_interval", column)?, "1d");
+            assert_eq!(cell(&stats, "gap_count", column)?, "2");
+        }
+        assert_eq!(cell(&stats, "span", "n")?, "null");
+        assert_eq!(cell(&stats, "gap_count", "n")?, "null");
+
+        // Two-day intervals are no gaps at twice the median
+        let lenient = df.describe_with(&opts.gap_multiplier(2.0))?;
+        assert_eq!(cell(&lenient, "gap_count", "day")?, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_sign_counts_partition_the_column() -> Result<()> {
+        let df = df! {
+            "x" => [Some(-2.5), Some(0.0), Some(-0.0), Some(3.0), None, Some(f64::NAN), Some(f64::INFINITY), Some(-1.0)],
+            "n" => [Some(-3), Some(0), Some(5), Some(7), None, Some(0), Some(1), Some(2)],
+            "s" => ["a", "b", "c", "d", "e", "f", "g", "h"],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::ZeroCount)
+            .statistic(Statistic::NegCount)
+            .statistic(Statistic::PosCount);
+        let stats = df.describe_with(&opts)?;
+
+        let number =
+            |row: &str, column: &str| -> Result<usize> { Ok(cell(&stats, row, column)?.parse()?) };
+        assert_eq!(
+            (
+                number("zero_count", "x")?,
+                number("neg_count", "x")?,
+                number("pos_count", "x")?
+            ),
+            (2, 2, 2)
+        );
+        assert_eq!(
+            (
+                number("zero_count", "n")?,
+                number("neg_count", "n")?,
+                number("pos_count", "n")?
+            ),
+            (2, 1, 4)
+        );
+        for (column, nans) in [("x", 1), ("n", 0)] {
+            let partition = number("zero_count", column)?
+                + number("neg_count", column)?
+                + number("pos_count", column)?
+                + nans
+                + number("null_count", column)?;
+            assert_eq!(partition, df.height(), "{column}");
+        }
+        assert_eq!(cell(&stats, "pos_count", "s")?, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn test_true_and_false_counts() -> Result<()> {
+        // 7 true and 3 false values among 12 rows
+        let flags: Vec<Option<bool>> = (0..12)
+            .map(|i| match i {
+                0 | 6 => None,
+                1 | 5 | 9 => Some(false),
+                _ => Some(true),
+            })
+            .collect();
+        let df = df! {
+            "flag" => flags,
+            "n" => (0..12).collect::<Vec<i32>>(),
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::TrueCount)
+            .statistic(Statistic::FalseCount);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "true_count", "flag")?, "7");
+        assert_eq!(cell(&stats, "false_count", "flag")?, "3");
+        assert_eq!(cell(&stats, "null_count", "flag")?, "2");
+        // The mean is still the fraction of true non-null values
+        assert_eq!(cell(&stats, "mean", "flag")?, "0.7");
+        assert_eq!(cell(&stats, "50%", "flag")?, "null");
+        assert_eq!(cell(&stats, "true_count", "n")?, "null");
+
+        let report = df.describe_report(&opts)?;
+        let flag = report.column("flag").unwrap();
+        assert_eq!(flag.mean, Some(0.7));
+        Ok(())
+    }
+
+    #[test]
+    fn test_whitespace_and_casefold_statistics() -> Result<()> {
+        let df = df! {
+            "s" => [" a", "a ", "A", "a"],
+            "n" => [1, 2, 3, 4],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::LeadingWsCount)
+            .statistic(Statistic::TrailingWsCount)
+            .statistic(Statistic::CasefoldDupCount);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "leading_ws_count", "s")?, "1");
+        assert_eq!(cell(&stats, "trailing_ws_count", "s")?, "1");
+        // All four values fold onto "a"
+        assert_eq!(cell(&stats, "casefold_dup_count", "s")?, "4");
+        assert_eq!(cell(&stats, "leading_ws_count", "n")?, "null");
+        assert_eq!(cell(&stats, "trailing_ws_count", "n")?, "null");
+        assert_eq!(cell(&stats, "casefold_dup_count", "n")?, "null");
+
+        // Values alone in their folded group and nulls don't count
+        let mixed = df! { "s" => [Some(" a"), Some("A"), Some("a"), Some(" b "), None] }?;
+        let mixed = mixed.describe_with(&opts)?;
+        assert_eq!(cell(&mixed, "casefold_dup_count", "s")?, "3");
+
+        let trimmed = df.describe_with(&opts.trim_strings(TrimMode::Both))?;
+        assert_eq!(cell(&trimmed, "leading_ws_count", "s")?, "0");
+        Ok(())
+    }
+
+    #[test]
+    fn test_cv_and_sem() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::Cv)
+            .statistic(Statistic::Sem);
+
+        // Four values with mean 5 and sample std sqrt(16 / 3)
+        let df = df! {
+            "x" => [Some(3.0), Some(7.0), None, Some(3.0), Some(7.0)],
+            "zero_mean" => [Some(-2.0), Some(2.0), Some(-1.0), Some(1.0), None],
+            "s" => ["a", "b", "c", "d", "e"],
+        }?;
+        let stats = df.describe_with(&opts.clone().precision(4))?;
+        assert_eq!(cell(&stats, "std", "x")?, "2.3094");
+        assert_eq!(cell(&stats, "cv", "x")?, "0.4619");
+        assert_eq!(cell(&stats, "sem", "x")?, "1.1547");
+        assert_eq!(cell(&stats, "cv", "zero_mean")?, "null");
+        assert_eq!(cell(&stats, "sem", "zero_mean")?, "0.9129");
+        assert_eq!(cell(&stats, "cv", "s")?, "null");
+
+        let single = df! { "x" => [4.0] }?.describe_with(&opts)?;
+        assert_eq!(cell(&single, "sem", "x")?, "null");
+        assert_eq!(cell(&single, "cv", "x")?, "null");
+        let empty = df! { "x" => [Option::<f64>::None] }?.describe_with(&opts)?;
+        assert_eq!(cell(&empty, "sem", "x")?, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn test_mean_confidence_interval() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::MeanCiLow)
+            .statistic(Statistic::MeanCiHigh);
+        let bounds = |values: Vec<f64>, opts: &DescribeOptions| -> Result<(f64, f64, f64)> {
+            let stats = df! { "x" => values }?.describe_with(opts)?;
+            let value = |row: &str| -> Result<f64> { Ok(cell(&stats, row, "x")?.parse()?) };
+            Ok((
+                value("mean_ci_low")?,
+                value("mean")?,
+                value("mean_ci_high")?,
+            ))
+        };
+
+        // Mean 5, sem sqrt(16 / 3) / 2
+        let (low, mean, high) = bounds(vec![3.0, 7.0, 3.0, 7.0], &opts)?;
+        assert!(low < mean && mean < high);
+        assert!((high - mean - 1.959_964 * 1.154_700_5).abs() < 1e-4);
+        let small_width = high - low;
+
+        let (low, mean, high) = bounds([3.0, 7.0].repeat(50), &opts)?;
+        assert!(low < mean && mean < high);
+        assert!(high - low < small_width / 4.0);
+
+        // 80% isn't in the table
+        let (low, _, high) = bounds(
+            vec![3.0, 7.0, 3.0, 7.0],
+            &opts.clone().confidence_level(0.8),
+        )?;
+        assert!(high - low < small_width);
+        assert!((z_value(0.8) - 1.281_552).abs() < 1e-6);
+        assert!((z_value(0.999) - 3.290_527).abs() < 1e-6);
+        assert!((z_value(0.95) - 1.959_964).abs() < 1e-6);
+
+        let single = df! { "x" => [4.0], "s" => ["a"] }?.describe_with(&opts)?;
+        assert_eq!(cell(&single, "mean_ci_low", "x")?, "null");
+        assert_eq!(cell(&single, "mean_ci_high", "x")?, "null");
+        assert_eq!(cell(&single, "mean_ci_low", "s")?, "null");
+        assert!(
+            df! { "x" => [1.0, 2.0] }?
+                .describe_with(&opts.confidence_level(1.0))
+                .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_jarque_bera() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::JarqueBera)
+            .statistic(Statistic::JarqueBeraPValue)
+            .precision(6);
+        // Symmetric and bell-shaped, against a long right tail
+        let normal = vec![
+            -2.0, -1.5, -1.0, -1.0, -0.5, -0.5, -0.5, 0.0, 0.0, 0.0, 0.0, 0.5, 0.5, 0.5, 1.0, 1.0,
+            1.5, 2.0,
+        ];
+        let skewed = vec![
+            1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0, 2.0,
+            50.0,
+        ];
+        let df = df! {
+            "normal" => normal,
+            "skewed" => skewed,
+            "s" => ["a"; 18],
+        }?;
+        let stats = df.describe_with(&opts)?;
+        let value =
+            |row: &str, column: &str| -> Result<f64> { Ok(cell(&stats, row, column)?.parse()?) };
+        assert!(value("jarque_bera", "normal")? < 1.0);
+        assert!(value("jarque_bera_p", "normal")? > 0.5);
+        assert!(value("jarque_bera", "skewed")? > 50.0);
+        assert!(value("jarque_bera_p", "skewed")? < 0.001);
+        assert_eq!(cell(&stats, "jarque_bera", "s")?, "null");
+
+        let few = df! { "x" => [1.0, 2.0, 3.0, 10.0] }?.describe_with(&opts)?;
+        assert_eq!(cell(&few, "jarque_bera", "x")?, "null");
+        let constant = df! { "c" => [1.0; 10] }?.describe_with(&opts)?;
+        assert_eq!(cell(&constant, "jarque_bera", "c")?, "null");
+        assert_eq!(cell(&constant, "jarque_bera_p", "c")?, "null");
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentiles_skip_nans_and_stay_ordered() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(f64::NAN), Some(3.0), None, Some(2.0), Some(f64::NAN)],
+            "f32" => [Some(1.0f32), Some(f32::NAN), Some(3.0), None, Some(2.0), Some(f32::NAN)],
+        }?;
+        let opts = DescribeOptions::new()
+            .percentiles(vec![0.99, 0.25, 0.5, 0.75, 0.0, 1.0])
+            .strict_quantiles(true);
+        for stats in [
+            df.describe_with(&opts)?,
+            df.clone().lazy().describe_with(&opts)?,
+        ] {
+            for column in ["x", "f32"] {
+                let values: Vec<f64> = ["min", "0%", "25%", "50%", "75%", "99%", "100%", "max"]
+                    .iter()
+                    .map(|label| Ok(cell(&stats, label, column)?.parse()?))
+                    .collect::<Result<_>>()?;
+                assert!(values.iter().all(|v| v.is_finite()), "{column}: {values:?}");
+                assert!(values.is_sorted(), "{column}: {values:?}");
+                assert_eq!(values[6], 3.0);
+            }
+            // Only the quantile-based statistics skip NaNs
+            assert_eq!(cell(&stats, "mean", "x")?, "NaN");
+        }
+
+        let quantile_df = df! { "x" => [1.0, 2.0, f64::NAN, 3.0, 4.0, f64::NAN] }?;
+        let quantile_opts = DescribeOptions::new()
+            .statistic(Statistic::Iqr)
+            .statistic(Statistic::OutlierCount)
+            .statistic(Statistic::Mad)
+            .statistic(Statistic::TrimmedMean);
+        let stats = quantile_df.describe_with(&quantile_opts)?;
+        let number = |row: &str| -> Result<f64> { Ok(cell(&stats, row, "x")?.parse()?) };
+        assert_eq!(number("iqr")?, number("75%")? - number("25%")?);
+        assert_eq!(number("iqr")?, 1.5);
+        assert_eq!(number("outlier_count")?, 0.0);
+        assert_eq!(number("mad")?, 1.0);
+        assert_eq!(number("trimmed_mean")?, 2.5);
+        let weighted = quantile_df
+            .lazy()
+            .with_column(lit(1).alias("w"))
+            .describe_weighted("w", None)?;
+        assert_eq!(cell(&weighted, "75%", "x")?, "3.0");
+
+        // An aggregation with interpolation noise: 50% above max and 25%
+        // below 0%
+        let schema = Schema::from_iter([Field::new("x".into(), DataType::Float64)]);
+        let percentiles = [0.5, 0.0, 0.25];
+        let metrics = df! {
+            "min:x" => [1.0],
+            "0.5:0:x" => [3.000_000_1],
+            "0:1:x" => [1.5],
+            "0.25:2:x" => [1.25],
+            "max:x" => [3.0],
+        }?;
+        let err =
+            check_quantile_order(&mut metrics.clone(), &schema, &percentiles, &opts).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::NonMonotoneQuantiles {
+                column: "x".to_string(),
+                percentile: "25%".to_string(),
+            })
+        );
+
+        let warnings = Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = warnings.clone();
+        let lenient =
+            DescribeOptions::new().on_warning(move |w| sink.lock().unwrap().push(w.to_string()));
+        let mut clamped = metrics.clone();
+        check_quantile_order(&mut clamped, &schema, &percentiles, &lenient)?;
+        let value = |alias: &str| clamped.column(alias).unwrap().f64().unwrap().get(0);
+        assert_eq!(value("0:1:x"), Some(1.5));
+        assert_eq!(value("0.25:2:x"), Some(1.5));
+        assert_eq!(value("0.5:0:x"), Some(3.0));
+        assert_eq!(warnings.lock().unwrap().len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_total_rows_and_strict_counts() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), None, Some(3.0), None],
+            "s" => [Some("a"), Some("b"), None, Some("d")],
+            "n" => [None::<i32>, None, None, None],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::TotalRows)
+            .strict_counts(true);
+        let stats = df.describe_with(&opts)?;
+        for column in ["x", "s", "n"] {
+            assert_eq!(cell(&stats, "total_rows", column)?, "4");
+        }
+        assert_eq!(cell(&stats, "null_count", "x")?, "2");
+
+        // An aggregation whose counts don't add up to the frame height
+        let schema = Schema::from_iter([Field::new("x".into(), DataType::Float64)]);
+        let metrics = df! {
+            "count:x" => [2u64],
+            "null_count:x" => [1u64],
+            "__total_rows:x" => [4u64],
+        }?;
+        let err = check_metrics(&metrics, &schema, &opts).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::InconsistentCounts {
+                column: "x".to_string(),
+                count: 2,
+                null_count: 1,
+                total_rows: 4,
+            })
+        );
+        assert!(check_metrics(&metrics, &schema, &DescribeOptions::new()).is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_mad() -> Result<()> {
+        let opts = DescribeOptions::new().statistic(Statistic::Mad);
+        let df = df! {
+            // median 3, absolute deviations 2, 1, 0, 1, 2
+            "symmetric" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0), None],
+            "outlier" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0), Some(1e6)],
+            "ints" => [Some(10), None, Some(10), Some(10), None, Some(10)],
+            "s" => ["a", "b", "c", "d", "e", "f"],
+        }?;
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "mad", "symmetric")?, "1.0");
+        // Deviations from the median 3.5: 2.5, 1.5, 0.5, 0.5, 1.5, ~1e6
+        assert_eq!(cell(&stats, "mad", "outlier")?, "1.5");
+        assert!(cell(&stats, "std", "outlier")?.parse::<f64>()? > 400_000.0);
+        assert_eq!(cell(&stats, "mad", "ints")?, "0.0");
+        assert_eq!(cell(&stats, "mad", "s")?, "null");
+
+        let normal = df.describe_with(&opts.mad_normal(true))?;
+        assert_eq!(cell(&normal, "mad", "symmetric")?, "1.4826");
+        Ok(())
+    }
+
+    #[test]
+    fn test_geo_and_harmonic_mean() -> Result<()> {
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::GeoMean)
+            .statistic(Statistic::HarmonicMean)
+            .precision(6);
+        let df = df! {
+            "x" => [Some(1.0), Some(2.0), None, Some(4.0), Some(8.0)],
+            "ints" => [Some(1), Some(2), None, Some(4), Some(8)],
+            "with_zero" => [Some(1.0), Some(0.0), None, Some(4.0), Some(8.0)],
+            "negative" => [Some(1.0), Some(-2.0), None, Some(4.0), Some(8.0)],
+            "s" => ["a", "b", "c", "d", "e"],
+        }?;
+        let stats = df.describe_with(&opts)?;
+        for column in ["x", "ints"] {
+            // (1 * 2 * 4 * 8)^(1/4) = 64^(1/4) and 4 / (1 + 1/2 + 1/4 + 1/8)
+            assert_eq!(cell(&stats, "geo_mean", column)?, "2.828427");
+            assert_eq!(cell(&stats, "harmonic_mean", column)?, "2.133333");
+        }
+        for column in ["with_zero", "negative", "s"] {
+            assert_eq!(cell(&stats, "geo_mean", column)?, "null", "{column}");
+            assert_eq!(cell(&stats, "harmonic_mean", column)?, "null", "{column}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_trimmed_mean() -> Result<()> {
+        let mut values: Vec<Option<f64>> = (1..=19).map(|v| Some(f64::from(v))).collect();
+        values.push(Some(1e9));
+        values.push(None);
+        let df = df! { "latency" => values, "s" => vec!["x"; 21] }?;
+
+        let stats = df.describe_with(&DescribeOptions::new().trimmed_mean(0.1))?;
+        // The 10% and 90% quantiles are 2.9 and 18.1: 3..=18 remain
+        assert_eq!(cell(&stats, "trimmed_mean", "latency")?, "10.5");
+        assert!(cell(&stats, "mean", "latency")?.parse::<f64>()? > 1e7);
+        assert_eq!(cell(&stats, "trimmed_mean", "s")?, "null");
+
+        let untrimmed = df.describe_with(&DescribeOptions::new().trimmed_mean(0.0))?;
+        assert_eq!(
+            cell(&untrimmed, "trimmed_mean", "latency")?,
+            cell(&untrimmed, "mean", "latency")?
+        );
+
+        for fraction in [0.5, -0.1] {
+            let err = df
+                .describe_with(&DescribeOptions::new().trimmed_mean(fraction))
+                .unwrap_err();
+            assert!(err.to_string().contains("outside the range"), "{err}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_unique_and_top_freq_ratios() -> Result<()> {
+        let df = df! {
+            "id" => [Some(1), Some(2), Some(3), Some(4), Some(5), None],
+            "constant" => ["x", "x", "x", "x", "x", "x"],
+            "mixed" => [Some(1.5), Some(1.5), Some(2.0), None, Some(3.0), Some(1.5)],
+            "empty" => [Option::<i32>::None, None, None, None, None, None],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::UniqueRatio)
+            .statistic(Statistic::TopFreqRatio);
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "unique_ratio", "id")?, "100.0%");
+        assert_eq!(cell(&stats, "top_freq_ratio", "id")?, "20.0%");
+        assert_eq!(cell(&stats, "unique_ratio", "constant")?, "16.666667%");
+        assert_eq!(cell(&stats, "top_freq_ratio", "constant")?, "100.0%");
+        assert_eq!(cell(&stats, "top_freq_ratio", "mixed")?, "60.0%");
+        assert_eq!(cell(&stats, "unique_ratio", "empty")?, "null");
+
+        let rounded = df.describe_with(&opts.precision(1))?;
+        assert_eq!(cell(&rounded, "unique_ratio", "constant")?, "16.7%");
+        assert_eq!(cell(&rounded, "unique_ratio", "id")?, "100.0%");
+        Ok(())
+    }
+
+    /// Value of a single statistic cell in a describe output
+    fn cell(stats: &DataFrame, statistic: &str, column: &str) -> Result<String> {
+        let labels = stats.column("statistic")?.str()?;
+        let idx = labels
+            .iter()
+            .position(|l| l == Some(statistic))
+            .ok_or_else(|| anyhow::anyhow!("no statistic row '{statistic}'"))?;
+        let value = stats.column(column)?.str()?.get(idx);
+        Ok(value.unwrap_or("null").to_string())
+    }
+
+    #[test]
+    fn test_describe_precision() -> Result<()> {
+        let df = df! {
+            "values" => [1.0, 2.0, 4.0],
+        }?;
+
+        let two = df.describe_with(&DescribeOptions::new().precision(2))?;
+        assert_eq!(cell(&two, "mean", "values")?, "2.33");
+        assert_eq!(cell(&two, "std", "values")?, "1.53");
+        assert_eq!(cell(&two, "50%", "values")?, "2.00");
+
+        let eight = df.describe_with(&DescribeOptions::new().precision(8))?;
+        assert_eq!(cell(&eight, "mean", "values")?, "2.33333333");
+        assert_eq!(cell(&eight, "std", "values")?, "1.52752523");
+        assert_eq!(cell(&eight, "25%", "values")?, "1.50000000");
+
+        // min/max keep their native formatting
+        assert_eq!(cell(&eight, "min", "values")?, "1.0");
+
+        let zero = df.describe_with(&DescribeOptions::new().precision(0))?;
+        assert_eq!(cell(&zero, "mean", "values")?, "2");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_scientific() -> Result<()> {
+        let df = df! {
+            "tiny" => [3.2e-9, 3.2e-9],
+        }?;
+
+        // Fixed notation loses the value entirely...
+        let fixed = df.describe_with(&DescribeOptions::new().precision(6))?;
+        assert_eq!(cell(&fixed, "mean", "tiny")?, "0.000000");
+
+        // ...while scientific notation keeps it
+        let sci = df.describe_with(&DescribeOptions::new().precision(2).scientific(true))?;
+        assert_eq!(cell(&sci, "mean", "tiny")?, "3.20e-9");
+        assert_eq!(cell(&sci, "75%", "tiny")?, "3.20e-9");
+
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_describe_native_minmax_formatting() -> Result<()> {
+        let lf = df! {
+            "strings" => ["pear", "apple", "fig"],
+            "dates" => [
+                NaiveDate::from_ymd_opt(2024, 3, 1).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 1, 15).unwrap(),
+                NaiveDate::from_ymd_opt(2024, 2, 10).unwrap(),
+            ],
+            "datetimes" => [1_704_164_645_123_456_i64, 1_704_164_645_000_001, 1_704_251_045_500_000],
+            "durations" => [5_000_i64, 4_980_000, 90_061_000],
+            "bools" => [true, true, true],
+        }?
+        .lazy()
+        .with_columns([
+            col("datetimes").cast(DataType::Datetime(TimeUnit::Microseconds, None)),
+            col("durations").cast(DataType::Duration(TimeUnit::Milliseconds)),
+        ]);
 
-        let lf = df.lazy();
         let stats = lf.describe(None)?;
 
-        // Should work with LazyFrame without collecting first
-        assert_eq!(stats.shape(), (9, 3));
+        assert_eq!(cell(&stats, "min", "strings")?, "apple");
+        assert_eq!(cell(&stats, "max", "strings")?, "pear");
+        assert_eq!(cell(&stats, "min", "dates")?, "2024-01-15");
+        assert_eq!(cell(&stats, "max", "dates")?, "2024-03-01");
+        assert_eq!(
+            cell(&stats, "min", "datetimes")?,
+            "2024-01-02T03:04:05.000001"
+        );
+        assert_eq!(
+            cell(&stats, "max", "datetimes")?,
+            "2024-01-03T03:04:05.500000"
+        );
+        assert_eq!(cell(&stats, "min", "durations")?, "5s");
+        assert_eq!(cell(&stats, "max", "durations")?, "1d 1h 1m 1s");
+        // Boolean min/max reflect the data rather than a fixed false/true
+        assert_eq!(cell(&stats, "min", "bools")?, "true");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_trim_strings() -> Result<()> {
+        let df = df! {
+            "fruit" => [" apple", "apple", "banana ", "banana", "cherry"],
+        }?;
+
+        let raw = DescribeOptions::new().statistic(Statistic::NUnique);
+        let stats = df.describe_with(&raw)?;
+        assert_eq!(cell(&stats, "min", "fruit")?, " apple");
+        assert_eq!(cell(&stats, "n_unique", "fruit")?, "5");
+
+        let trimmed = raw.clone().trim_strings(TrimMode::Both);
+        let stats = df.describe_with(&trimmed)?;
+        assert_eq!(cell(&stats, "min", "fruit")?, "apple");
+        assert_eq!(cell(&stats, "n_unique", "fruit")?, "3");
+        // Raw counts are unaffected by trimming
+        assert_eq!(cell(&stats, "count", "fruit")?, "5");
+
+        // Trimming only the start leaves "banana " distinct from "banana"
+        let start = raw.trim_strings(TrimMode::Start);
+        let stats = df.describe_with(&start)?;
+        assert_eq!(cell(&stats, "n_unique", "fruit")?, "4");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_nan() -> Result<()> {
+        let df = df! {
+            "values" => [1.0, f64::NAN, 3.0],
+            "labels" => ["a", "b", "c"],
+        }?;
+
+        // Lenient mode happily describes the frame
+        let stats = df.describe_with(&DescribeOptions::new())?;
+        assert_eq!(cell(&stats, "count", "values")?, "3");
+
+        let err = df
+            .describe_with(&DescribeOptions::new().strict(true))
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::NanValues("values".to_string()))
+        );
+        // LazyFrames fail with the same typed error
+        let opts = DescribeOptions::new().nan_is_error(true);
+        let err = df.clone().lazy().describe_with(&opts).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::NanValues("values".to_string()))
+        );
+        // A lazy plan can only fail with a Polars error, carrying the message
+        let err = df
+            .clone()
+            .lazy()
+            .describe_lazy(&opts)?
+            .collect()
+            .unwrap_err();
+        assert!(err.to_string().contains("NaN"), "{err}");
+
+        // Individual settings stay overridable after the bundle
+        let relaxed = DescribeOptions::new().strict(true).nan_is_error(false);
+        assert!(df.describe_with(&relaxed).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_mode_percentiles_and_columns() -> Result<()> {
+        let df = df! {
+            "a" => [1, 2, 3],
+            "b" => [4, 5, 6],
+        }?;
+
+        // Lenient: duplicate percentiles are dropped, unknown columns skipped
+        let lenient = DescribeOptions::new()
+            .percentiles(vec![0.5, 0.5])
+            .columns(["b", "missing"])
+            .on_warning(|_| {});
+        let stats = df.describe_with(&lenient)?;
+        assert_eq!(stats.shape(), (7, 2));
+
+        let strict = DescribeOptions::new().strict(true);
+        let variant = |result: Result<DataFrame>| {
+            result.unwrap_err().downcast_ref::<DescribeError>().cloned()
+        };
+        assert_eq!(
+            variant(df.describe_with(&strict.clone().percentiles(vec![0.5, 0.5]))),
+            Some(DescribeError::DuplicatePercentile(0.5))
+        );
+        assert_eq!(
+            variant(df.describe_with(&strict.columns(["missing"]))),
+            Some(DescribeError::ColumnNotFound("missing".to_string()))
+        );
+
+        // Out-of-range percentiles are always an error
+        assert_eq!(
+            variant(df.describe(Some(vec![0.5, 1.5]))),
+            Some(DescribeError::InvalidPercentile(1.5))
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_empty_schema_error() -> Result<()> {
+        let empty = DataFrame::empty();
+        for result in [
+            empty.describe(None),
+            empty
+                .describe_report(&DescribeOptions::new())
+                .map(|_| empty.clone()),
+            empty.describe_with(&DescribeOptions::new().compat(Compat::Pandas)),
+        ] {
+            let err = result.unwrap_err();
+            assert_eq!(
+                err.downcast_ref::<DescribeError>(),
+                Some(&DescribeError::EmptySchema),
+                "{err:#}"
+            );
+        }
+        // Also through added context
+        let df = df! { "day" => [1, 2] }?;
+        let err = df.describe_partitioned(&["day"], None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::EmptySchema)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_dtypes_list_column() -> Result<()> {
+        let df = df! {
+            "x" => [1, 2],
+            "tags" => [Series::new("".into(), [1, 2]), Series::new("".into(), [3])],
+        }?;
+
+        let report = df.describe_report(&DescribeOptions::new())?;
+        assert_eq!(report.degraded_columns(), ["tags"]);
+        assert_eq!(report.column("tags").unwrap().min, None);
+
+        for opts in [
+            DescribeOptions::new().strict_dtypes(true),
+            DescribeOptions::new().strict(true),
+        ] {
+            let err = df.describe_with(&opts).unwrap_err();
+            let Some(DescribeError::UnsupportedDtype { column, dtype, .. }) =
+                err.downcast_ref::<DescribeError>()
+            else {
+                panic!("expected UnsupportedDtype, got {err:#}");
+            };
+            assert_eq!(column, "tags");
+            assert!(matches!(dtype, DataType::List(_)));
+        }
+        // Leaving the column out makes the frame describable again
+        let numeric_only = DescribeOptions::new().strict_dtypes(true).columns(["x"]);
+        assert!(
+            df.describe_report(&numeric_only)?
+                .degraded_columns()
+                .is_empty()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_order_by_null_count() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), Some(2), Some(3), Some(4)],
+            "b" => [None, Some(2), None, Some(4)],
+            "c" => [Some(1), None, Some(3), Some(4)],
+            "d" => [None, None, None, Some(4)],
+            "e" => [Some(1), Some(2), None, None],
+        }?;
+        let opts = DescribeOptions::new().order_by(ColumnOrder::NullCountDesc);
+        // Ties (b and e, a last) keep the schema order
+        let expected = ["d", "b", "e", "c", "a"];
+
+        let stats = df.describe_with(&opts)?;
+        let names: Vec<&str> = stats.get_column_names_str()[1..].to_vec();
+        assert_eq!(names, expected);
+        assert_eq!(cell(&stats, "null_count", "d")?, "3");
+
+        let typed = df.describe_report(&opts)?.to_typed_dataframe()?;
+        let rows: Vec<&str> = typed.column("column")?.str()?.into_no_null_iter().collect();
+        assert_eq!(rows, expected);
+
+        // Orders known from the schema stay lazy
+        let lazy = df
+            .lazy()
+            .describe_lazy(
+                &DescribeOptions::new()
+                    .order_by(ColumnOrder::Names(vec!["e".to_string(), "a".to_string()])),
+            )?
+            .collect()?;
+        assert_eq!(
+            lazy.get_column_names_str(),
+            ["statistic", "e", "a", "b", "c", "d"]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_columns_limit() -> Result<()> {
+        let df = df! { "a" => [1], "b" => [2], "c" => [3], "d" => [4], "e" => [5] }?;
+        let warnings = Arc::new(AtomicUsize::new(0));
+        let counter = warnings.clone();
+        let quiet = DescribeOptions::new().on_warning(move |_| {
+            counter.fetch_add(1, Ordering::Relaxed);
+        });
+        let names = |opts: DescribeOptions| -> Result<Vec<String>> {
+            let stats = df.describe_with(&opts)?;
+            Ok(stats.get_column_names_str()[1..]
+                .iter()
+                .map(|name| name.to_string())
+                .collect())
+        };
+
+        assert_eq!(names(quiet.clone().max_columns(2))?, ["a", "b", "…"]);
+        assert_eq!(
+            names(quiet.clone().max_columns_head_tail(1, 2))?,
+            ["a", "…", "d", "e"]
+        );
+        assert_eq!(warnings.load(Ordering::Relaxed), 2);
+        let stats = df.describe_with(&quiet.clone().max_columns(2))?;
+        assert_eq!(cell(&stats, "mean", "…")?, "…");
+        let report = df.describe_report(&quiet.clone().max_columns(2))?;
+        assert_eq!((report.columns.len(), report.skipped_columns), (2, 3));
+
+        // Lazy plans declare the gap column in their schema
+        let lazy = df
+            .clone()
+            .lazy()
+            .describe_lazy(&quiet.clone().max_columns(2))?;
+        assert_eq!(lazy.collect()?.get_column_names_str()[3], "…");
+
+        for max in [5, 8] {
+            assert_eq!(
+                names(quiet.clone().max_columns(max))?,
+                ["a", "b", "c", "d", "e"]
+            );
+            let report = df.describe_report(&quiet.clone().max_columns(max))?;
+            assert_eq!(report.skipped_columns, 0);
+        }
+        // An explicit selection lifts the limit
+        assert_eq!(
+            names(quiet.clone().max_columns(1).columns(["c", "d"]))?,
+            ["c", "d"]
+        );
+        assert_eq!(warnings.load(Ordering::Relaxed), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn test_max_columns_gap_survives_ordering() -> Result<()> {
+        let df = df! { "d" => [1], "c" => [2], "b" => [3], "a" => [4] }?;
+        let opts = DescribeOptions::new()
+            .on_warning(|_| {})
+            .max_columns_head_tail(2, 1)
+            .order_by(ColumnOrder::Alphabetical);
+
+        // Each side of the gap is ordered on its own
+        let expected = ["statistic", "c", "d", "…", "a"];
+        assert_eq!(df.describe_with(&opts)?.get_column_names_str(), expected);
+        let lazy = df.clone().lazy().describe_lazy(&opts)?.collect()?;
+        assert_eq!(lazy.get_column_names_str(), expected);
+        let report = df.describe_report(&opts)?;
+        let names: Vec<&str> = report.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["c", "d", "a"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_null_dtype_column() -> Result<()> {
+        let df = DataFrame::new(vec![Series::new_null("n".into(), 3).into_column()])?;
+        let stats = df.describe(None)?;
+        assert_eq!(cell(&stats, "count", "n")?, "0");
+        assert_eq!(cell(&stats, "null_count", "n")?, "3");
+        for statistic in ["mean", "std", "min", "25%", "50%", "75%", "max"] {
+            assert_eq!(cell(&stats, statistic, "n")?, "null", "{statistic}");
+        }
+
+        let report = df.describe_report(&DescribeOptions::new())?;
+        let summary = report.column("n").unwrap();
+        assert_eq!(summary.dtype, "null");
+        assert_eq!((summary.count, summary.null_count), (0, 3));
+        assert_eq!((summary.mean, &summary.min), (None, &None));
+        let typed = report.to_typed_dataframe()?;
+        assert_eq!(typed.column("dtype")?.str()?.get(0), Some("null"));
+        assert_eq!(typed.column("mean")?.f64()?.get(0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_null_dtype_among_other_columns() -> Result<()> {
+        let df = DataFrame::new(vec![
+            Column::new("x".into(), [1.0, 2.0, 3.0, 4.0]),
+            Series::new_null("n".into(), 4).into_column(),
+            Column::new("s".into(), ["b", "a", "d", "c"]),
+        ])?;
+        let stats = df.describe(None)?;
+        assert_eq!(stats.width(), 4);
+        assert_eq!(cell(&stats, "mean", "x")?, "2.5");
+        assert_eq!(cell(&stats, "max", "x")?, "4.0");
+        assert_eq!(cell(&stats, "count", "n")?, "0");
+        assert_eq!(cell(&stats, "null_count", "n")?, "4");
+        assert_eq!(cell(&stats, "max", "n")?, "null");
+        assert_eq!(cell(&stats, "min", "s")?, "a");
+        assert_eq!(cell(&stats, "max", "s")?, "d");
+
+        let report = df.describe_report(&DescribeOptions::new())?;
+        let names: Vec<&str> = report.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["x", "n", "s"]);
+        assert_eq!(report.column("x").unwrap().mean, Some(2.5));
+        assert_eq!(report.column("s").unwrap().count, 4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_mode_rejects_describe_output() -> Result<()> {
+        let df = df! { "a" => [1, 2, 3] }?;
+        let stats = df.describe(None)?;
+
+        let err = stats
+            .describe_with(&DescribeOptions::new().strict(true))
+            .unwrap_err();
+        assert!(err.to_string().contains("describe() output"));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "categorical")]
+    #[test]
+    fn test_describe_categorical_and_enum() -> Result<()> {
+        let levels = FrozenCategories::new(["low", "medium", "high"])?;
+        let lf = df! {
+            "fruit" => ["pear", "apple", "fig", "apple"],
+            "level" => ["medium", "high", "low", "high"],
+        }?
+        .lazy()
+        .with_columns([
+            col("fruit").cast(DataType::from_categories(Categories::global())),
+            col("level").cast(DataType::from_frozen_categories(levels)),
+        ]);
+
+        let stats = lf.describe_with(&DescribeOptions::new().statistic(Statistic::NUnique))?;
+
+        // Categorical min/max are lexical
+        assert_eq!(cell(&stats, "min", "fruit")?, "apple");
+        assert_eq!(cell(&stats, "max", "fruit")?, "pear");
+        assert_eq!(cell(&stats, "n_unique", "fruit")?, "3");
+
+        // Enum min/max follow the declared category order
+        assert_eq!(cell(&stats, "min", "level")?, "low");
+        assert_eq!(cell(&stats, "max", "level")?, "high");
+        assert_eq!(cell(&stats, "n_unique", "level")?, "3");
+
+        Ok(())
+    }
+
+    #[cfg(not(feature = "categorical"))]
+    #[test]
+    fn test_categorical_degraded_without_feature() -> Result<()> {
+        let lf = df! { "x" => [1, 2], "fruit" => ["pear", "apple"] }?
+            .lazy()
+            .with_column(col("fruit").cast(DataType::from_categories(Categories::global())));
+
+        let report = lf.describe_report(&DescribeOptions::new())?;
+        assert_eq!(report.degraded_columns(), ["fruit"]);
+        assert_eq!(report.column("fruit").unwrap().count, 2);
+        assert_eq!(report.column("fruit").unwrap().min, None);
+
+        let err = lf
+            .describe_with(&DescribeOptions::new().strict(true))
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DescribeError>(),
+            Some(DescribeError::UnsupportedDtype { column, .. }) if column == "fruit"
+        ));
+        Ok(())
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn test_describe_decimal() -> Result<()> {
+        let df = df! { "price" => [Some(1.25), Some(3.50), Some(2.10), None] }?
+            .lazy()
+            .with_column(col("price").cast(DataType::Decimal(Some(10), Some(2))))
+            .collect()?;
+
+        let stats = df.describe(None)?;
+        assert_eq!(cell(&stats, "count", "price")?, "3");
+        assert_eq!(cell(&stats, "mean", "price")?, "2.283333");
+        // min/max keep the decimal scale
+        assert_eq!(cell(&stats, "min", "price")?, "1.25");
+        assert_eq!(cell(&stats, "max", "price")?, "3.50");
+        assert_eq!(cell(&stats, "50%", "price")?, "2.1");
+
+        let report = df.describe_report(&DescribeOptions::new())?;
+        let price = report.column("price").unwrap();
+        assert!((price.mean.unwrap() - 2.283_333).abs() < 1e-6);
+        assert_eq!(price.max, Some(StatValue::Decimal("3.50".to_string())));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_list_lengths() -> Result<()> {
+        let values = Series::new(
+            "values".into(),
+            [
+                Some(Series::new("".into(), [Some(1), None, Some(3)])),
+                Some(Series::new("".into(), Vec::<i32>::new())),
+                None,
+                Some(Series::new("".into(), [Some(4)])),
+            ],
+        );
+        let empty = Series::new(
+            "empty".into(),
+            vec![Series::new("".into(), Vec::<i32>::new()); 4],
+        );
+        let df = DataFrame::new(vec![
+            values.into(),
+            empty.into(),
+            Column::new("n".into(), [1, 2, 3, 4]),
+        ])?;
+
+        let stats = df.describe(None)?;
+        assert_eq!(cell(&stats, "count", "values")?, "3");
+        assert_eq!(cell(&stats, "null_count", "values")?, "1");
+        assert_eq!(cell(&stats, "list_len_min", "values")?, "0");
+        assert_eq!(cell(&stats, "list_len_mean", "values")?, "1.333333");
+        assert_eq!(cell(&stats, "list_len_max", "values")?, "3");
+        assert_eq!(cell(&stats, "list_elements", "values")?, "4");
+        assert_eq!(cell(&stats, "list_null_elements", "values")?, "1");
+
+        // All-empty lists have zero-length statistics, not nulls
+        assert_eq!(cell(&stats, "list_len_max", "empty")?, "0");
+        assert_eq!(cell(&stats, "list_len_mean", "empty")?, "0.0");
+        assert_eq!(cell(&stats, "list_elements", "empty")?, "0");
+        assert_eq!(cell(&stats, "list_null_elements", "empty")?, "0");
+
+        // Non-list columns have no list statistics
+        assert_eq!(cell(&stats, "list_len_max", "n")?, "null");
+
+        // Without List columns there are no list rows
+        let plain = df.select(["n"])?.describe(None)?;
+        assert_eq!(plain.height(), 9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicate_output_names() -> Result<()> {
+        // "item.price" is both a column and, once unnested, a struct field;
+        // "statistic" clashes with the statistic column of the output
+        let df = df! {
+            "price" => [1.5, 2.5, 4.0],
+            "label" => ["b", "a", "c"],
+            "score" => [1, 2, 3],
+            "Score" => [4, 5, 6],
+        }?
+        .lazy()
+        .select([
+            col("price").alias("item.price"),
+            as_struct(vec![col("price"), col("label")]).alias("item"),
+            col("score").alias("statistic"),
+            col("Score"),
+        ]);
+
+        let opts = DescribeOptions::new().unnest_structs(true);
+        let err = df.describe_with(&opts).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::DuplicateColumns(vec![
+                "item.price".to_string(),
+                "statistic".to_string(),
+            ]))
+        );
+        assert!(err.to_string().contains("item.price, statistic"));
+        assert!(df.describe_report(&opts).is_err());
+
+        let stats = df.describe_with(&opts.dedupe_names(true))?;
+        assert_eq!(
+            stats.get_column_names(),
+            [
+                "statistic",
+                "item.price",
+                "item.price_1",
+                "item.label",
+                "statistic_1",
+                "Score",
+            ]
+        );
+        assert!(
+            stats
+                .column("item.price")?
+                .equals_missing(stats.column("item.price_1")?)
+        );
+        assert_eq!(cell(&stats, "max", "statistic_1")?, "3");
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_unnest_structs() -> Result<()> {
+        let df = df! {
+            "id" => [1, 2, 3],
+            "price" => [Some(1.5), None, Some(4.0)],
+            "label" => ["b", "a", "c"],
+        }?;
+        let nested = df
+            .clone()
+            .lazy()
+            .select([
+                col("id"),
+                as_struct(vec![col("price"), col("label")]).alias("item"),
+            ])
+            .collect()?;
+
+        // Struct columns only get counts by default
+        let stats = nested.describe(None)?;
+        assert_eq!(cell(&stats, "count", "item")?, "3");
+        assert_eq!(cell(&stats, "null_count", "item")?, "0");
+        assert_eq!(cell(&stats, "min", "item")?, "null");
+
+        let stats = nested.describe_with(&DescribeOptions::new().unnest_structs(true))?;
+        let expected = df.describe(None)?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["statistic", "id", "item.price", "item.label"]
+        );
+        for (field, column) in [("item.price", "price"), ("item.label", "label")] {
+            assert!(
+                stats
+                    .column(field)?
+                    .equals_missing(expected.column(column)?)
+            );
+        }
+
+        // Depth limits how far nested structs are expanded
+        let deeper = nested
+            .lazy()
+            .select([as_struct(vec![col("id"), col("item")]).alias("outer")])
+            .collect()?;
+        let stats =
+            deeper.describe_with(&DescribeOptions::new().unnest_structs(true).unnest_depth(1))?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["statistic", "outer.id", "outer.item"]
+        );
+        let stats = deeper.describe_with(&DescribeOptions::new().unnest_structs(true))?;
+        assert_eq!(
+            stats.get_column_names(),
+            [
+                "statistic",
+                "outer.id",
+                "outer.item.price",
+                "outer.item.label"
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_describe_duration_and_time() -> Result<()> {
+        let df = df! {
+            "elapsed" => [Some(1_000_i64), Some(5_000_000), None, Some(90_000)],
+            "at" => [Some(3_600_000_000_000_i64), Some(45_296_123_456_000), None, Some(1_000)],
+        }?
+        .lazy()
+        .with_columns([
+            col("elapsed").cast(DataType::Duration(TimeUnit::Milliseconds)),
+            col("at").cast(DataType::Time),
+        ])
+        .collect()?;
+
+        let stats = df.describe(None)?;
+        assert_eq!(cell(&stats, "mean", "elapsed")?, "28m 17s");
+        assert_eq!(cell(&stats, "std", "elapsed")?, "47m 40s 828ms");
+        assert_eq!(cell(&stats, "min", "elapsed")?, "1s");
+        assert_eq!(cell(&stats, "50%", "elapsed")?, "1m 30s");
+        assert_eq!(cell(&stats, "max", "elapsed")?, "1h 23m 20s");
+
+        assert_eq!(cell(&stats, "std", "at")?, "null");
+        assert_eq!(cell(&stats, "min", "at")?, "00:00:00.000001");
+        assert_eq!(cell(&stats, "50%", "at")?, "01:00:00.000000");
+        assert_eq!(cell(&stats, "max", "at")?, "12:34:56.123456");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_binary_lengths() -> Result<()> {
+        let blobs = Series::new(
+            "blob".into(),
+            [
+                Some(b"ab".as_slice()),
+                None,
+                Some(b"abcdef".as_slice()),
+                Some(b"".as_slice()),
+                Some(b"ab".as_slice()),
+            ],
+        );
+        let df = DataFrame::new(vec![blobs.into(), Column::new("n".into(), [1, 2, 3, 4, 5])])?;
+
+        let stats = df.describe_with(&DescribeOptions::new().statistic(Statistic::NUnique))?;
+        assert_eq!(cell(&stats, "count", "blob")?, "4");
+        assert_eq!(cell(&stats, "null_count", "blob")?, "1");
+        assert_eq!(cell(&stats, "n_unique", "blob")?, "3");
+        assert_eq!(cell(&stats, "min", "blob")?, "null");
+        assert_eq!(cell(&stats, "byte_len_min", "blob")?, "0");
+        assert_eq!(cell(&stats, "byte_len_mean", "blob")?, "2.5");
+        assert_eq!(cell(&stats, "byte_len_max", "blob")?, "6");
+        assert_eq!(cell(&stats, "byte_len_max", "n")?, "null");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_string_lengths() -> Result<()> {
+        let df = df! {
+            "word" => [Some("héllo"), Some(""), None, Some("日本"), Some(""), Some("abc")],
+            "n" => [1, 2, 3, 4, 5, 6],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::LenMin)
+            .statistic(Statistic::LenMean)
+            .statistic(Statistic::LenMax)
+            .statistic(Statistic::EmptyCount);
+
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "count", "word")?, "5");
+        assert_eq!(cell(&stats, "null_count", "word")?, "1");
+        assert_eq!(cell(&stats, "len_min", "word")?, "0");
+        // Multi-byte characters count once: (5 + 0 + 2 + 0 + 3) / 5
+        assert_eq!(cell(&stats, "len_mean", "word")?, "2.0");
+        assert_eq!(cell(&stats, "len_max", "word")?, "5");
+        assert_eq!(cell(&stats, "empty_count", "word")?, "2");
+
+        assert_eq!(cell(&stats, "len_max", "n")?, "null");
+        assert_eq!(cell(&stats, "empty_count", "n")?, "null");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_outlier_count() -> Result<()> {
+        let df = df! {
+            // Q1 = 2.25, Q3 = 4.75: fences at -1.5 and 8.5 with the default k = 1.5
+            "x" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0), Some(5.0), Some(40.0), None],
+            "flat" => [5, 5, 5, 5, 5, 5, 9],
+            "s" => ["a", "b", "c", "d", "e", "f", "g"],
+        }?;
+        let opts = DescribeOptions::new().statistic(Statistic::OutlierCount);
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "outlier_count", "x")?, "1");
+        // Zero IQR: everything different from the constant is an outlier
+        assert_eq!(cell(&stats, "outlier_count", "flat")?, "1");
+        assert_eq!(cell(&stats, "outlier_count", "s")?, "null");
+
+        // A multiplier large enough to take in the planted outlier
+        let stats = df.describe_with(&opts.iqr_multiplier(20.0))?;
+        assert_eq!(cell(&stats, "outlier_count", "x")?, "0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_zscore_outliers() -> Result<()> {
+        let mut values = vec![10.0, 11.0, 9.0, 10.0, 12.0, 8.0, 10.0, 11.0, 9.0, 10.0];
+        values.extend_from_within(..);
+        values[19] = 100.0;
+        let df = df! {
+            "x" => values,
+            "flat" => [7; 20],
+        }?;
+        let opts = DescribeOptions::new().statistic(Statistic::ZScoreOutliers);
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "zscore_outliers", "x")?, "1");
+        // Zero std: z-scores are undefined
+        assert_eq!(cell(&stats, "zscore_outliers", "flat")?, "null");
+
+        let stats = df.describe_with(&opts.zscore_threshold(5.0))?;
+        assert_eq!(cell(&stats, "zscore_outliers", "x")?, "0");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_sortedness() -> Result<()> {
+        let df = df! {
+            "asc" => [Some(1), Some(2), Some(2), Some(5), None],
+            "desc" => [None, Some("d"), Some("c"), Some("b"), Some("a")],
+            "unsorted" => [3.0, 1.0, 2.0, 4.0, 5.0],
+            "gap" => [Some(1), None, Some(2), Some(3), Some(4)],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::IsSortedAsc)
+            .statistic(Statistic::IsSortedDesc);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "is_sorted_asc", "asc")?, "true");
+        assert_eq!(cell(&stats, "is_sorted_desc", "asc")?, "false");
+        assert_eq!(cell(&stats, "is_sorted_asc", "desc")?, "false");
+        assert_eq!(cell(&stats, "is_sorted_desc", "desc")?, "true");
+        assert_eq!(cell(&stats, "is_sorted_asc", "unsorted")?, "false");
+        assert_eq!(cell(&stats, "is_sorted_desc", "unsorted")?, "false");
+        // A null between values breaks the order
+        assert_eq!(cell(&stats, "is_sorted_asc", "gap")?, "false");
+
+        let single = df! { "x" => [7] }?.describe_with(&opts)?;
+        assert_eq!(cell(&single, "is_sorted_asc", "x")?, "true");
+        assert_eq!(cell(&single, "is_sorted_desc", "x")?, "true");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_degenerate_columns() -> Result<()> {
+        let lists = Series::new(
+            "lists".into(),
+            [
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [1, 2]),
+            ],
+        );
+        let df = df! {
+            "same" => ["x", "x", "x"],
+            "same_or_null" => [Some(4), None, Some(4)],
+            "nothing" => [None::<f64>, None, None],
+            "varied" => [1, 2, 3],
+        }?
+        .hstack(&[lists.into()])?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::IsConstant)
+            .statistic(Statistic::AllNull);
+        let stats = df.describe_with(&opts)?;
+
+        assert_eq!(cell(&stats, "is_constant", "same")?, "true");
+        assert_eq!(cell(&stats, "is_constant", "same_or_null")?, "true");
+        assert_eq!(cell(&stats, "all_null", "same_or_null")?, "false");
+        assert_eq!(cell(&stats, "all_null", "nothing")?, "true");
+        assert_eq!(cell(&stats, "is_constant", "varied")?, "false");
+        assert_eq!(cell(&stats, "is_constant", "lists")?, "true");
+
+        assert_eq!(
+            df.lazy().degenerate_columns()?,
+            ["same", "same_or_null", "nothing", "lists"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_estimated_size() -> Result<()> {
+        let df = df! {
+            "small" => [1i32; 1_000],
+            "large" => [1.0f64; 1_000],
+            "text" => ["a fairly long string value"; 1_000],
+        }?;
+        let opts = DescribeOptions::new().statistic(Statistic::EstimatedSize);
+        let report = df.clone().lazy().describe_report(&opts)?;
+
+        let size = |name: &str| report.column(name).unwrap().estimated_size.unwrap();
+        assert!(size("small") > 0);
+        assert!(size("small") < size("large"));
+        assert!(size("large") < size("text"));
+
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(cell(&stats, "estimated_size", "small")?, "3.9 KiB");
+        assert_eq!(cell(&stats, "estimated_size", "large")?, "7.8 KiB");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_report_histograms() -> Result<()> {
+        let df = df! {
+            "values" => [Some(1.0), Some(2.0), None, Some(2.5), Some(7.0), Some(10.0)],
+            "strings" => ["a", "b", "c", "d", "e", "f"],
+        }?;
+
+        let report = df.describe_report(&DescribeOptions::new().histograms(4))?;
+
+        let values = report.column("values").unwrap();
+        let hist = values.histogram.as_ref().unwrap();
+        assert_eq!(hist.edges.len(), 5);
+        assert_eq!(hist.counts, vec![3, 0, 1, 1]);
+        assert_eq!(hist.counts.iter().sum::<u64>(), values.count);
+
+        // Histograms are only computed for numeric columns
+        assert!(report.column("strings").unwrap().histogram.is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_report_without_histograms() -> Result<()> {
+        let df = df! {
+            "values" => [1, 2, 3],
+        }?;
+
+        let report = df.lazy().describe_report(&DescribeOptions::new())?;
+
+        let values = report.column("values").unwrap();
+        assert_eq!(values.count, 3);
+        assert_eq!(values.mean, Some(2.0));
+        assert!(values.histogram.is_none());
 
         Ok(())
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_describe_report_histogram_json() -> Result<()> {
+        let df = df! {
+            "values" => [1, 2, 3, 4],
+        }?;
+
+        let report = df.describe_report(&DescribeOptions::new().histograms(2))?;
+        let json = serde_json::to_value(&report)?;
+
+        let hist = &json["columns"][0]["histogram"];
+        assert_eq!(hist["edges"], serde_json::json!([1.0, 2.5, 4.0]));
+        assert_eq!(hist["counts"], serde_json::json!([2, 2]));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "streaming")]
+    #[test]
+    fn test_streaming_engine_matches_in_memory() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.5), None, Some(-2.0), Some(4.25), Some(8.0)],
+            "name" => [Some("b"), Some("a"), None, Some("c"), Some("a")],
+            "flag" => [true, false, true, true, false],
+        }?;
+        let opts = DescribeOptions::new()
+            .statistic(Statistic::NUnique)
+            .engine(Engine::Streaming);
+        assert_eq!(opts.engine, Engine::Streaming);
+        assert!(format!("{opts:?}").contains("engine: Streaming"));
+
+        let streamed = df.clone().lazy().describe_with(&opts)?;
+        let in_memory = df.describe_with(&opts.clone().engine(Engine::InMemory))?;
+        assert!(streamed.equals_missing(&in_memory), "{streamed}");
+        Ok(())
+    }
+
+    /// Describes a Parquet file of 50M rows (~800 MB uncompressed) through a
+    /// streaming scan; run it under a memory limit, e.g. `ulimit -v`, with
+    /// `cargo test --release -- --ignored streaming`
+    #[cfg(all(feature = "streaming", feature = "parquet"))]
+    #[test]
+    #[ignore = "writes and scans a large Parquet file"]
+    fn test_streaming_engine_on_large_parquet_scan() -> Result<()> {
+        const BATCHES: i64 = 50;
+        const BATCH_ROWS: i64 = 1_000_000;
+        let path = std::env::temp_dir().join(format!(
+            "describe_df_{}_streaming.parquet",
+            std::process::id()
+        ));
+
+        let batch = |b: i64| -> Result<DataFrame> {
+            let ids: Vec<i64> = (b * BATCH_ROWS..(b + 1) * BATCH_ROWS).collect();
+            #[allow(clippy::cast_precision_loss)]
+            let values: Vec<f64> = ids.iter().map(|i| (i % 1000) as f64).collect();
+            Ok(df! { "id" => ids, "value" => values }?)
+        };
+        let file = std::fs::File::create(&path)?;
+        let mut writer = ParquetWriter::new(file).batched(batch(0)?.schema())?;
+        for b in 0..BATCHES {
+            writer.write_batch(&batch(b)?)?;
+        }
+        writer.finish()?;
+
+        let scan = LazyFrame::scan_parquet(
+            PlPath::new(path.to_str().unwrap()),
+            ScanArgsParquet::default(),
+        )?;
+        let opts = DescribeOptions::new()
+            .percentiles(vec![])
+            .engine(Engine::Streaming);
+        let stats = scan.describe_with(&opts);
+        std::fs::remove_file(&path)?;
+        let stats = stats?;
+
+        assert_eq!(
+            cell(&stats, "count", "id")?,
+            (BATCHES * BATCH_ROWS).to_string()
+        );
+        assert_eq!(cell(&stats, "mean", "value")?, "499.5");
+        Ok(())
+    }
+
+    #[test]
+    fn test_batched_metrics_match_single_select() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3), Some(4)],
+            "b" => [1.5, 2.5, f64::NAN, -1.0],
+            "name" => [Some("x"), Some("y"), None, Some("x")],
+            "flag" => [true, false, true, true],
+            "lists" => [
+                Series::new("".into(), [1, 2]),
+                Series::new("".into(), [3]),
+                Series::new("".into(), Vec::<i32>::new()),
+                Series::new("".into(), [4, 5, 6]),
+            ],
+        }?
+        .lazy();
+        let opts = DescribeOptions::new()
+            .percentiles(vec![0.1, 0.9])
+            .statistic(Statistic::NUnique)
+            .statistic(Statistic::EstimatedSize);
+
+        let single = compute_metrics(&df, &opts.clone().batch_columns(usize::MAX))?;
+        for batch_columns in [1, 2, 3] {
+            let batched = compute_metrics(&df, &opts.clone().batch_columns(batch_columns))?;
+            assert_eq!(batched.rows, single.rows);
+            assert!(batched.frame.equals_missing(&single.frame));
+            let rendered = render_metrics(&batched, &opts)?;
+            assert!(rendered.equals_missing(&render_metrics(&single, &opts)?));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_thousands_of_columns_in_batches() -> Result<()> {
+        let columns: Vec<Column> = (0..3_000)
+            .map(|i| Column::new(format!("c{i}").into(), [i, i + 1, i * 2]))
+            .collect();
+        let df = DataFrame::new(columns)?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let in_memory = df.describe_with(&opts)?;
+        assert_eq!(in_memory.width(), 3_001);
+        assert_eq!(cell(&in_memory, "max", "c2999")?, "5998");
+
+        // Wrapped in a query, the frame is a lazy source whose batches run
+        // one after the other
+        let lazy = df.lazy().select([all().as_expr()]);
+        for opts in [opts.clone(), opts.clone().batch_columns(500)] {
+            assert!(lazy.describe_with(&opts)?.equals_missing(&in_memory));
+            let plan = lazy.describe_lazy(&opts)?;
+            assert!(plan.collect()?.equals_missing(&in_memory));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_progress_milestones() -> Result<()> {
+        use std::sync::Mutex;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        let opts = DescribeOptions::new()
+            .on_progress(move |event| recorded.lock().unwrap().push(event))
+            .on_warning(|_| {});
+        let df = df! {
+            "a" => [1, 2, 3],
+            "b" => [1.5, 2.5, 3.5],
+            "c" => ["x", "y", "z"],
+        }?;
+
+        let batched = compute_metrics(&df.clone().lazy(), &opts.clone().batch_columns(2))?;
+        let expected = [
+            Progress::SchemaResolved,
+            Progress::ExpressionsBuilt,
+            Progress::CollectionStarted,
+            Progress::Batch {
+                columns_done: 2,
+                columns_total: 3,
+            },
+            Progress::Batch {
+                columns_done: 3,
+                columns_total: 3,
+            },
+            Progress::CollectionFinished,
+        ];
+        assert_eq!(*events.lock().unwrap(), expected);
+        let single = compute_metrics(&df.clone().lazy(), &DescribeOptions::new().batch_columns(2))?;
+        assert!(batched.frame.equals_missing(&single.frame));
+
+        events.lock().unwrap().clear();
+        let stats = df.describe_with(&opts)?;
+        assert!(stats.equals_missing(&df.describe(None)?));
+        let seen = events.lock().unwrap().clone();
+        assert_eq!(seen.first(), Some(&Progress::SchemaResolved));
+        assert_eq!(seen.last(), Some(&Progress::ReshapeDone));
+
+        // A panicking hook is reported as a warning, not a failed describe
+        let panicking = DescribeOptions::new()
+            .on_progress(|_| panic!("hook failure"))
+            .on_warning(|_| {});
+        assert!(df.describe_with(&panicking)?.equals_missing(&stats));
+        Ok(())
+    }
+
+    #[test]
+    fn test_cancel_between_batches() -> Result<()> {
+        let columns: Vec<Column> = (0..8)
+            .map(|i| Column::new(format!("c{i}").into(), [i, i + 1]))
+            .collect();
+        let df = DataFrame::new(columns)?.lazy();
+
+        let token = CancellationToken::new();
+        let canceller = token.clone();
+        let batches = Arc::new(AtomicUsize::new(0));
+        let seen = batches.clone();
+        let opts = DescribeOptions::new()
+            .cancel_with(token.clone())
+            .on_progress(move |event| {
+                if matches!(event, Progress::Batch { .. }) {
+                    seen.fetch_add(1, Ordering::Relaxed);
+                    canceller.cancel();
+                }
+            });
+        let err = compute_metrics(&df, &opts.clone().batch_columns(2))
+            .err()
+            .expect("cancelled describe");
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::Cancelled)
+        );
+        // The first batch ran, the second never started
+        assert_eq!(batches.load(Ordering::Relaxed), 1);
+        assert!(df.describe_with(&opts).is_err());
+
+        let timed_out = DescribeOptions::new().timeout(std::time::Duration::ZERO);
+        let err = df.describe_with(&timed_out).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::TimedOut)
+        );
+        let generous = DescribeOptions::new().timeout(std::time::Duration::from_secs(3600));
+        assert!(
+            df.describe_with(&generous)?
+                .equals_missing(&df.describe(None)?)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_wide_frame_matches_per_column_describe() -> Result<()> {
+        let columns: Vec<Column> = (0..600)
+            .map(|i| Column::new(format!("c{i}").into(), [i, i + 1, i * 2]))
+            .collect();
+        let df = DataFrame::new(columns)?;
+        let stats = df.describe(None)?;
+
+        assert_eq!(stats.width(), 601);
+        for name in ["c0", "c255", "c256", "c599"] {
+            let single = df.select([name])?.describe(None)?;
+            assert_eq!(stats.column(name)?, single.column(name)?, "{name}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,394 @@
+//! Drift report between the statistics of two frames
+//!
+//! `describe_diff` describes both frames with the same options and lines up
+//! every statistic of every column, so differences don't have to be spotted
+//! by eye in two describe outputs. `DescribeReport::diff` and
+//! `DescribeReport::approx_eq` make the same comparison between two reports.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::Describable;
+use crate::options::DescribeOptions;
+use crate::report::{ColumnSummary, DescribeReport, StatValue, label_percent};
+
+/// A statistic that differs between two reports, see
+/// `DescribeReport::diff`
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatDiff {
+    /// Name of the described column
+    pub column: String,
+    /// Row label of the statistic, e.g. `mean` or `25%`
+    pub statistic: String,
+    /// Value in the first report as shown by `describe_diff()`; `None`
+    /// when null or when the column is missing from that report
+    pub a: Option<String>,
+    /// Value in the second report, like `a`
+    pub b: Option<String>,
+    /// b − a, for numeric statistics present in both reports
+    pub delta: Option<f64>,
+}
+
+/// One compared statistic of one column
+struct DiffRow {
+    column: String,
+    statistic: String,
+    a: Option<String>,
+    b: Option<String>,
+    delta: Option<f64>,
+    rel_delta: Option<f64>,
+    changed: bool,
+    status: &'static str,
+}
+
+/// A statistic value: numeric statistics get deltas, the rest are compared
+/// for equality
+enum DiffValue {
+    Number(Option<f64>),
+    Text(Option<String>),
+    /// min/max: a number when numeric, rendered like `describe()` either way
+    Extreme(Option<StatValue>),
+}
+
+impl DiffValue {
+    fn render(&self) -> Option<String> {
+        match self {
+            DiffValue::Number(v) => v.map(|v| AnyValue::Float64(v).to_string()),
+            DiffValue::Text(s) => s.clone(),
+            DiffValue::Extreme(v) => v.as_ref().map(ToString::to_string),
+        }
+    }
+
+    fn number(&self) -> Option<f64> {
+        match self {
+            DiffValue::Number(v) => *v,
+            DiffValue::Text(_) => None,
+            DiffValue::Extreme(v) => v.as_ref().and_then(StatValue::as_f64),
+        }
+    }
+
+    /// min/max of an integer column, compared without going through f64
+    fn integer(&self) -> Option<i128> {
+        match self {
+            DiffValue::Extreme(v) => v.as_ref().and_then(StatValue::as_i128),
+            _ => None,
+        }
+    }
+}
+
+/// The statistics of a column summary in describe order, with the
+/// percentiles listed in `pct_labels`
+fn summary_values(summary: &ColumnSummary, pct_labels: &[String]) -> Vec<(String, DiffValue)> {
+    #[allow(clippy::cast_precision_loss)]
+    let mut values = vec![
+        (
+            "dtype".to_string(),
+            DiffValue::Text(Some(summary.dtype.clone())),
+        ),
+        (
+            "count".to_string(),
+            DiffValue::Number(Some(summary.count as f64)),
+        ),
+        (
+            "null_count".to_string(),
+            DiffValue::Number(Some(summary.null_count as f64)),
+        ),
+        ("mean".to_string(), DiffValue::Number(summary.mean)),
+        ("std".to_string(), DiffValue::Number(summary.std)),
+        ("min".to_string(), DiffValue::Extreme(summary.min.clone())),
+    ];
+    values.extend(pct_labels.iter().map(|label| {
+        (
+            label.clone(),
+            DiffValue::Number(summary.percentiles.get(label).copied()),
+        )
+    }));
+    values.push(("max".to_string(), DiffValue::Extreme(summary.max.clone())));
+    values
+}
+
+/// Compare the statistics of `a` and `b` column by column
+///
+/// Returns a long DataFrame with one row per column and statistic:
+/// - `column`, `statistic`
+/// - `a`, `b`: the values as rendered in the reports
+/// - `delta` (b − a) and `rel_delta` (delta / |a|) for numeric statistics,
+///   min and max of numeric columns included
+/// - `changed`: whether the values differ; numeric statistics within the
+///   tolerances of `DescribeOptions::diff_tolerance` count as unchanged
+/// - `status`: `both`, or `only_a` / `only_b` for columns present in one
+///   frame only
+///
+/// Columns shared by both frames come first, in the order of `a`.
+///
+/// # Example
+/// ```rust
+/// use polars::prelude::*;
+/// use describe_df::{DescribeOptions, describe_diff};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let old = df! { "price" => [1.0, 2.0, 3.0] }?;
+/// let new = df! { "price" => [1.5, 2.5, 3.5] }?;
+///
+/// let drift = describe_diff(&old, &new, &DescribeOptions::new())?;
+/// println!("{drift}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn describe_diff(
+    a: &impl Describable,
+    b: &impl Describable,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let report_a = a.describe_report(opts)?;
+    let report_b = b.describe_report(opts)?;
+    diff_frame(compare_reports(
+        &report_a,
+        &report_b,
+        opts.diff_abs_tolerance,
+        opts.diff_rel_tolerance,
+    ))
+}
+
+/// The statistics that differ between `a` and `b` beyond the tolerances,
+/// in the order of `describe_diff()`
+pub(crate) fn report_diffs(
+    a: &DescribeReport,
+    b: &DescribeReport,
+    abs_tolerance: f64,
+    rel_tolerance: f64,
+) -> Vec<StatDiff> {
+    compare_reports(a, b, abs_tolerance, rel_tolerance)
+        .into_iter()
+        .filter(|row| row.changed)
+        .map(|row| StatDiff {
+            column: row.column,
+            statistic: row.statistic,
+            a: row.a,
+            b: row.b,
+            delta: row.delta,
+        })
+        .collect()
+}
+
+/// Every statistic of every column of `a` and `b`, compared
+fn compare_reports(
+    report_a: &DescribeReport,
+    report_b: &DescribeReport,
+    abs_tolerance: f64,
+    rel_tolerance: f64,
+) -> Vec<DiffRow> {
+    let mut pct_labels = report_a.percentile_labels();
+    pct_labels.extend(report_b.percentile_labels());
+    pct_labels.sort_by(|x, y| label_percent(x).total_cmp(&label_percent(y)));
+    pct_labels.dedup();
+
+    let mut rows = Vec::new();
+    for summary_a in &report_a.columns {
+        let Some(summary_b) = report_b.column(&summary_a.name) else {
+            continue;
+        };
+        let values_b = summary_values(summary_b, &pct_labels);
+        for ((statistic, value_a), (_, value_b)) in summary_values(summary_a, &pct_labels)
+            .into_iter()
+            .zip(values_b)
+        {
+            rows.push(compare(
+                &summary_a.name,
+                statistic,
+                &value_a,
+                &value_b,
+                abs_tolerance,
+                rel_tolerance,
+            ));
+        }
+    }
+
+    // Columns present in only one of the frames
+    let one_sided = report_a
+        .columns
+        .iter()
+        .filter(|c| report_b.column(&c.name).is_none())
+        .map(|c| (c, "only_a"))
+        .chain(
+            report_b
+                .columns
+                .iter()
+                .filter(|c| report_a.column(&c.name).is_none())
+                .map(|c| (c, "only_b")),
+        );
+    for (summary, status) in one_sided {
+        for (statistic, value) in summary_values(summary, &pct_labels) {
+            let (a, b) = if status == "only_a" {
+                (value.render(), None)
+            } else {
+                (None, value.render())
+            };
+            rows.push(DiffRow {
+                column: summary.name.clone(),
+                statistic,
+                a,
+                b,
+                delta: None,
+                rel_delta: None,
+                changed: true,
+                status,
+            });
+        }
+    }
+
+    rows
+}
+
+fn compare(
+    column: &str,
+    statistic: String,
+    a: &DiffValue,
+    b: &DiffValue,
+    abs_tolerance: f64,
+    rel_tolerance: f64,
+) -> DiffRow {
+    // Large integers (e.g. IDs near u64::MAX) round to the same float, so
+    // their delta is taken exactly first
+    #[allow(clippy::cast_precision_loss)]
+    let exact_delta = match (a.integer(), b.integer()) {
+        (Some(a), Some(b)) => Some((b - a) as f64),
+        _ => None,
+    };
+    let (delta, rel_delta, changed) = match (a.number(), b.number()) {
+        // Two NaNs are the same value, as in `DataFrame::equals_missing`
+        (Some(a), Some(b)) if a.is_nan() && b.is_nan() => (None, None, false),
+        (Some(a), Some(b)) => {
+            let delta = exact_delta.unwrap_or(b - a);
+            let rel_delta = (a != 0.0).then(|| delta / a.abs());
+            let within_tolerance =
+                delta.abs() <= abs_tolerance || delta.abs() <= rel_tolerance * a.abs();
+            (Some(delta), rel_delta, !within_tolerance)
+        }
+        _ => (None, None, a.render() != b.render()),
+    };
+    DiffRow {
+        column: column.to_string(),
+        statistic,
+        a: a.render(),
+        b: b.render(),
+        delta,
+        rel_delta,
+        changed,
+        status: "both",
+    }
+}
+
+fn diff_frame(rows: Vec<DiffRow>) -> Result<DataFrame> {
+    let column: Vec<String> = rows.iter().map(|r| r.column.clone()).collect();
+    let statistic: Vec<String> = rows.iter().map(|r| r.statistic.clone()).collect();
+    let a: Vec<Option<String>> = rows.iter().map(|r| r.a.clone()).collect();
+    let b: Vec<Option<String>> = rows.iter().map(|r| r.b.clone()).collect();
+    let delta: Vec<Option<f64>> = rows.iter().map(|r| r.delta).collect();
+    let rel_delta: Vec<Option<f64>> = rows.iter().map(|r| r.rel_delta).collect();
+    let changed: Vec<bool> = rows.iter().map(|r| r.changed).collect();
+    let status: Vec<&str> = rows.iter().map(|r| r.status).collect();
+
+    DataFrame::new(vec![
+        Column::new("column".into(), column),
+        Column::new("statistic".into(), statistic),
+        Column::new("a".into(), a),
+        Column::new("b".into(), b),
+        Column::new("delta".into(), delta),
+        Column::new("rel_delta".into(), rel_delta),
+        Column::new("changed".into(), changed),
+        Column::new("status".into(), status),
+    ])
+    .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn row(diff: &DataFrame, column: &str, statistic: &str) -> Result<usize> {
+        let columns = diff.column("column")?.str()?;
+        let statistics = diff.column("statistic")?.str()?;
+        Ok((0..diff.height())
+            .find(|&i| columns.get(i) == Some(column) && statistics.get(i) == Some(statistic))
+            .unwrap())
+    }
+
+    #[test]
+    fn test_describe_diff_identical_frames() -> Result<()> {
+        let df = df! { "x" => [1.0, 2.0, 4.0], "s" => ["a", "b", "c"] }?;
+        let diff = describe_diff(&df, &df.clone().lazy(), &DescribeOptions::new())?;
+
+        assert!(!diff.column("changed")?.bool()?.any());
+        let deltas = diff.column("delta")?.f64()?;
+        assert!(deltas.into_iter().flatten().all(|d| d == 0.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_diff_large_integer_extremes() -> Result<()> {
+        // Both maxima round to 2^64 as floats
+        let a = df! { "id" => [3, u64::MAX - 1] }?;
+        let b = df! { "id" => [3, u64::MAX] }?;
+        let diff = describe_diff(&a, &b, &DescribeOptions::new())?;
+
+        let i = row(&diff, "id", "max")?;
+        assert_eq!(
+            diff.column("a")?.str()?.get(i),
+            Some("18446744073709551614")
+        );
+        assert_eq!(
+            diff.column("b")?.str()?.get(i),
+            Some("18446744073709551615")
+        );
+        assert_eq!(diff.column("delta")?.f64()?.get(i), Some(1.0));
+        assert_eq!(diff.column("changed")?.bool()?.get(i), Some(true));
+        assert!(!a.describe_report(&DescribeOptions::new())?.approx_eq(
+            &b.describe_report(&DescribeOptions::new())?,
+            0.0,
+            0.0
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_diff_shifted_mean() -> Result<()> {
+        let a = df! { "x" => [1.0, 2.0, 3.0] }?;
+        let b = df! { "x" => [3.0, 4.0, 5.0] }?;
+        let diff = describe_diff(&a, &b, &DescribeOptions::new())?;
+
+        let i = row(&diff, "x", "mean")?;
+        assert_eq!(diff.column("delta")?.f64()?.get(i), Some(2.0));
+        assert_eq!(diff.column("rel_delta")?.f64()?.get(i), Some(1.0));
+        assert_eq!(diff.column("changed")?.bool()?.get(i), Some(true));
+
+        // The shift doesn't change the spread
+        let i = row(&diff, "x", "std")?;
+        assert_eq!(diff.column("changed")?.bool()?.get(i), Some(false));
+
+        // A tolerance larger than the shift marks it unchanged
+        let tolerant = DescribeOptions::new().diff_tolerance(2.5, 0.0);
+        let diff = describe_diff(&a, &b, &tolerant)?;
+        let i = row(&diff, "x", "mean")?;
+        assert_eq!(diff.column("changed")?.bool()?.get(i), Some(false));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_diff_mismatched_schemas() -> Result<()> {
+        let a = df! { "x" => [1, 2], "old" => ["a", "b"] }?;
+        let b = df! { "x" => [1.0, 2.0], "new" => [true, false] }?;
+        let diff = describe_diff(&a, &b, &DescribeOptions::new())?;
+
+        let status = diff.column("status")?.str()?;
+        assert_eq!(status.get(row(&diff, "old", "count")?), Some("only_a"));
+        assert_eq!(status.get(row(&diff, "new", "count")?), Some("only_b"));
+
+        // The dtype change is reported as a changed text statistic
+        let i = row(&diff, "x", "dtype")?;
+        assert_eq!(diff.column("changed")?.bool()?.get(i), Some(true));
+        assert_eq!(diff.column("a")?.str()?.get(i), Some("i32"));
+        Ok(())
+    }
+}
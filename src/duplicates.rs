@@ -0,0 +1,137 @@
+//! Duplicate rows and values
+//!
+//! `duplicates_report()` counts repeated rows and, per column, repeated
+//! non-null values in a single lazy pass. Rows are compared as structs of
+//! all their columns, so nested columns (lists, structs) take part in the
+//! comparison like any other column.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::error::DescribeError;
+
+/// Result of `Describable::duplicates_report`
+#[derive(Debug, Clone)]
+pub struct DuplicatesReport {
+    /// Rows that repeat an earlier row in every column; two identical rows
+    /// count as one duplicate
+    pub duplicate_rows: u64,
+    /// One row per column: `column` and `dup_count` (UInt64), the non-null
+    /// values that repeat an earlier value (count − n_unique)
+    pub columns: DataFrame,
+}
+
+/// Non-null values of `values` repeating an earlier value
+pub(crate) fn dup_count_expr(values: &Expr) -> Expr {
+    values.clone().count().cast(DataType::UInt64)
+        - values
+            .clone()
+            .drop_nulls()
+            .n_unique()
+            .cast(DataType::UInt64)
+}
+
+/// Rows repeating an earlier row in every column
+pub(crate) fn duplicate_rows_expr() -> Expr {
+    len().cast(DataType::UInt64)
+        - as_struct(vec![all().as_expr()])
+            .n_unique()
+            .cast(DataType::UInt64)
+}
+
+pub(crate) fn duplicates_report_impl(lazy_frame: &LazyFrame) -> Result<DuplicatesReport> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    if schema.is_empty() {
+        return Err(DescribeError::EmptySchema.into());
+    }
+
+    let mut exprs = vec![duplicate_rows_expr().alias("__duplicate_rows")];
+    exprs.extend(
+        schema
+            .iter_names()
+            .map(|name| dup_count_expr(&col(name.clone())).alias(format!("dup_count:{name}"))),
+    );
+    let stats = lazy_frame.clone().select(exprs).collect()?;
+
+    let value =
+        |alias: &str| -> Result<u64> { Ok(stats.column(alias)?.u64()?.get(0).unwrap_or(0)) };
+    let duplicate_rows = value("__duplicate_rows")?;
+    let names: Vec<&str> = schema.iter_names().map(|name| name.as_str()).collect();
+    let dup_counts = names
+        .iter()
+        .map(|name| value(&format!("dup_count:{name}")))
+        .collect::<Result<Vec<u64>>>()?;
+    let columns = DataFrame::new(vec![
+        Column::new("column".into(), &names),
+        Column::new("dup_count".into(), dup_counts),
+    ])?;
+    Ok(DuplicatesReport {
+        duplicate_rows,
+        columns,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Describable, DescribeOptions, Statistic};
+
+    #[test]
+    fn test_duplicates_report() -> Result<()> {
+        let mut df = df! {
+            "id" => [1, 2, 1, 3],
+            "city" => [Some("Oslo"), None, Some("Oslo"), None],
+            "flag" => [true, true, true, true],
+            "tags" => [
+                Series::new("".into(), ["a"]),
+                Series::new("".into(), ["b"]),
+                Series::new("".into(), ["a"]),
+                Series::new("".into(), ["a", "b"]),
+            ],
+        }?;
+        df = df
+            .lazy()
+            .with_column(as_struct(vec![col("id"), col("city")]).alias("pair"))
+            .collect()?;
+        let report = df.duplicates_report()?;
+        assert_eq!(report.duplicate_rows, 1);
+        let dup_counts: Vec<Option<u64>> = report
+            .columns
+            .column("dup_count")?
+            .u64()?
+            .into_iter()
+            .collect();
+        assert_eq!(dup_counts, [Some(1), Some(1), Some(3), Some(1), Some(1)]);
+
+        let stats = df.describe_with(&DescribeOptions::new().statistic(Statistic::DupCount))?;
+        let row = stats
+            .column("statistic")?
+            .str()?
+            .iter()
+            .position(|l| l == Some("dup_count"));
+        let value = |column: &str| {
+            stats
+                .column(column)
+                .unwrap()
+                .str()
+                .unwrap()
+                .get(row.unwrap())
+        };
+        assert_eq!(value("flag"), Some("3"));
+        assert_eq!(value("city"), Some("1"));
+        // Nested columns are counted like in the report
+        assert_eq!(value("tags"), Some("1"));
+        assert_eq!(value("pair"), Some("1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_duplicates_report_without_duplicates() -> Result<()> {
+        let lf = df! { "a" => [1, 2, 3], "b" => ["x", "x", "y"] }?.lazy();
+        let report = lf.duplicates_report()?;
+        assert_eq!(report.duplicate_rows, 0);
+        let empty = lf.limit(0).duplicates_report()?;
+        assert_eq!(empty.duplicate_rows, 0);
+        Ok(())
+    }
+}
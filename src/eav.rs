@@ -0,0 +1,184 @@
+//! Describe long entity-attribute-value data as if it were wide
+//!
+//! Long frames (`entity`, `attribute`, `value`) are grouped by the attribute
+//! column and the numeric metric set is computed over the value column per
+//! attribute. The result is reshaped exactly like an ordinary describe, with
+//! one output column per attribute.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::{
+    ColumnGap, Metrics, column_metric_exprs, metric_rows, render_metrics, resolved_percentiles,
+};
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+/// Name the value column takes inside the grouped aggregation
+const VALUE_KEY: &str = "value";
+
+/// Alias of the per-attribute count of values that failed to parse
+const UNPARSED_KEY: &str = "__unparsed";
+
+pub(crate) fn describe_eav_impl(
+    lazy_frame: &LazyFrame,
+    attribute_col: &str,
+    value_col: &str,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    for name in [attribute_col, value_col] {
+        if !schema.contains(name) {
+            return Err(DescribeError::ColumnNotFound(name.to_string()).into());
+        }
+    }
+
+    let percentiles = resolved_percentiles(opts)?;
+
+    // Values are described as Float64; text values that don't parse become null
+    let raw = col(value_col);
+    let value = raw.clone().cast(DataType::Float64);
+
+    let mut agg_exprs = column_metric_exprs(
+        value.clone(),
+        VALUE_KEY,
+        &DataType::Float64,
+        &percentiles,
+        opts,
+    );
+    agg_exprs.push(
+        raw.is_not_null()
+            .and(value.is_null())
+            .sum()
+            .alias(UNPARSED_KEY),
+    );
+
+    // Stable grouping keeps attributes in order of first appearance
+    let grouped = lazy_frame
+        .clone()
+        .group_by_stable([col(attribute_col).cast(DataType::String)])
+        .agg(agg_exprs)
+        .collect()?;
+
+    let attributes = grouped.column(attribute_col)?.str()?.clone();
+    let unparsed = grouped.column(UNPARSED_KEY)?.cast(&DataType::UInt64)?;
+    let unparsed = unparsed.u64()?;
+    let metric_names: Vec<String> = grouped
+        .get_column_names()
+        .into_iter()
+        .filter(|name| name.as_str() != attribute_col && name.as_str() != UNPARSED_KEY)
+        .map(|name| name.to_string())
+        .collect();
+
+    // Turn each per-attribute row into `<metric>:<attribute>` columns of a
+    // single-row frame, the same shape the ordinary aggregation produces
+    let suffix = format!(":{VALUE_KEY}");
+    let mut fields = Vec::new();
+    let mut columns = Vec::new();
+    for (row, attribute) in attributes.iter().enumerate() {
+        let Some(attribute) = attribute else {
+            opts.warn("skipping rows with a null attribute");
+            continue;
+        };
+        if unparsed.get(row).unwrap_or(0) > 0 {
+            opts.warn(&format!(
+                "skipping attribute '{attribute}': values are not numeric"
+            ));
+            continue;
+        }
+
+        fields.push(Field::new(attribute.into(), DataType::Float64));
+        for name in &metric_names {
+            let prefix = name.strip_suffix(&suffix).unwrap_or(name);
+            #[allow(clippy::cast_possible_wrap)]
+            let value = grouped
+                .column(name)?
+                .slice(row as i64, 1)
+                .with_name(format!("{prefix}:{attribute}").into());
+            columns.push(value);
+        }
+    }
+
+    if fields.is_empty() {
+        return Err(DescribeError::EmptySchema).with_context(|| {
+            format!("no attribute in '{attribute_col}' has numeric values to describe")
+        });
+    }
+
+    let metrics = Metrics {
+        schema: Arc::new(Schema::from_iter(fields)),
+        rows: metric_rows(&percentiles, opts)?,
+        percentiles,
+        frame: DataFrame::new(columns)?,
+        gap: ColumnGap::default(),
+    };
+    render_metrics(&metrics, opts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_describe_eav_matches_wide() -> Result<()> {
+        let long = df! {
+            "entity" => [1, 1, 1, 2, 2, 2, 3, 3, 3, 4, 4, 4],
+            "attribute" => ["height", "weight", "age", "height", "weight", "age",
+                            "height", "weight", "age", "height", "weight", "age"],
+            "value" => [Some(1.8), Some(80.0), Some(30.0), Some(1.6), Some(55.0), None,
+                        Some(1.7), Some(70.0), Some(41.0), Some(1.9), Some(95.0), Some(25.0)],
+        }?;
+        let wide = df! {
+            "height" => [Some(1.8), Some(1.6), Some(1.7), Some(1.9)],
+            "weight" => [Some(80.0), Some(55.0), Some(70.0), Some(95.0)],
+            "age" => [Some(30.0), None, Some(41.0), Some(25.0)],
+        }?;
+
+        let opts = DescribeOptions::new();
+        let from_long = long.describe_eav("attribute", "value", &opts)?;
+        let from_wide = wide.describe_with(&opts)?;
+
+        assert!(from_long.equals_missing(&from_wide));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_eav_skips_non_numeric_attributes() -> Result<()> {
+        let long = df! {
+            "attribute" => ["score", "score", "label", "label"],
+            "value" => ["1.5", "2.5", "red", "blue"],
+        }?;
+
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&warnings);
+        let opts = DescribeOptions::new()
+            .on_warning(move |msg| sink.lock().unwrap().push(msg.to_string()));
+
+        let stats = long.lazy().describe_eav("attribute", "value", &opts)?;
+
+        assert_eq!(stats.get_column_names(), vec!["statistic", "score"]);
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 1);
+        assert!(warnings[0].contains("label"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_eav_missing_column() -> Result<()> {
+        let long = df! {
+            "attribute" => ["a"],
+            "value" => [1.0],
+        }?;
+
+        assert!(
+            long.describe_eav("attr", "value", &DescribeOptions::new())
+                .is_err()
+        );
+
+        Ok(())
+    }
+}
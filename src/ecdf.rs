@@ -0,0 +1,218 @@
+//! Percentile-of-value lookups, the inverse of the describe percentiles
+//!
+//! `percentile_of()` evaluates the empirical CDF of a column at a given
+//! value, `mean(col <= value)` over the non-null values, for every
+//! requested (column, value) pair in a single lazy pass. Temporal columns
+//! are compared on their physical values.
+
+use anyhow::Result;
+#[cfg(feature = "temporal")]
+use chrono::NaiveDate;
+use polars::prelude::*;
+
+use crate::error::DescribeError;
+
+/// Physical datetime units in a day
+#[cfg(feature = "temporal")]
+fn units_per_day(unit: TimeUnit) -> f64 {
+    match unit {
+        TimeUnit::Nanoseconds => 86_400_000_000_000.0,
+        TimeUnit::Microseconds => 86_400_000_000.0,
+        TimeUnit::Milliseconds => 86_400_000.0,
+    }
+}
+
+/// Dtype of `name`, which must be numeric or temporal
+fn lookup_dtype<'a>(schema: &'a Schema, name: &str) -> Result<&'a DataType> {
+    let dtype = schema
+        .get(name)
+        .ok_or_else(|| DescribeError::ColumnNotFound(name.to_string()))?;
+    if !(dtype.is_primitive_numeric() || dtype.is_temporal()) {
+        return Err(DescribeError::UnsupportedDtype {
+            column: name.to_string(),
+            dtype: dtype.clone(),
+            expected: "numeric or temporal",
+        }
+        .into());
+    }
+    Ok(dtype)
+}
+
+pub(crate) fn percentile_of_impl(
+    lazy_frame: &LazyFrame,
+    values: &[(&str, f64)],
+) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    for (name, _) in values {
+        lookup_dtype(&schema, name)?;
+    }
+    let thresholds: Vec<f64> = values.iter().map(|(_, value)| *value).collect();
+    ecdf_frame(
+        lazy_frame,
+        values,
+        &thresholds,
+        Column::new("value".into(), thresholds.clone()),
+    )
+}
+
+#[cfg(feature = "temporal")]
+pub(crate) fn percentile_of_dates_impl(
+    lazy_frame: &LazyFrame,
+    values: &[(&str, NaiveDate)],
+) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let mut thresholds = Vec::with_capacity(values.len());
+    for (name, date) in values {
+        let days = f64::from(date.to_epoch_days());
+        let threshold = match lookup_dtype(&schema, name)? {
+            DataType::Date => days,
+            // Midnight UTC, the epoch of the physical datetime values
+            DataType::Datetime(unit, _) => days * units_per_day(*unit),
+            dtype => {
+                return Err(DescribeError::UnsupportedDtype {
+                    column: name.to_string(),
+                    dtype: dtype.clone(),
+                    expected: "a Date or Datetime column",
+                }
+                .into());
+            }
+        };
+        thresholds.push(threshold);
+    }
+    let dates: Vec<NaiveDate> = values.iter().map(|(_, date)| *date).collect();
+    ecdf_frame(
+        lazy_frame,
+        values,
+        &thresholds,
+        Column::new("value".into(), dates),
+    )
+}
+
+/// `column`, `value`, `fraction` and `n_equal` rows for `values`, compared
+/// against the physical `thresholds`
+fn ecdf_frame<T>(
+    lazy_frame: &LazyFrame,
+    values: &[(&str, T)],
+    thresholds: &[f64],
+    value: Column,
+) -> Result<DataFrame> {
+    let names: Vec<&str> = values.iter().map(|(name, _)| *name).collect();
+    let (fractions, n_equal) = if values.is_empty() {
+        (Vec::new(), Vec::new())
+    } else {
+        let mut exprs = Vec::with_capacity(2 * values.len());
+        for (i, (name, threshold)) in names.iter().zip(thresholds).enumerate() {
+            let physical = col(*name).to_physical().cast(DataType::Float64);
+            exprs.push(
+                physical
+                    .clone()
+                    .lt_eq(lit(*threshold))
+                    .cast(DataType::Float64)
+                    .mean()
+                    .alias(format!("fraction:{i}")),
+            );
+            exprs.push(
+                physical
+                    .eq(lit(*threshold))
+                    .sum()
+                    .cast(DataType::UInt64)
+                    .alias(format!("n_equal:{i}")),
+            );
+        }
+        let stats = lazy_frame.clone().select(exprs).collect()?;
+        let mut fractions = Vec::with_capacity(values.len());
+        let mut n_equal = Vec::with_capacity(values.len());
+        for i in 0..values.len() {
+            fractions.push(stats.column(&format!("fraction:{i}"))?.f64()?.get(0));
+            n_equal.push(
+                stats
+                    .column(&format!("n_equal:{i}"))?
+                    .u64()?
+                    .get(0)
+                    .unwrap_or(0),
+            );
+        }
+        (fractions, n_equal)
+    };
+
+    DataFrame::new(vec![
+        Column::new("column".into(), names),
+        value,
+        Column::new("fraction".into(), fractions),
+        Column::new("n_equal".into(), n_equal),
+    ])
+    .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_percentile_of_numeric() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(2.0), Some(2.0), None, Some(4.0), Some(5.0)],
+            "n" => [10, 20, 30, 40, 50, 60],
+        }?;
+        let result = df.percentile_of(&[("x", 0.5), ("x", 9.0), ("x", 2.0), ("n", 35.0)])?;
+
+        let expected = df! {
+            "column" => ["x", "x", "x", "n"],
+            "value" => [0.5, 9.0, 2.0, 35.0],
+            // Nulls are ignored: 3 of the 5 values of x are <= 2
+            "fraction" => [0.0, 1.0, 0.6, 0.5],
+            "n_equal" => [0u64, 0, 2, 0],
+        }?;
+        assert!(result.equals(&expected), "{result}");
+        Ok(())
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_percentile_of_dates() -> Result<()> {
+        let day = |d| NaiveDate::from_ymd_opt(2024, 1, d).unwrap();
+        let df = df! { "day" => [day(1), day(2), day(3), day(4)] }?
+            .lazy()
+            .with_column(
+                col("day")
+                    .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                    .alias("ts"),
+            );
+        let result = df.percentile_of_dates(&[("day", day(2)), ("ts", day(3))])?;
+
+        let fractions: Vec<Option<f64>> = result.column("fraction")?.f64()?.into_iter().collect();
+        assert_eq!(fractions, [Some(0.5), Some(0.75)]);
+        let n_equal: Vec<Option<u64>> = result.column("n_equal")?.u64()?.into_iter().collect();
+        assert_eq!(n_equal, [Some(1), Some(1)]);
+        assert_eq!(result.column("value")?.dtype(), &DataType::Date);
+        Ok(())
+    }
+
+    #[test]
+    fn test_percentile_of_errors() -> Result<()> {
+        let df = df! { "x" => [1, 2], "s" => ["a", "b"] }?;
+
+        let err = df.percentile_of(&[("missing", 1.0)]).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::ColumnNotFound("missing".to_string()))
+        );
+        let err = df.percentile_of(&[("s", 1.0)]).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DescribeError>(),
+            Some(DescribeError::UnsupportedDtype { column, .. }) if column == "s"
+        ));
+        #[cfg(feature = "temporal")]
+        {
+            let err = df
+                .percentile_of_dates(&[("x", NaiveDate::from_ymd_opt(2024, 1, 1).unwrap())])
+                .unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DescribeError>(),
+                Some(DescribeError::UnsupportedDtype { column, .. }) if column == "x"
+            ));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,108 @@
+//! Errors raised by describe_df itself
+//!
+//! The public functions return `anyhow::Result`, so errors can carry the
+//! context added along the way (e.g. the path of a file). Every failure the
+//! crate detects on its own is a `DescribeError` underneath, so it can be
+//! matched with `err.downcast_ref::<DescribeError>()`, context or not.
+//! Failures of the Polars queries themselves stay `PolarsError`s and
+//! downcast to that type instead, as do failures raised while collecting
+//! the plan of `Describable::describe_lazy()`.
+
+use polars::prelude::DataType;
+
+/// A describe failure detected by describe_df
+///
+/// # Example
+/// ```rust
+/// use polars::prelude::*;
+/// use describe_df::{Describable, DescribeError};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let df = df! { "x" => [1, 2, 3] }?;
+/// let err = df.describe(Some(vec![1.5])).unwrap_err();
+/// assert_eq!(
+///     err.downcast_ref::<DescribeError>(),
+///     Some(&DescribeError::InvalidPercentile(1.5))
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, thiserror::Error)]
+pub enum DescribeError {
+    /// The frame (or the selection of it to describe) has no columns
+    #[error("cannot describe a frame that has no columns")]
+    EmptySchema,
+    /// A percentile outside [0, 1]
+    #[error("percentile {0} is outside the range [0, 1]")]
+    InvalidPercentile(f64),
+    /// A percentile listed twice under `DescribeOptions::strict_percentiles`
+    #[error("percentile {0} was requested more than once")]
+    DuplicatePercentile(f64),
+    /// A column named in the options or arguments isn't in the frame
+    #[error("column '{0}' not found")]
+    ColumnNotFound(String),
+    /// Columns that would share a name in the output: repeated by unnested
+    /// struct fields or named `statistic`; see
+    /// `DescribeOptions::dedupe_names`
+    #[error(
+        "duplicate column names in the output: {}; rename them or use dedupe_names",
+        .0.join(", ")
+    )]
+    DuplicateColumns(Vec<String>),
+    /// A column whose dtype the requested operation can't use
+    #[error("column '{column}' must be {expected}, found {dtype}")]
+    UnsupportedDtype {
+        column: String,
+        dtype: DataType,
+        /// What the operation accepts, e.g. `numeric`
+        expected: &'static str,
+    },
+    /// A NaN in a float column under `DescribeOptions::nan_is_error`
+    #[error("column '{0}' contains NaN values")]
+    NanValues(String),
+    /// count + null_count of a column differs from the frame height under
+    /// `DescribeOptions::strict_counts`
+    #[error(
+        "column '{column}' has count {count} + null_count {null_count}, expected {total_rows} rows"
+    )]
+    InconsistentCounts {
+        column: String,
+        count: u64,
+        null_count: u64,
+        total_rows: u64,
+    },
+    /// A percentile of a numeric column below min, above max or below a
+    /// lower percentile under `DescribeOptions::strict_quantiles`
+    #[error(
+        "percentile {percentile} of column '{column}' is out of order with min, max or a lower percentile"
+    )]
+    NonMonotoneQuantiles {
+        column: String,
+        /// Label of the percentile, e.g. `75%`
+        percentile: String,
+    },
+    /// Negative weights in the weight column of `describe_weighted`
+    #[error("weight column '{0}' contains negative weights")]
+    NegativeWeights(String),
+    /// Weights summing to zero over a non-empty frame in `describe_weighted`
+    #[error("weight column '{0}' has no positive weights")]
+    ZeroWeights(String),
+    /// The schema differs from the previous report of
+    /// `describe_incremental` under `DescribeOptions::error_on_schema_change`
+    #[error("schema changed: {0}")]
+    SchemaChanged(String),
+    /// An option or argument out of its valid range
+    #[error("{0}")]
+    InvalidArgument(String),
+    /// The `CancellationToken` passed to `DescribeOptions::cancel_with` was
+    /// cancelled
+    #[error("describe was cancelled")]
+    Cancelled,
+    /// The describe ran longer than `DescribeOptions::timeout`
+    #[error("describe timed out")]
+    TimedOut,
+    /// An aggregation result lacks a value describe_df put in the query; a
+    /// bug in describe_df
+    #[error("internal error: {0}")]
+    Internal(String),
+}
@@ -0,0 +1,145 @@
+//! Describing computed columns and filtered rows
+//!
+//! `describe_exprs()` selects the expressions on top of the lazy plan and
+//! describes the selection, so the computed columns only exist inside the
+//! aggregation pass. `describe_where()` likewise adds a filter to the plan.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::describe_lazy_impl;
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+pub(crate) fn describe_exprs_impl(
+    lazy_frame: &LazyFrame,
+    exprs: Vec<Expr>,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    if exprs.is_empty() {
+        return Err(DescribeError::InvalidArgument(
+            "at least one expression is required".to_string(),
+        )
+        .into());
+    }
+    let named: Vec<Expr> = exprs
+        .into_iter()
+        .enumerate()
+        .map(|(i, expr)| {
+            if has_output_name(&expr) {
+                expr
+            } else {
+                expr.alias(format!("expr_{i}"))
+            }
+        })
+        .collect();
+    describe_lazy_impl(&lazy_frame.clone().select(named), opts)
+}
+
+pub(crate) fn describe_where_impl(
+    lazy_frame: &LazyFrame,
+    predicate: Expr,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let mut filtered = lazy_frame.clone().filter(predicate);
+    // Resolving the schema checks the predicate before anything is read
+    filtered
+        .collect_schema()
+        .context("invalid describe_where predicate")?;
+    describe_lazy_impl(&filtered, opts)
+}
+
+/// Whether the expression chose its output name(s): aliases, plain columns,
+/// selectors and name functions such as `.name().suffix()`
+///
+/// Anything else would be named after its leftmost input column by Polars,
+/// e.g. `col("a") * 2` as "a", and clash with that column.
+fn has_output_name(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Alias(..)
+            | Expr::Column(_)
+            | Expr::Selector(_)
+            | Expr::KeepName(_)
+            | Expr::RenameAlias { .. }
+            | Expr::Field(_)
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn cell(stats: &DataFrame, statistic: &str, column: &str) -> Result<f64> {
+        let labels = stats.column("statistic")?.str()?;
+        let idx = labels.iter().position(|l| l == Some(statistic)).unwrap();
+        Ok(stats.column(column)?.str()?.get(idx).unwrap().parse()?)
+    }
+
+    #[test]
+    fn test_describe_exprs_doubled_mean() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1.5), Some(4.0), None, Some(7.25)],
+            "b" => [1, 2, 3, 4],
+        }?;
+        let stats = df.describe_exprs(
+            vec![
+                col("a"),
+                (col("a") * lit(2)).alias("doubled"),
+                col("a") / col("b"),
+            ],
+            &DescribeOptions::new(),
+        )?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["statistic", "a", "doubled", "expr_2"]
+        );
+        assert_eq!(
+            cell(&stats, "mean", "doubled")?,
+            2.0 * cell(&stats, "mean", "a")?
+        );
+        assert_eq!(cell(&stats, "count", "expr_2")?, 3.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_exprs_alongside_all_columns() -> Result<()> {
+        let lf = df! { "x" => [1, 2, 3], "y" => ["p", "q", "r"] }?.lazy();
+        let stats = lf.describe_exprs(
+            vec![all().as_expr(), col("x").cum_sum(false).alias("running")],
+            &DescribeOptions::new(),
+        )?;
+        assert_eq!(stats.get_column_names(), ["statistic", "x", "y", "running"]);
+        assert_eq!(cell(&stats, "max", "running")?, 6.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_where_matches_prefiltered_frame() -> Result<()> {
+        let df = df! {
+            "x" => [Some(-2.0), Some(1.5), None, Some(4.0), Some(0.0)],
+            "s" => ["a", "b", "c", "d", "e"],
+        }?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let stats = df.describe_where(col("x").gt(lit(0)), &opts)?;
+        let expected = df
+            .clone()
+            .lazy()
+            .filter(col("x").gt(lit(0)))
+            .collect()?
+            .describe_with(&opts)?;
+        assert!(stats.equals_missing(&expected));
+        assert_eq!(cell(&stats, "count", "x")?, 2.0);
+
+        let err = df
+            .lazy()
+            .describe_where(col("y").gt(lit(0)), &opts)
+            .unwrap_err();
+        assert!(
+            format!("{err:#}").contains("describe_where predicate"),
+            "{err:#}"
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,222 @@
+//! Value formatting for the string describe output
+
+#[cfg(feature = "temporal")]
+use chrono::{DateTime, NaiveTime};
+use polars::prelude::*;
+
+use crate::options::DescribeOptions;
+
+/// The underlying float of a floating point AnyValue
+fn float_value(val: &AnyValue<'_>) -> Option<f64> {
+    match val {
+        AnyValue::Float32(v) => Some(f64::from(*v)),
+        AnyValue::Float64(v) => Some(*v),
+        _ => None,
+    }
+}
+
+/// Format a float statistic (mean, std, percentiles) using the configured
+/// precision and notation
+///
+/// Without any explicit setting the value keeps the Polars rendering, and
+/// non-float values (e.g. temporal means) are always rendered by Polars.
+pub(crate) fn format_float(val: &AnyValue<'_>, opts: &DescribeOptions) -> String {
+    let Some(v) = float_value(val) else {
+        return format!("{val}");
+    };
+    match (opts.precision, opts.scientific) {
+        (Some(precision), true) => format!("{v:.precision$e}"),
+        (None, true) => format!("{v:e}"),
+        (Some(precision), false) => format!("{v:.precision$}"),
+        (None, false) => format!("{val}"),
+    }
+}
+
+/// Format a count as a plain integer, never with a decimal point or in
+/// scientific notation
+///
+/// Counts are aggregated as UInt64, but counts reaching the output through
+/// a float (e.g. merged partial summaries) are rendered the same way as
+/// long as they are whole numbers.
+pub(crate) fn format_count(val: &AnyValue<'_>) -> String {
+    if let Some(v) = float_value(val)
+        && v.fract() == 0.0
+        && v.abs() < 1.8e19
+    {
+        #[allow(clippy::cast_possible_truncation)]
+        return format!("{}", v as i128);
+    }
+    match val.extract::<u64>() {
+        Some(count) if val.is_integer() => count.to_string(),
+        _ => format!("{val}"),
+    }
+}
+
+/// Format a ratio in [0, 1] as a percentage, e.g. `0.5` as `50.0%`, with
+/// the configured precision applied to the percentage
+pub(crate) fn format_percent(val: &AnyValue<'_>, opts: &DescribeOptions) -> String {
+    match float_value(val) {
+        Some(ratio) => format!("{}%", format_float(&AnyValue::Float64(ratio * 100.0), opts)),
+        None => format!("{val}"),
+    }
+}
+
+/// Human-readable byte size with binary units, e.g. `512 B` or `12.4 MiB`
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["KiB", "MiB", "GiB", "TiB", "PiB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// Format a min/max/percentile value in its column's native representation
+///
+/// - strings and categoricals without the surrounding quotes Polars adds
+/// - dates as `YYYY-MM-DD`
+/// - datetimes as ISO-8601 with as many fractional digits as the time unit
+///   carries; time-zone aware values are rendered as the UTC instant (`Z`)
+/// - times as `HH:MM:SS.ffffff`
+/// - durations humanized the way Polars displays them (e.g. `1h 23m`)
+pub(crate) fn format_value(val: &AnyValue<'_>) -> String {
+    match val {
+        #[cfg(feature = "temporal")]
+        AnyValue::Time(ns) => format_time(*ns),
+        #[cfg(feature = "temporal")]
+        AnyValue::Datetime(v, unit, tz) => format_datetime(*v, *unit, tz.is_some()),
+        #[cfg(feature = "temporal")]
+        AnyValue::DatetimeOwned(v, unit, tz) => format_datetime(*v, *unit, tz.is_some()),
+        _ => match val.get_str() {
+            Some(s) => s.to_string(),
+            None => format!("{val}"),
+        },
+    }
+}
+
+/// `HH:MM:SS.ffffff` rendering of nanoseconds since midnight
+#[cfg(feature = "temporal")]
+fn format_time(ns: i64) -> String {
+    let secs = ns.div_euclid(1_000_000_000);
+    let nanos = ns.rem_euclid(1_000_000_000);
+    match u32::try_from(secs)
+        .ok()
+        .zip(u32::try_from(nanos).ok())
+        .and_then(|(secs, nanos)| NaiveTime::from_num_seconds_from_midnight_opt(secs, nanos))
+    {
+        Some(time) => time.format("%H:%M:%S%.6f").to_string(),
+        None => ns.to_string(),
+    }
+}
+
+/// ISO-8601 rendering of a datetime's physical value
+#[cfg(feature = "temporal")]
+fn format_datetime(v: i64, unit: TimeUnit, tz_aware: bool) -> String {
+    let (datetime, pattern) = match unit {
+        TimeUnit::Milliseconds => (DateTime::from_timestamp_millis(v), "%Y-%m-%dT%H:%M:%S%.3f"),
+        TimeUnit::Microseconds => (DateTime::from_timestamp_micros(v), "%Y-%m-%dT%H:%M:%S%.6f"),
+        TimeUnit::Nanoseconds => (
+            Some(DateTime::from_timestamp_nanos(v)),
+            "%Y-%m-%dT%H:%M:%S%.9f",
+        ),
+    };
+    match datetime {
+        Some(datetime) => {
+            let mut formatted = datetime.naive_utc().format(pattern).to_string();
+            if tz_aware {
+                formatted.push('Z');
+            }
+            formatted
+        }
+        // Out of chrono's range: fall back to the raw physical value
+        None => v.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_float_defaults_to_polars_display() {
+        let opts = DescribeOptions::new();
+        assert_eq!(format_float(&AnyValue::Float64(2.5), &opts), "2.5");
+        assert_eq!(format_float(&AnyValue::Int64(3), &opts.precision(2)), "3");
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_format_value_datetime_units() {
+        // 2024-01-02T03:04:05.123456789
+        let ns = 1_704_164_645_123_456_789_i64;
+        let tz = TimeZone::UTC;
+        assert_eq!(
+            format_value(&AnyValue::Datetime(
+                ns / 1_000_000,
+                TimeUnit::Milliseconds,
+                None
+            )),
+            "2024-01-02T03:04:05.123"
+        );
+        assert_eq!(
+            format_value(&AnyValue::Datetime(ns, TimeUnit::Nanoseconds, None)),
+            "2024-01-02T03:04:05.123456789"
+        );
+        assert_eq!(
+            format_value(&AnyValue::Datetime(
+                ns / 1_000,
+                TimeUnit::Microseconds,
+                Some(&tz)
+            )),
+            "2024-01-02T03:04:05.123456Z"
+        );
+    }
+
+    #[cfg(feature = "temporal")]
+    #[test]
+    fn test_format_value_time() {
+        assert_eq!(
+            format_value(&AnyValue::Time(45_296_123_456_789)),
+            "12:34:56.123456"
+        );
+        assert_eq!(format_value(&AnyValue::Time(0)), "00:00:00.000000");
+    }
+
+    #[test]
+    fn test_format_count_large_values() {
+        // Beyond 2^53 a count no longer survives a round trip through f64
+        let beyond_f64 = (1u64 << 53) + 1;
+        assert_eq!(
+            format_count(&AnyValue::UInt64(beyond_f64)),
+            "9007199254740993"
+        );
+        assert_eq!(
+            format_count(&AnyValue::UInt64(u64::MAX)),
+            "18446744073709551615"
+        );
+        assert_eq!(format_count(&AnyValue::UInt32(u32::MAX)), "4294967295");
+        assert_eq!(format_count(&AnyValue::Int64(5_000_000_000)), "5000000000");
+        assert_eq!(format_count(&AnyValue::Float64(5e9)), "5000000000");
+        assert_eq!(format_count(&AnyValue::Float64(1e17)), "100000000000000000");
+        assert_eq!(format_count(&AnyValue::Float64(2.5)), "2.5");
+    }
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(13_002_342), "12.4 MiB");
+    }
+
+    #[test]
+    fn test_format_float_precision_zero() {
+        let opts = DescribeOptions::new().precision(0);
+        assert_eq!(format_float(&AnyValue::Float64(41.9), &opts), "42");
+    }
+}
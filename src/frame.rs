@@ -0,0 +1,152 @@
+//! Frame-level summary
+//!
+//! `frame_summary()` describes the frame as a whole rather than column by
+//! column: its shape, its in-memory size and the rows that are repeated or
+//! entirely null.
+
+use std::fmt;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::duplicates::duplicate_rows_expr;
+use crate::error::DescribeError;
+use crate::format::format_bytes;
+
+/// Result of `Describable::frame_summary`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FrameSummary {
+    /// Number of rows
+    pub n_rows: u64,
+    /// Number of columns
+    pub n_columns: usize,
+    /// In-memory size of the frame in bytes, as estimated by Polars
+    pub estimated_size: u64,
+    /// Rows that repeat an earlier row in every column, as in
+    /// `DuplicatesReport::duplicate_rows`
+    pub duplicate_rows: u64,
+    /// Rows where every column is null
+    pub all_null_rows: u64,
+}
+
+impl fmt::Display for FrameSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} rows x {} columns, {}, {} duplicate rows, {} all-null rows",
+            self.n_rows,
+            self.n_columns,
+            format_bytes(self.estimated_size),
+            self.duplicate_rows,
+            self.all_null_rows
+        )
+    }
+}
+
+/// Summary of `df`, which a LazyFrame is collected into first so the
+/// source is only read once
+pub(crate) fn frame_summary_impl(df: &DataFrame) -> Result<FrameSummary> {
+    if df.width() == 0 {
+        return Err(DescribeError::EmptySchema.into());
+    }
+
+    let all_null = all_horizontal(
+        df.get_column_names()
+            .into_iter()
+            .map(|name| col(name.clone()).is_null())
+            .collect::<Vec<_>>(),
+    )?;
+    let stats = df
+        .clone()
+        .lazy()
+        .select([
+            len().cast(DataType::UInt64).alias("n_rows"),
+            duplicate_rows_expr().alias("duplicate_rows"),
+            all_null.sum().cast(DataType::UInt64).alias("all_null_rows"),
+        ])
+        .collect()?;
+    let value = |name: &str| -> Result<u64> { Ok(stats.column(name)?.u64()?.get(0).unwrap_or(0)) };
+
+    Ok(FrameSummary {
+        n_rows: value("n_rows")?,
+        n_columns: df.width(),
+        estimated_size: df.estimated_size() as u64,
+        duplicate_rows: value("duplicate_rows")?,
+        all_null_rows: value("all_null_rows")?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_frame_summary() -> Result<()> {
+        let df = df! {
+            "id" => [Some(1), Some(2), Some(1), None, Some(3)],
+            "city" => [Some("Oslo"), Some("Rome"), Some("Oslo"), None, None],
+            "score" => [Some(0.5), None, Some(0.5), None, Some(2.0)],
+        }?;
+        let summary = df.frame_summary()?;
+        assert_eq!(
+            summary,
+            FrameSummary {
+                n_rows: 5,
+                n_columns: 3,
+                estimated_size: df.estimated_size() as u64,
+                duplicate_rows: 1,
+                all_null_rows: 1,
+            }
+        );
+        assert_eq!(df.lazy().frame_summary()?, summary);
+        assert_eq!(
+            summary.to_string(),
+            format!(
+                "5 rows x 3 columns, {} B, 1 duplicate rows, 1 all-null rows",
+                summary.estimated_size
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_summary_reads_lazy_source_once() -> Result<()> {
+        let reads = Arc::new(AtomicUsize::new(0));
+        let counter = reads.clone();
+        let schema = Arc::new(Schema::from_iter([Field::new("a".into(), DataType::Int32)]));
+        let lf = df! { "a" => [1, 1, 2] }?.lazy().map(
+            move |df| {
+                counter.fetch_add(1, Ordering::Relaxed);
+                Ok(df)
+            },
+            AllowedOptimizations::empty(),
+            Some(Arc::new(move |_: &Schema| Ok(schema.clone()))),
+            Some("count_reads"),
+        );
+        let summary = lf.frame_summary()?;
+        assert_eq!((summary.n_rows, summary.duplicate_rows), (3, 1));
+        assert_eq!(reads.load(Ordering::Relaxed), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_summary_edge_cases() -> Result<()> {
+        let df = df! { "a" => [1, 2, 3] }?;
+        let empty = df.clone().lazy().limit(0).frame_summary()?;
+        assert_eq!(
+            (empty.n_rows, empty.duplicate_rows, empty.all_null_rows),
+            (0, 0, 0)
+        );
+
+        let err = DataFrame::empty().frame_summary().unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DescribeError>(),
+            Some(DescribeError::EmptySchema)
+        ));
+        Ok(())
+    }
+}
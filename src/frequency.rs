@@ -0,0 +1,311 @@
+//! Frequency profile of text columns
+//!
+//! `describe_string()` lists the most frequent values of every String and
+//! Categorical column as a long frame (`column`, `value`, `count`, `pct`),
+//! which suits text data better than min/max and the numeric metric set.
+//! `value_counts_report()` counts every value of the requested columns of
+//! any dtype, nulls included.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::is_degraded_dtype;
+use crate::error::DescribeError;
+
+/// Value of the row aggregating everything outside the top k
+const OTHER_VALUE: &str = "(other)";
+
+pub(crate) fn describe_string_impl(lazy_frame: &LazyFrame, k: usize) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+
+    let profiles = schema
+        .iter()
+        .filter(|(_, dtype)| dtype.is_string() || dtype.is_categorical())
+        .map(|(col_name, _)| column_profile(lazy_frame, col_name, k))
+        .collect::<Result<Vec<_>>>()?;
+
+    if profiles.is_empty() {
+        return DataFrame::new(vec![
+            Column::new_empty("column".into(), &DataType::String),
+            Column::new_empty("value".into(), &DataType::String),
+            Column::new_empty("count".into(), &DataType::UInt64),
+            Column::new_empty("pct".into(), &DataType::Float64),
+        ])
+        .map_err(Into::into);
+    }
+
+    concat(profiles, UnionArgs::default())?
+        .collect()
+        .map_err(Into::into)
+}
+
+/// Top-k rows plus the "(other)" row for a single column
+///
+/// Values are ordered by descending count, ties broken by ascending value so
+/// the cutoff is deterministic. Nulls are not counted; `pct` is the share of
+/// the non-null values, in percent.
+fn column_profile(lazy_frame: &LazyFrame, col_name: &str, k: usize) -> Result<LazyFrame> {
+    let counts = lazy_frame
+        .clone()
+        .select([col(col_name).cast(DataType::String).alias("value")])
+        .drop_nulls(None)
+        .group_by([col("value")])
+        .agg([len().cast(DataType::UInt64).alias("count")])
+        .sort_by_exprs(
+            [col("count"), col("value")],
+            SortMultipleOptions::default().with_order_descending_multi([true, false]),
+        )
+        .with_column(col("count").sum().alias("total"));
+
+    #[allow(clippy::cast_possible_truncation)]
+    let k = k as IdxSize;
+    let top = counts.clone().limit(k);
+    let other = counts
+        .slice(i64::from(k), IdxSize::MAX)
+        .select([
+            lit(OTHER_VALUE).alias("value"),
+            col("count").sum(),
+            col("total").first(),
+        ])
+        .filter(col("count").gt(lit(0)));
+
+    Ok(concat([top, other], UnionArgs::default())?.select([
+        lit(col_name).alias("column"),
+        col("value"),
+        col("count"),
+        (col("count").cast(DataType::Float64) / col("total").cast(DataType::Float64) * lit(100.0))
+            .alias("pct"),
+    ]))
+}
+
+pub(crate) fn value_counts_report_impl(
+    lazy_frame: &LazyFrame,
+    columns: &[&str],
+    normalize: bool,
+    limit: Option<usize>,
+) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let mut counts = Vec::with_capacity(columns.len());
+    for col_name in columns {
+        let dtype = schema
+            .get(col_name)
+            .ok_or_else(|| DescribeError::ColumnNotFound(col_name.to_string()))?;
+        if is_degraded_dtype(dtype) {
+            return Err(DescribeError::UnsupportedDtype {
+                column: col_name.to_string(),
+                dtype: dtype.clone(),
+                expected: "a column of comparable values",
+            }
+            .into());
+        }
+        counts.push(column_value_counts(lazy_frame, col_name, limit)?);
+    }
+
+    let mut report = if counts.is_empty() {
+        DataFrame::new(vec![
+            Column::new_empty("column".into(), &DataType::String),
+            Column::new_empty("value".into(), &DataType::String),
+            Column::new_empty("count".into(), &DataType::UInt64),
+            Column::new_empty("proportion".into(), &DataType::Float64),
+        ])?
+    } else {
+        concat(counts, UnionArgs::default())?.collect()?
+    };
+    if !normalize {
+        report = report.drop("proportion")?;
+    }
+    Ok(report)
+}
+
+/// Value counts of a single column, with the "(other)" row under a `limit`
+///
+/// Nulls are grouped into a row of their own, sorted after the values of
+/// the same count; `proportion` is the share of all rows.
+fn column_value_counts(
+    lazy_frame: &LazyFrame,
+    col_name: &str,
+    limit: Option<usize>,
+) -> Result<LazyFrame> {
+    let counts = lazy_frame
+        .clone()
+        .select([col(col_name).cast(DataType::String).alias("value")])
+        .group_by([col("value")])
+        .agg([len().cast(DataType::UInt64).alias("count")])
+        .sort_by_exprs(
+            [col("count"), col("value")],
+            SortMultipleOptions::default()
+                .with_order_descending_multi([true, false])
+                .with_nulls_last(true),
+        )
+        .with_column(col("count").sum().alias("total"));
+
+    let counts = match limit {
+        Some(limit) => {
+            let limit = IdxSize::try_from(limit).unwrap_or(IdxSize::MAX);
+            let other = counts
+                .clone()
+                .slice(i64::from(limit), IdxSize::MAX)
+                .select([
+                    lit(OTHER_VALUE).alias("value"),
+                    col("count").sum(),
+                    col("total").first(),
+                ])
+                .filter(col("count").gt(lit(0)));
+            concat([counts.limit(limit), other], UnionArgs::default())?
+        }
+        None => counts,
+    };
+
+    Ok(counts.select([
+        lit(col_name).alias("column"),
+        col("value"),
+        col("count"),
+        (col("count").cast(DataType::Float64) / col("total").cast(DataType::Float64))
+            .alias("proportion"),
+    ]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn rows(df: &DataFrame) -> Result<Vec<(String, String, u64)>> {
+        let column = df.column("column")?.str()?;
+        let value = df.column("value")?.str()?;
+        let count = df.column("count")?.u64()?;
+        Ok((0..df.height())
+            .map(|i| {
+                (
+                    column.get(i).unwrap().to_string(),
+                    value.get(i).unwrap().to_string(),
+                    count.get(i).unwrap(),
+                )
+            })
+            .collect())
+    }
+
+    #[test]
+    fn test_describe_string_top_k_and_other() -> Result<()> {
+        let df = df! {
+            "fruit" => [Some("pear"), Some("apple"), Some("fig"), Some("apple"), Some("kiwi"), Some("fig"), None, Some("apple")],
+            "n" => [1, 2, 3, 4, 5, 6, 7, 8],
+        }?;
+
+        let profile = df.describe_string(2)?;
+        // kiwi and pear (one each) fall into the (other) row
+        assert_eq!(
+            rows(&profile)?,
+            [
+                ("fruit".to_string(), "apple".to_string(), 3),
+                ("fruit".to_string(), "fig".to_string(), 2),
+                ("fruit".to_string(), OTHER_VALUE.to_string(), 2),
+            ]
+        );
+        let pct = profile.column("pct")?.f64()?;
+        assert!((pct.get(0).unwrap() - 300.0 / 7.0).abs() < 1e-9);
+        assert!((pct.sum().unwrap() - 100.0).abs() < 1e-9);
+
+        // Ties at the cutoff are broken by value
+        let profile = df.describe_string(3)?;
+        assert_eq!(rows(&profile)?[2].1, "kiwi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_string_fewer_values_than_k() -> Result<()> {
+        let lf = df! { "code" => ["b", "a", "b"] }?
+            .lazy()
+            .with_column(col("code").cast(DataType::from_categories(Categories::global())));
+
+        let profile = lf.describe_string(10)?;
+        assert_eq!(
+            rows(&profile)?,
+            [
+                ("code".to_string(), "b".to_string(), 2),
+                ("code".to_string(), "a".to_string(), 1),
+            ]
+        );
+
+        let numeric = df! { "n" => [1, 2] }?.describe_string(3)?;
+        assert_eq!(numeric.height(), 0);
+        assert_eq!(
+            numeric.get_column_names(),
+            ["column", "value", "count", "pct"]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_counts_report_proportions_and_nulls() -> Result<()> {
+        let df = df! {
+            "fruit" => [Some("pear"), None, Some("apple"), Some("pear"), None, Some("fig")],
+            "n" => [3, 1, 3, 2, 3, 1],
+        }?;
+
+        let report = df.value_counts_report(&["fruit", "n"], true, None)?;
+        assert_eq!(
+            report.get_column_names_str(),
+            ["column", "value", "count", "proportion"]
+        );
+        let fruit = report
+            .clone()
+            .lazy()
+            .filter(col("column").eq(lit("fruit")))
+            .collect()?;
+        let values: Vec<Option<&str>> = fruit.column("value")?.str()?.into_iter().collect();
+        // The two nulls tie with pear and sort after it
+        assert_eq!(values, [Some("pear"), None, Some("apple"), Some("fig")]);
+        let counts: Vec<Option<u64>> = fruit.column("count")?.u64()?.into_iter().collect();
+        assert_eq!(counts, [Some(2), Some(2), Some(1), Some(1)]);
+
+        for column in ["fruit", "n"] {
+            let proportions = report
+                .clone()
+                .lazy()
+                .filter(col("column").eq(lit(column)))
+                .select([col("proportion").sum()])
+                .collect()?;
+            let total = proportions.column("proportion")?.f64()?.get(0).unwrap();
+            assert!((total - 1.0).abs() < 1e-12, "{column}: {total}");
+        }
+
+        let plain = df.value_counts_report(&["n"], false, None)?;
+        assert_eq!(plain.get_column_names_str(), ["column", "value", "count"]);
+        assert_eq!(plain.column("value")?.str()?.get(0), Some("3"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_value_counts_report_limit_and_remainder() -> Result<()> {
+        let df = df! {
+            "code" => ["a", "b", "a", "c", "d", "a", "b", "e"],
+        }?;
+        let report = df.value_counts_report(&["code"], true, Some(2))?;
+        assert_eq!(
+            rows(&report)?,
+            [
+                ("code".to_string(), "a".to_string(), 3),
+                ("code".to_string(), "b".to_string(), 2),
+                ("code".to_string(), OTHER_VALUE.to_string(), 3),
+            ]
+        );
+        let proportion = report.column("proportion")?.f64()?;
+        assert_eq!(proportion.get(2), Some(3.0 / 8.0));
+
+        // No remainder row when the limit covers every value
+        let report = df.value_counts_report(&["code"], false, Some(5))?;
+        assert_eq!(report.height(), 5);
+
+        let err = df
+            .value_counts_report(&["missing"], false, None)
+            .unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::ColumnNotFound("missing".to_string()))
+        );
+        Ok(())
+    }
+}
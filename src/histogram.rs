@@ -0,0 +1,271 @@
+//! Equal-width histograms for numeric columns
+//!
+//! Bin edges are derived from the column's min and max, so histograms take a
+//! second lazy pass after the main aggregation has produced those values.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::error::DescribeError;
+
+/// Characters used to render a histogram as a sparkline, lowest to highest
+const SPARK_TICKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Equal-width histogram of a numeric column
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Histogram {
+    /// Bin edges, one more than the number of bins
+    pub edges: Vec<f64>,
+    /// Number of values falling into each bin
+    pub counts: Vec<u64>,
+}
+
+impl Histogram {
+    /// Render the bin counts as a unicode sparkline, one character per bin
+    pub fn sparkline(&self) -> String {
+        let max = self.counts.iter().copied().max().unwrap_or(0);
+        self.counts
+            .iter()
+            .map(|&count| {
+                // An all-empty histogram renders as a flat line
+                let level = (count * (SPARK_TICKS.len() as u64 - 1))
+                    .checked_div(max)
+                    .unwrap_or(0);
+                #[allow(clippy::cast_possible_truncation)]
+                SPARK_TICKS[level as usize]
+            })
+            .collect()
+    }
+}
+
+/// Bin edges for `n_bins` equal-width bins spanning [min, max]
+///
+/// A constant column (min == max) gets a single bin
+pub(crate) fn bin_edges(min: f64, max: f64, n_bins: usize) -> Vec<f64> {
+    if min == max {
+        return vec![min, max];
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let width = (max - min) / n_bins as f64;
+    let mut edges: Vec<f64> = (0..n_bins)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let offset = i as f64 * width;
+            min + offset
+        })
+        .collect();
+    // Pin the last edge to max so rounding can't leave the maximum out
+    edges.push(max);
+    edges
+}
+
+/// Count expressions, one per bin, for the given column and edges
+///
+/// Bins are half-open except the last, which also includes its upper edge.
+/// Nulls and NaNs fail every comparison and therefore land in no bin.
+fn bin_count_exprs(col_name: &str, edges: &[f64], key: usize) -> Vec<Expr> {
+    let col = col(col_name).cast(DataType::Float64);
+    let n_bins = edges.len() - 1;
+
+    (0..n_bins)
+        .map(|i| {
+            let lower = col.clone().gt_eq(lit(edges[i]));
+            let upper = if i == n_bins - 1 {
+                col.clone().lt_eq(lit(edges[i + 1]))
+            } else {
+                col.clone().lt(lit(edges[i + 1]))
+            };
+            lower.and(upper).sum().alias(format!("{key}:{i}"))
+        })
+        .collect()
+}
+
+/// Compute histograms for `(column, min, max)` ranges in a single lazy pass
+pub(crate) fn compute_histograms(
+    lazy_frame: &LazyFrame,
+    ranges: &[(String, f64, f64)],
+    n_bins: usize,
+) -> Result<Vec<Histogram>> {
+    if n_bins == 0 {
+        return Err(DescribeError::InvalidArgument(
+            "histogram bin count must be at least 1".to_string(),
+        )
+        .into());
+    }
+    if ranges.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let all_edges: Vec<Vec<f64>> = ranges
+        .iter()
+        .map(|(_, min, max)| bin_edges(*min, *max, n_bins))
+        .collect();
+
+    let exprs: Vec<Expr> = ranges
+        .iter()
+        .zip(&all_edges)
+        .enumerate()
+        .flat_map(|(key, ((name, _, _), edges))| bin_count_exprs(name, edges, key))
+        .collect();
+
+    let df_counts = lazy_frame.clone().select(exprs).collect()?;
+
+    let mut histograms = Vec::with_capacity(ranges.len());
+    for (key, edges) in all_edges.into_iter().enumerate() {
+        let mut counts = Vec::with_capacity(edges.len() - 1);
+        for i in 0..edges.len() - 1 {
+            let val = df_counts.column(&format!("{key}:{i}"))?.get(0)?;
+            counts.push(val.extract::<u64>().unwrap_or(0));
+        }
+        histograms.push(Histogram { edges, counts });
+    }
+
+    Ok(histograms)
+}
+
+/// Long-format histograms of every numeric column (`describe_histogram`)
+///
+/// A first pass finds each column's range and its null/NaN count, a second
+/// one counts the bins. Every column ends with an overflow row (null
+/// `bin_start`/`bin_end`) counting the nulls and NaNs left out of the bins.
+pub(crate) fn describe_histogram_impl(lazy_frame: &LazyFrame, bins: usize) -> Result<DataFrame> {
+    if bins == 0 {
+        return Err(DescribeError::InvalidArgument(
+            "histogram bin count must be at least 1".to_string(),
+        )
+        .into());
+    }
+    let schema = lazy_frame.clone().collect_schema()?;
+    let names: Vec<(&PlSmallStr, bool)> = schema
+        .iter()
+        .filter(|(_, dtype)| dtype.is_numeric())
+        .map(|(name, dtype)| (name, dtype.is_float()))
+        .collect();
+
+    let range_exprs: Vec<Expr> = names
+        .iter()
+        .flat_map(|(name, is_float)| {
+            let values = col((*name).clone()).cast(DataType::Float64);
+            let (values, missing) = if *is_float {
+                let nan = values.clone().is_nan();
+                (
+                    values.clone().filter(nan.clone().not()),
+                    values.is_null().or(nan).sum(),
+                )
+            } else {
+                (values.clone(), values.null_count())
+            };
+            [
+                values.clone().min().alias(format!("min:{name}")),
+                values.max().alias(format!("max:{name}")),
+                missing
+                    .cast(DataType::UInt64)
+                    .alias(format!("missing:{name}")),
+            ]
+        })
+        .collect();
+    let ranges_df = if range_exprs.is_empty() {
+        DataFrame::empty()
+    } else {
+        lazy_frame.clone().select(range_exprs).collect()?
+    };
+
+    let value_of = |stat: &str, name: &str| -> Result<AnyValue<'static>> {
+        Ok(ranges_df
+            .column(&format!("{stat}:{name}"))?
+            .get(0)?
+            .into_static())
+    };
+    let mut ranges = Vec::new();
+    for (name, _) in &names {
+        let min = value_of("min", name)?.extract::<f64>();
+        let max = value_of("max", name)?.extract::<f64>();
+        if let (Some(min), Some(max)) = (min, max) {
+            ranges.push((name.to_string(), min, max));
+        }
+    }
+    let histograms = compute_histograms(lazy_frame, &ranges, bins)?;
+
+    let mut column = Vec::new();
+    let mut bin_start = Vec::new();
+    let mut bin_end = Vec::new();
+    let mut count = Vec::new();
+    for (name, _) in &names {
+        let histogram = ranges
+            .iter()
+            .position(|(range_name, _, _)| range_name == name.as_str())
+            .map(|i| &histograms[i]);
+        if let Some(histogram) = histogram {
+            for (i, bin_count) in histogram.counts.iter().enumerate() {
+                column.push(name.to_string());
+                bin_start.push(Some(histogram.edges[i]));
+                bin_end.push(Some(histogram.edges[i + 1]));
+                count.push(*bin_count);
+            }
+        }
+        column.push(name.to_string());
+        bin_start.push(None);
+        bin_end.push(None);
+        count.push(value_of("missing", name)?.extract::<u64>().unwrap_or(0));
+    }
+
+    DataFrame::new(vec![
+        Column::new("column".into(), column),
+        Column::new("bin_start".into(), bin_start),
+        Column::new("bin_end".into(), bin_end),
+        Column::new("count".into(), count),
+    ])
+    .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_bin_edges_constant_column() {
+        assert_eq!(bin_edges(3.0, 3.0, 10), vec![3.0, 3.0]);
+    }
+
+    #[test]
+    fn test_describe_histogram_counts() -> Result<()> {
+        let df = df! {
+            "x" => [Some(0.0), Some(1.0), None, Some(2.5), Some(f64::NAN), Some(10.0)],
+            "c" => [Some(3), Some(3), Some(3), Some(3), None, Some(3)],
+            "s" => ["a", "b", "c", "d", "e", "f"],
+        }?;
+        let hist = df.describe_histogram(4)?;
+
+        let x = hist
+            .clone()
+            .lazy()
+            .filter(col("column").eq(lit("x")))
+            .collect()?;
+        let counts: Vec<Option<u64>> = x.column("count")?.u64()?.into_iter().collect();
+        // [0, 2.5), [2.5, 5), [5, 7.5), [7.5, 10], then the null and the NaN
+        assert_eq!(counts, [2, 1, 0, 1, 2].map(Some));
+        assert_eq!(x.column("bin_start")?.f64()?.get(0), Some(0.0));
+        assert_eq!(x.column("bin_end")?.f64()?.get(3), Some(10.0));
+        assert_eq!(x.column("bin_start")?.f64()?.get(4), None);
+
+        // A constant column gets a single bin
+        let c = hist.lazy().filter(col("column").eq(lit("c"))).collect()?;
+        let counts: Vec<Option<u64>> = c.column("count")?.u64()?.into_iter().collect();
+        assert_eq!(counts, [Some(5), Some(1)]);
+
+        let err = df.describe_histogram(0).unwrap_err();
+        assert!(err.to_string().contains("at least 1"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_sparkline() {
+        let hist = Histogram {
+            edges: vec![0.0, 1.0, 2.0, 3.0],
+            counts: vec![0, 7, 14],
+        };
+        assert_eq!(hist.sparkline(), "▁▄█");
+    }
+}
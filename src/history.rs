@@ -0,0 +1,369 @@
+//! Describe snapshots kept over time in a Parquet file
+//!
+//! `DescribeHistory` appends the typed layout of
+//! `DescribeReport::to_typed_dataframe()` to a Parquet file, one block of
+//! rows per snapshot behind a `snapshot` timestamp column. Snapshots are
+//! concatenated diagonally, so columns or percentiles that only appear in
+//! later snapshots are null in the earlier ones.
+
+use std::fs::File;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use chrono::DateTime;
+use polars::prelude::*;
+
+use crate::describe::Describable;
+use crate::error::DescribeError;
+use crate::io::create_file;
+use crate::options::DescribeOptions;
+
+/// Name of the leading column holding each row's snapshot timestamp
+const SNAPSHOT_COLUMN: &str = "snapshot";
+
+/// Dtype of the snapshot column: UTC timestamps without a time zone
+const SNAPSHOT_DTYPE: DataType = DataType::Datetime(TimeUnit::Microseconds, None);
+
+/// Typed describe snapshots stored in a Parquet file
+///
+/// # Example
+/// ```rust,no_run
+/// use polars::prelude::*;
+/// use describe_df::DescribeHistory;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let history = DescribeHistory::new("stats/history.parquet");
+/// let df = df! { "price" => [1.0, 2.0, 3.0] }?;
+/// history.push(&df, "2024-06-01T00:00:00Z")?;
+///
+/// let means = history.series("price", "mean")?;
+/// println!("{means}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct DescribeHistory {
+    path: PathBuf,
+    opts: DescribeOptions,
+}
+
+impl DescribeHistory {
+    /// History stored at `path`; the file is created by the first `push`
+    pub fn new(path: impl AsRef<Path>) -> Self {
+        Self {
+            path: path.as_ref().to_path_buf(),
+            opts: DescribeOptions::new(),
+        }
+    }
+
+    /// Options used to describe the pushed frames
+    pub fn options(mut self, opts: DescribeOptions) -> Self {
+        self.opts = opts;
+        self
+    }
+
+    /// Describe `frame` and append it as the snapshot taken at `timestamp`
+    ///
+    /// `timestamp` is an RFC 3339 timestamp (e.g. `2024-06-01T00:00:00Z`),
+    /// stored in UTC. The file is rewritten with the snapshots ordered by
+    /// time, through a sibling temporary file renamed over it, so a failed
+    /// write leaves the previous history intact. Fails when the history
+    /// already has a snapshot at `timestamp`.
+    pub fn push(&self, frame: &impl Describable, timestamp: &str) -> Result<()> {
+        let micros = DateTime::parse_from_rfc3339(timestamp)
+            .with_context(|| format!("invalid snapshot timestamp '{timestamp}'"))?
+            .timestamp_micros();
+
+        let mut snapshot = frame.describe_report(&self.opts)?.to_typed_dataframe()?;
+        let stamps = Column::new(SNAPSHOT_COLUMN.into(), vec![micros; snapshot.height()])
+            .cast(&SNAPSHOT_DTYPE)?;
+        snapshot.insert_column(0, stamps)?;
+
+        let mut frames = vec![snapshot.lazy()];
+        if self.path.exists() {
+            let history = self.load()?;
+            if snapshot_times(&history)?.contains(&micros) {
+                return Err(DescribeError::InvalidArgument(format!(
+                    "{} already has a snapshot at {timestamp}",
+                    self.path.display()
+                ))
+                .into());
+            }
+            frames.insert(0, history.lazy());
+        }
+        let mut history = concat_lf_diagonal(frames, UnionArgs::default())?
+            .sort(
+                [SNAPSHOT_COLUMN],
+                SortMultipleOptions::default().with_maintain_order(true),
+            )
+            .collect()?;
+
+        let temp_path = self.temp_path();
+        let written = create_file(&temp_path).and_then(|file| {
+            ParquetWriter::new(file)
+                .finish(&mut history)
+                .with_context(|| format!("failed to write Parquet to {}", temp_path.display()))
+        });
+        if let Err(err) = written {
+            let _ = std::fs::remove_file(&temp_path);
+            return Err(err);
+        }
+        std::fs::rename(&temp_path, &self.path).with_context(|| {
+            format!(
+                "failed to replace {} with {}",
+                self.path.display(),
+                temp_path.display()
+            )
+        })
+    }
+
+    /// Sibling of the history file that `push` writes before renaming it
+    /// over the history
+    fn temp_path(&self) -> PathBuf {
+        let mut name = self.path.file_name().unwrap_or_default().to_os_string();
+        name.push(".tmp");
+        self.path.with_file_name(name)
+    }
+
+    /// Every snapshot, oldest first, in the typed layout led by `snapshot`
+    pub fn load(&self) -> Result<DataFrame> {
+        let file = File::open(&self.path)
+            .with_context(|| format!("failed to open {}", self.path.display()))?;
+        ParquetReader::new(file)
+            .finish()
+            .with_context(|| format!("failed to read describe history {}", self.path.display()))
+    }
+
+    /// Changes of the numeric statistics between consecutive snapshots
+    /// among the `n` most recent ones
+    ///
+    /// Columns: `from` and `to` (the snapshot timestamps), `column`, then
+    /// one Float64 column per numeric statistic (count, null_count, mean,
+    /// std, percentiles) holding `to − from`. Pairs are ordered by time;
+    /// within a pair, columns follow the newer snapshot, then the columns
+    /// it dropped. Deltas of a column missing from either snapshot are
+    /// null. `n` must be at least 2 and at most the number of snapshots.
+    pub fn compare_last_n(&self, n: usize) -> Result<DataFrame> {
+        let history = self.load()?;
+        let times = snapshot_times(&history)?;
+        if n < 2 || n > times.len() {
+            return Err(DescribeError::InvalidArgument(format!(
+                "cannot compare the last {n} snapshots of a history with {}",
+                times.len()
+            ))
+            .into());
+        }
+        let statistics: Vec<PlSmallStr> = history
+            .get_columns()
+            .iter()
+            .filter(|column| column.name() != SNAPSHOT_COLUMN && column.dtype().is_numeric())
+            .map(|column| column.name().clone())
+            .collect();
+
+        let recent = &times[times.len() - n..];
+        let mut deltas = Vec::with_capacity(n - 1);
+        for pair in recent.windows(2) {
+            let older = snapshot_rows(&history, pair[0])?;
+            let newer = snapshot_rows(&history, pair[1])?;
+            deltas.push(snapshot_delta(&older, &newer, &statistics)?.lazy());
+        }
+        Ok(concat(deltas, UnionArgs::default())?.collect()?)
+    }
+
+    /// Time series of one statistic of one column
+    ///
+    /// Columns: `snapshot` and `value`, with the statistic's dtype in the
+    /// typed layout (e.g. Float64 for `mean` or `25%`, String for `min`).
+    /// Snapshots without the column are left out. Fails when the column or
+    /// the statistic appears in no snapshot.
+    pub fn series(&self, column: &str, statistic: &str) -> Result<DataFrame> {
+        let history = self.load()?;
+        if statistic == SNAPSHOT_COLUMN
+            || statistic == "column"
+            || !history.schema().contains(statistic)
+        {
+            return Err(DescribeError::InvalidArgument(format!(
+                "statistic '{statistic}' not found in {}",
+                self.path.display()
+            ))
+            .into());
+        }
+        let series = history
+            .lazy()
+            .filter(col("column").eq(lit(column)))
+            .select([col(SNAPSHOT_COLUMN), col(statistic).alias("value")])
+            .collect()?;
+        if series.height() == 0 {
+            return Err(DescribeError::ColumnNotFound(column.to_string()))
+                .with_context(|| format!("no snapshot of {} has the column", self.path.display()));
+        }
+        Ok(series)
+    }
+}
+
+/// Distinct snapshot timestamps of a history, oldest first
+fn snapshot_times(history: &DataFrame) -> Result<Vec<i64>> {
+    let times = history
+        .column(SNAPSHOT_COLUMN)?
+        .cast(&DataType::Int64)?
+        .i64()?
+        .unique()?
+        .sort(false);
+    Ok(times.into_no_null_iter().collect())
+}
+
+/// The rows of the snapshot taken at `micros`
+fn snapshot_rows(history: &DataFrame, micros: i64) -> Result<DataFrame> {
+    Ok(history
+        .clone()
+        .lazy()
+        .filter(col(SNAPSHOT_COLUMN).eq(lit(micros).cast(SNAPSHOT_DTYPE)))
+        .collect()?)
+}
+
+/// `newer − older` for every statistic, one row per column of either
+/// snapshot
+fn snapshot_delta(
+    older: &DataFrame,
+    newer: &DataFrame,
+    statistics: &[PlSmallStr],
+) -> Result<DataFrame> {
+    let names = |frame: &DataFrame| -> Result<Vec<String>> {
+        Ok(frame
+            .column("column")?
+            .str()?
+            .into_no_null_iter()
+            .map(str::to_string)
+            .collect())
+    };
+    let older_names = names(older)?;
+    let newer_names = names(newer)?;
+    let mut columns = newer_names.clone();
+    columns.extend(
+        older_names
+            .iter()
+            .filter(|name| !newer_names.contains(name))
+            .cloned(),
+    );
+
+    let value = |frame: &DataFrame, row: Option<usize>, statistic: &str| -> Result<Option<f64>> {
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        Ok(frame
+            .column(statistic)?
+            .cast(&DataType::Float64)?
+            .f64()?
+            .get(row))
+    };
+
+    let stamp = |frame: &DataFrame| frame.column(SNAPSHOT_COLUMN).map(|c| c.head(Some(1)));
+    let mut result = vec![
+        stamp(older)?
+            .new_from_index(0, columns.len())
+            .with_name("from".into()),
+        stamp(newer)?
+            .new_from_index(0, columns.len())
+            .with_name("to".into()),
+        Column::new("column".into(), &columns),
+    ];
+    for statistic in statistics {
+        let mut deltas = Vec::with_capacity(columns.len());
+        for name in &columns {
+            let old_row = older_names.iter().position(|n| n == name);
+            let new_row = newer_names.iter().position(|n| n == name);
+            let delta = match (
+                value(older, old_row, statistic)?,
+                value(newer, new_row, statistic)?,
+            ) {
+                (Some(a), Some(b)) => Some(b - a),
+                _ => None,
+            };
+            deltas.push(delta);
+        }
+        result.push(Column::new(statistic.clone(), deltas));
+    }
+    Ok(DataFrame::new(result)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("describe_df_{}_{name}", std::process::id()))
+    }
+
+    #[test]
+    fn test_history_trends_across_three_snapshots() -> Result<()> {
+        let path = temp_path("history.parquet");
+        let _ = std::fs::remove_file(&path);
+        let history =
+            DescribeHistory::new(&path).options(DescribeOptions::new().percentiles(vec![0.5]));
+
+        history.push(&df! { "price" => [1.0, 2.0, 3.0] }?, "2024-06-01T00:00:00Z")?;
+        // Pushed out of order: snapshots are kept sorted by time
+        history.push(
+            &df! { "price" => [2.0, 4.0, 6.0], "qty" => [1, 2, 3] }?,
+            "2024-06-03T00:00:00Z",
+        )?;
+        history.push(
+            &df! { "price" => [2.0, 3.0], "qty" => [5, 5] }?,
+            "2024-06-02T00:00:00+02:00",
+        )?;
+        assert!(
+            history
+                .push(&df! { "price" => [0.0] }?, "2024-06-01T00:00:00Z")
+                .is_err()
+        );
+
+        let means = history.series("price", "mean")?;
+        assert_eq!(means.column("snapshot")?.dtype(), &SNAPSHOT_DTYPE);
+        let values: Vec<Option<f64>> = means.column("value")?.f64()?.into_iter().collect();
+        assert_eq!(values, [Some(2.0), Some(2.5), Some(4.0)]);
+        // qty only appears from the second snapshot on
+        assert_eq!(history.series("qty", "count")?.height(), 2);
+        assert!(history.series("price", "kurtosis").is_err());
+        assert!(history.series("city", "mean").is_err());
+
+        let delta = history.compare_last_n(2)?;
+        assert_eq!(delta.height(), 2);
+        let cell = |column: &str, row: usize| delta.column(column).unwrap().f64().unwrap().get(row);
+        assert_eq!(cell("mean", 0), Some(1.5));
+        assert_eq!(cell("count", 0), Some(1.0));
+        assert_eq!(cell("50%", 1), Some(-3.0));
+        assert_eq!(
+            delta.column("from")?.get(0)?,
+            means.column("snapshot")?.get(1)?
+        );
+
+        // The first pair compares qty against a snapshot without it
+        let all = history.compare_last_n(3)?;
+        assert_eq!(all.height(), 4);
+        assert_eq!(all.column("column")?.str()?.get(1), Some("qty"));
+        assert_eq!(all.column("mean")?.f64()?.get(1), None);
+        assert!(history.compare_last_n(4).is_err());
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_history_push_failure_keeps_previous_file() -> Result<()> {
+        let path = temp_path("kept.parquet");
+        let _ = std::fs::remove_file(&path);
+        let history = DescribeHistory::new(&path);
+        history.push(&df! { "price" => [1.0, 2.0] }?, "2024-06-01T00:00:00Z")?;
+        assert!(!history.temp_path().exists());
+
+        // A directory in the way of the temporary file makes the write fail
+        std::fs::create_dir(history.temp_path())?;
+        let pushed = history.push(&df! { "price" => [3.0] }?, "2024-06-02T00:00:00Z");
+        std::fs::remove_dir(history.temp_path())?;
+        assert!(pushed.is_err());
+        assert_eq!(history.series("price", "count")?.height(), 1);
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+}
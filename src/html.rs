@@ -0,0 +1,161 @@
+//! HTML table rendering of describe output for notebooks and dashboards
+//!
+//! The table carries CSS class hooks instead of inline styles: the table is
+//! `describe-table`, null cells are `stat-null`, and with a null threshold
+//! configured, the header and null_count cell of columns above it are
+//! `stat-high-nulls`.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_native_impl;
+use crate::options::DescribeOptions;
+
+const TABLE_CLASS: &str = "describe-table";
+const NULL_CLASS: &str = "stat-null";
+const HIGH_NULLS_CLASS: &str = "stat-high-nulls";
+
+pub(crate) fn describe_html_impl(lazy_frame: &LazyFrame, opts: &DescribeOptions) -> Result<String> {
+    let stats = describe_native_impl(lazy_frame, opts)?;
+    render_html(&stats, opts)
+}
+
+fn render_html(stats: &DataFrame, opts: &DescribeOptions) -> Result<String> {
+    let labels = stats.column("statistic")?.str()?;
+    let row_of = |label: &str| labels.iter().position(|l| l == Some(label));
+    let (count_row, null_row) = (row_of("count"), row_of("null_count"));
+
+    let columns: Vec<&Column> = stats.get_columns().iter().skip(1).collect();
+    let mut high_nulls = Vec::with_capacity(columns.len());
+    for column in &columns {
+        let values = column.str()?;
+        let number = |row: Option<usize>| {
+            row.and_then(|r| values.get(r))
+                .and_then(|v| v.parse::<u64>().ok())
+                .unwrap_or(0)
+        };
+        high_nulls.push(exceeds_null_threshold(
+            number(count_row),
+            number(null_row),
+            opts,
+        ));
+    }
+
+    let mut html = format!("<table class=\"{TABLE_CLASS}\">\n<thead>\n<tr><th>statistic</th>");
+    for (column, high) in columns.iter().zip(&high_nulls) {
+        html.push_str(&header_cell(column.name(), *high));
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for row in 0..stats.height() {
+        html.push_str("<tr><th>");
+        html.push_str(&escape(labels.get(row).unwrap_or("null")));
+        html.push_str("</th>");
+        for (column, high) in columns.iter().zip(&high_nulls) {
+            let value = column.str()?.get(row).unwrap_or("null");
+            let classes = [
+                (value == "null").then_some(NULL_CLASS),
+                (*high && Some(row) == null_row).then_some(HIGH_NULLS_CLASS),
+            ];
+            let classes: Vec<&str> = classes.into_iter().flatten().collect();
+            if classes.is_empty() {
+                html.push_str(&format!("<td>{}</td>", escape(value)));
+            } else {
+                html.push_str(&format!(
+                    "<td class=\"{}\">{}</td>",
+                    classes.join(" "),
+                    escape(value)
+                ));
+            }
+        }
+        html.push_str("</tr>\n");
+    }
+    html.push_str("</tbody>\n</table>\n");
+    Ok(html)
+}
+
+/// A column header, carrying the high-nulls class when `high` is set
+fn header_cell(name: &str, high: bool) -> String {
+    if high {
+        format!("<th class=\"{HIGH_NULLS_CLASS}\">{}</th>", escape(name))
+    } else {
+        format!("<th>{}</th>", escape(name))
+    }
+}
+
+/// Whether a column's nulls exceed either configured threshold
+pub(crate) fn exceeds_null_threshold(count: u64, null_count: u64, opts: &DescribeOptions) -> bool {
+    let total = count + null_count;
+    #[allow(clippy::cast_precision_loss)]
+    let null_pct = if total == 0 {
+        0.0
+    } else {
+        null_count as f64 / total as f64 * 100.0
+    };
+    opts.highlight_null_count
+        .is_some_and(|max| null_count > max)
+        || opts.highlight_null_pct.is_some_and(|max| null_pct > max)
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Compat;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_html_escapes_values() -> Result<()> {
+        let df = df! { "tag" => ["<b>", "x & y"], "n" => [1, 2] }?;
+        let html = df.describe_html(&DescribeOptions::new())?;
+
+        assert!(html.starts_with("<table class=\"describe-table\">\n<thead>\n<tr><th>statistic</th><th>tag</th><th>n</th></tr>"));
+        assert!(html.contains("<tr><th>min</th><td>&lt;b&gt;</td><td>1</td></tr>"));
+        assert!(
+            html.contains("<tr><th>mean</th><td class=\"stat-null\">null</td><td>1.5</td></tr>")
+        );
+        assert!(!html.contains("stat-high-nulls"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_html_highlights_null_thresholds() -> Result<()> {
+        let df = df! {
+            "sparse" => [Some(1), None, None, None],
+            "dense" => [Some(1), Some(2), Some(3), None],
+        }?;
+
+        let html = df.describe_html(&DescribeOptions::new().highlight_null_pct(50.0))?;
+        assert!(html.contains("<th class=\"stat-high-nulls\">sparse</th><th>dense</th>"));
+        assert!(html.contains(
+            "<tr><th>null_count</th><td class=\"stat-high-nulls\">3</td><td>1</td></tr>"
+        ));
+
+        let html = df.describe_html(&DescribeOptions::new().highlight_null_count(0))?;
+        assert!(
+            html.contains(
+                "<td class=\"stat-high-nulls\">3</td><td class=\"stat-high-nulls\">1</td>"
+            )
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_html_ignores_compat() -> Result<()> {
+        let df = df! { "x" => [1.5, 2.5], "s" => ["a", "b"], "b" => [true, false] }?;
+        let native = df.describe_html(&DescribeOptions::new())?;
+        for compat in [Compat::PyPolars, Compat::Pandas] {
+            let html = df.describe_html(&DescribeOptions::new().compat(compat))?;
+            assert_eq!(html, native, "{compat:?}");
+        }
+        Ok(())
+    }
+}
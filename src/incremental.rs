@@ -0,0 +1,448 @@
+//! Incremental describe that reuses a previous report for unchanged columns
+//!
+//! Wide, slowly changing tables rarely need every column recomputed. Columns
+//! the caller marks as changed are described again; everything else is
+//! spliced in from the previous report and flagged as `carried_forward`.
+//! For Parquet files the changed columns are found from the row-group
+//! statistics in the file footer, without reading the data.
+
+use std::collections::HashMap;
+#[cfg(feature = "parquet")]
+use std::fs::File;
+#[cfg(feature = "parquet")]
+use std::path::Path;
+
+#[cfg(feature = "parquet")]
+use anyhow::Context;
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_report_impl;
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+use crate::report::{ColumnSummary, DescribeReport};
+
+/// Describe only the changed columns of `lazy_frame`, carrying the rest over
+/// from `previous`
+///
+/// Schema drift is handled explicitly:
+/// - columns missing from `previous` are always computed
+/// - columns whose dtype differs from `previous` are always recomputed
+/// - columns present in `previous` but no longer in the frame are dropped
+///
+/// With `DescribeOptions::error_on_schema_change` (part of `strict`) any of
+/// these changes is an error instead.
+///
+/// Carried-forward summaries keep whatever percentiles and histograms they
+/// were computed with, so `opts` should match the options of the previous run.
+/// A `DescribeOptions::weight_col` is read by the recomputed columns but not
+/// described itself; listing it as changed recomputes every column.
+///
+/// # Errors
+/// Returns an error if a name in `changed_columns` is not a column of the
+/// frame, or with `DescribeOptions::unnest_structs` or
+/// `DescribeOptions::columns`, whose output columns don't map one to one
+/// onto the columns of the frame.
+pub fn describe_incremental(
+    lazy_frame: &LazyFrame,
+    previous: &DescribeReport,
+    changed_columns: &[&str],
+    opts: &DescribeOptions,
+) -> Result<DescribeReport> {
+    if opts.unnest_structs || opts.columns.is_some() {
+        return Err(DescribeError::InvalidArgument(
+            "describe_incremental doesn't support unnest_structs or columns".to_string(),
+        )
+        .into());
+    }
+    let mut schema = (*lazy_frame.clone().collect_schema()?).clone();
+
+    if let Some(missing) = changed_columns.iter().find(|c| !schema.contains(c)) {
+        return Err(DescribeError::ColumnNotFound(missing.to_string()).into());
+    }
+    if let Some(weight_col) = &opts.weight_col {
+        schema.shift_remove(weight_col.as_str());
+    }
+
+    if opts.error_on_schema_change {
+        check_schema_unchanged(&schema, previous)?;
+    }
+
+    // The weights enter every weighted statistic
+    let weights_changed = opts
+        .weight_col
+        .as_deref()
+        .is_some_and(|weight_col| changed_columns.contains(&weight_col));
+    let needs_recompute = |name: &str, dtype: &DataType| -> bool {
+        weights_changed
+            || changed_columns.contains(&name)
+            || previous
+                .column(name)
+                .is_none_or(|prev| prev.dtype != dtype.to_string())
+    };
+
+    let mut recompute: Vec<Expr> = schema
+        .iter()
+        .filter(|(name, dtype)| needs_recompute(name, dtype))
+        .map(|(name, _)| col(name.clone()))
+        .collect();
+
+    // Every column of the schema ends up in the report, so the
+    // `max_columns` limit doesn't apply
+    let mut fresh: HashMap<String, ColumnSummary> = if recompute.is_empty() {
+        HashMap::new()
+    } else {
+        if let Some(weight_col) = &opts.weight_col {
+            recompute.push(col(weight_col.as_str()));
+        }
+        let opts = DescribeOptions {
+            column_limit: None,
+            ..opts.clone()
+        };
+        describe_report_impl(&lazy_frame.clone().select(recompute), &opts)?
+            .columns
+            .into_iter()
+            .map(|summary| (summary.name.clone(), summary))
+            .collect()
+    };
+
+    // Splice fresh and carried-forward summaries back into schema order;
+    // a recomputed column left out by `skip_errors` stays out
+    let mut columns = Vec::with_capacity(schema.len());
+    for (name, dtype) in schema.iter() {
+        if needs_recompute(name, dtype) {
+            columns.extend(fresh.remove(name.as_str()));
+        } else if let Some(prev) = previous.column(name) {
+            let mut summary = prev.clone();
+            summary.carried_forward = true;
+            columns.push(summary);
+        }
+    }
+
+    Ok(DescribeReport {
+        columns,
+        skipped_columns: 0,
+    })
+}
+
+/// `describe_incremental` on a Parquet file, detecting the changed columns
+/// itself
+///
+/// Every column gets a digest of the statistics of its column chunks in
+/// each row group (value and null counts, min/max and sizes), read from the
+/// file footer and kept in `ColumnSummary::source_digest`. Columns whose
+/// digest differs from the one in `previous`, or without one (a column
+/// chunk written without statistics), are recomputed. Pass
+/// `DescribeReport::default()` as `previous` for the first run.
+///
+/// # Errors
+/// Returns an error if the file can't be read, and in the cases of
+/// `describe_incremental`.
+#[cfg(feature = "parquet")]
+pub fn describe_incremental_parquet(
+    path: impl AsRef<Path>,
+    previous: &DescribeReport,
+    opts: &DescribeOptions,
+) -> Result<DescribeReport> {
+    let path = path.as_ref();
+    let digests = parquet_column_digests(path)
+        .with_context(|| format!("failed to read the Parquet footer of {}", path.display()))?;
+    let changed: Vec<&str> = digests
+        .iter()
+        .filter(|(name, digest)| {
+            digest.is_none()
+                || previous
+                    .column(name)
+                    .is_none_or(|prev| prev.source_digest != **digest)
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+
+    let lazy_frame =
+        LazyFrame::scan_parquet(PlPath::Local(Arc::from(path)), ScanArgsParquet::default())
+            .with_context(|| format!("failed to scan {}", path.display()))?;
+    let mut report = describe_incremental(&lazy_frame, previous, &changed, opts)?;
+    for summary in &mut report.columns {
+        summary.source_digest = digests.get(&summary.name).copied().flatten();
+    }
+    Ok(report)
+}
+
+/// Digest of the row-group statistics of each top-level column of a
+/// Parquet file; `None` for a column with a chunk lacking statistics
+#[cfg(feature = "parquet")]
+fn parquet_column_digests(path: &Path) -> Result<HashMap<String, Option<u64>>> {
+    let mut reader = ParquetReader::new(File::open(path)?);
+    let names: Vec<PlSmallStr> = reader.schema()?.iter_names().cloned().collect();
+    let metadata = reader.get_metadata()?;
+
+    let mut digests = HashMap::with_capacity(names.len());
+    for name in names {
+        let mut digest = Digest::default();
+        let mut complete = true;
+        'row_groups: for row_group in &metadata.row_groups {
+            digest.write_i64(row_group.num_rows() as i64);
+            let Some(chunks) = row_group.columns_under_root_iter(&name) else {
+                complete = false;
+                break;
+            };
+            for chunk in chunks {
+                let meta = chunk.metadata();
+                let Some(stats) = &meta.statistics else {
+                    complete = false;
+                    break 'row_groups;
+                };
+                for part in &meta.path_in_schema {
+                    digest.write_bytes(part.as_bytes());
+                }
+                digest.write_i64(meta.num_values);
+                digest.write_i64(meta.total_uncompressed_size);
+                for count in [stats.null_count, stats.distinct_count] {
+                    digest.write_i64(count.unwrap_or(-1));
+                }
+                for bound in [&stats.min_value, &stats.max_value, &stats.min, &stats.max] {
+                    digest.write_bytes(bound.as_deref().unwrap_or_default());
+                }
+            }
+        }
+        digests.insert(name.to_string(), complete.then_some(digest.0));
+    }
+    Ok(digests)
+}
+
+/// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust
+/// versions, so digests stored in serialized reports stay comparable
+#[cfg(feature = "parquet")]
+struct Digest(u64);
+
+#[cfg(feature = "parquet")]
+impl Default for Digest {
+    fn default() -> Self {
+        Digest(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+#[cfg(feature = "parquet")]
+impl Digest {
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 = (self.0 ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3);
+        }
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        self.write(&value.to_le_bytes());
+    }
+
+    /// Length-prefixed, so consecutive fields can't run into each other
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.write_i64(bytes.len() as i64);
+        self.write(bytes);
+    }
+}
+
+/// Error describing the first difference between the frame's schema and the
+/// columns of the previous report
+fn check_schema_unchanged(schema: &Schema, previous: &DescribeReport) -> Result<()> {
+    for (name, dtype) in schema.iter() {
+        match previous.column(name) {
+            None => {
+                return Err(DescribeError::SchemaChanged(format!(
+                    "column '{name}' is new since the previous report"
+                ))
+                .into());
+            }
+            Some(prev) if prev.dtype != dtype.to_string() => {
+                return Err(DescribeError::SchemaChanged(format!(
+                    "column '{name}' was {} and is now {dtype}",
+                    prev.dtype
+                ))
+                .into());
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some(removed) = previous.columns.iter().find(|c| !schema.contains(&c.name)) {
+        return Err(DescribeError::SchemaChanged(format!(
+            "column '{}' was removed since the previous report",
+            removed.name
+        ))
+        .into());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_incremental_recomputes_only_changed_columns() -> Result<()> {
+        let opts = DescribeOptions::new();
+        let before = df! {
+            "a" => [1, 2, 3],
+            "b" => [10.0, 20.0, 30.0],
+            "c" => ["x", "y", "z"],
+        }?;
+        let previous = before.describe_report(&opts)?;
+
+        let after = df! {
+            "a" => [1, 2, 3],
+            "b" => [100.0, 200.0, 300.0],
+            "c" => ["x", "y", "z"],
+        }?;
+        let report = describe_incremental(&after.lazy(), &previous, &["b"], &opts)?;
+
+        let b = report.column("b").unwrap();
+        assert!(!b.carried_forward);
+        assert_eq!(b.mean, Some(200.0));
+
+        for name in ["a", "c"] {
+            let summary = report.column(name).unwrap();
+            assert!(summary.carried_forward);
+            let mut expected = previous.column(name).unwrap().clone();
+            expected.carried_forward = true;
+            assert_eq!(summary, &expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_schema_drift() -> Result<()> {
+        let opts = DescribeOptions::new();
+        let previous = df! {
+            "a" => [1, 2, 3],
+            "gone" => [1, 2, 3],
+        }?
+        .describe_report(&opts)?;
+
+        let current = df! {
+            "a" => [1, 2, 3],
+            "new" => [4, 5, 6],
+        }?;
+        let report = describe_incremental(&current.lazy(), &previous, &[], &opts)?;
+
+        let names: Vec<&str> = report.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["a", "new"]);
+        assert!(report.column("a").unwrap().carried_forward);
+        assert!(!report.column("new").unwrap().carried_forward);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_strict_rejects_schema_change() -> Result<()> {
+        let opts = DescribeOptions::new();
+        let previous = df! { "a" => [1, 2, 3] }?.describe_report(&opts)?;
+        let current = df! { "a" => [1.0, 2.0, 3.0] }?;
+
+        let strict = DescribeOptions::new().strict(true);
+        let result = describe_incremental(&current.lazy(), &previous, &[], &strict);
+        assert!(result.unwrap_err().to_string().contains("schema changed"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_weight_column() -> Result<()> {
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let weighted = DescribeOptions {
+            weight_col: Some("w".to_string()),
+            ..opts.clone()
+        };
+        let df = df! { "x" => [1.0, 2.0, 3.0], "y" => [1, 2, 3], "w" => [1, 1, 2] }?;
+        let previous = describe_report_impl(&df.clone().lazy(), &weighted)?;
+
+        // The weight column isn't recomputed, but the changed column reads it
+        let report = describe_incremental(&df.clone().lazy(), &previous, &["x"], &weighted)?;
+        let names: Vec<&str> = report.columns.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, ["x", "y"]);
+        assert_eq!(report.column("x").unwrap().mean, Some(2.25));
+        assert!(report.column("y").unwrap().carried_forward);
+
+        // Changed weights change every weighted statistic
+        let report = describe_incremental(&df.lazy(), &previous, &["w"], &weighted)?;
+        assert!(report.columns.iter().all(|c| !c.carried_forward));
+        assert_eq!(report.columns.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_rejects_reshaping_options() -> Result<()> {
+        let df = df! { "a" => [1, 2] }?
+            .lazy()
+            .with_column(as_struct(vec![col("a")]).alias("item"));
+        let previous = DescribeReport::default();
+        for opts in [
+            DescribeOptions::new().unnest_structs(true),
+            DescribeOptions::new().columns(["a"]),
+        ] {
+            let err = describe_incremental(&df, &previous, &[], &opts).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DescribeError>(),
+                Some(DescribeError::InvalidArgument(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_incremental_parquet_detects_changed_columns() -> Result<()> {
+        let path = std::env::temp_dir().join(format!(
+            "describe_df_{}_incremental.parquet",
+            std::process::id()
+        ));
+        let write = |b: [f64; 3]| -> Result<()> {
+            let mut df = df! {
+                "a" => [1, 2, 3],
+                "b" => b,
+                "c" => ["x", "y", "z"],
+            }?;
+            ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+            Ok(())
+        };
+        let opts = DescribeOptions::new();
+
+        write([10.0, 20.0, 30.0])?;
+        let first = describe_incremental_parquet(&path, &DescribeReport::default(), &opts)?;
+        assert!(first.columns.iter().all(|c| !c.carried_forward));
+        assert!(first.columns.iter().all(|c| c.source_digest.is_some()));
+
+        // An unchanged file carries every column forward
+        let same = describe_incremental_parquet(&path, &first, &opts)?;
+        assert!(same.columns.iter().all(|c| c.carried_forward));
+
+        write([100.0, 200.0, 300.0])?;
+        let report = describe_incremental_parquet(&path, &first, &opts)?;
+        let b = report.column("b").unwrap();
+        assert!(!b.carried_forward);
+        assert_eq!(b.mean, Some(200.0));
+        assert_ne!(b.source_digest, first.column("b").unwrap().source_digest);
+        for name in ["a", "c"] {
+            let summary = report.column(name).unwrap();
+            assert!(summary.carried_forward);
+            assert_eq!(
+                summary.source_digest,
+                first.column(name).unwrap().source_digest
+            );
+        }
+
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_incremental_unknown_changed_column() -> Result<()> {
+        let opts = DescribeOptions::new();
+        let df = df! { "a" => [1, 2, 3] }?;
+        let previous = df.describe_report(&opts)?;
+
+        let result = describe_incremental(&df.lazy(), &previous, &["missing"], &opts);
+        assert!(result.is_err());
+
+        Ok(())
+    }
+}
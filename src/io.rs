@@ -0,0 +1,217 @@
+//! Writing describe output to files and Arrow IPC streams
+//!
+//! CSV gets the native string layout of `describe_with()` (`Compat` layouts
+//! aside), so the file reads like the printed table. Parquet gets the typed layout of
+//! `DescribeReport::to_typed_dataframe()`, so numeric statistics survive the
+//! round trip without being parsed back from strings. The Arrow IPC stream
+//! uses the same typed layout and is meant for handing statistics to other
+//! Arrow consumers (e.g. `pyarrow.ipc.open_stream`).
+
+#[cfg(feature = "ipc")]
+use std::collections::BTreeMap;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+use std::fs::File;
+#[cfg(feature = "ipc")]
+use std::io::Cursor;
+#[cfg(any(feature = "csv", feature = "parquet"))]
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+#[cfg(feature = "csv")]
+use crate::describe::describe_native_impl;
+#[cfg(any(feature = "parquet", feature = "ipc"))]
+use crate::describe::describe_report_impl;
+#[cfg(feature = "ipc")]
+use crate::describe::resolved_percentiles;
+use crate::options::DescribeOptions;
+
+/// Schema metadata key holding the percentile list of an IPC stream, as a
+/// JSON array (e.g. `[0.25,0.5,0.75]`)
+#[cfg(feature = "ipc")]
+pub(crate) const PERCENTILES_METADATA_KEY: &str = "describe_df.percentiles";
+
+#[cfg(feature = "csv")]
+pub(crate) fn describe_to_csv_impl(
+    lazy_frame: &LazyFrame,
+    path: &Path,
+    opts: &DescribeOptions,
+) -> Result<()> {
+    let mut stats = describe_native_impl(lazy_frame, opts)?;
+    let mut file = create_file(path)?;
+    CsvWriter::new(&mut file)
+        .finish(&mut stats)
+        .with_context(|| format!("failed to write CSV to {}", path.display()))
+}
+
+#[cfg(feature = "parquet")]
+pub(crate) fn describe_to_parquet_impl(
+    lazy_frame: &LazyFrame,
+    path: &Path,
+    opts: &DescribeOptions,
+) -> Result<()> {
+    let mut stats = describe_report_impl(lazy_frame, opts)?.to_typed_dataframe()?;
+    let file = create_file(path)?;
+    ParquetWriter::new(file)
+        .finish(&mut stats)
+        .with_context(|| format!("failed to write Parquet to {}", path.display()))?;
+    Ok(())
+}
+
+#[cfg(feature = "ipc")]
+pub(crate) fn describe_ipc_impl(lazy_frame: &LazyFrame, opts: &DescribeOptions) -> Result<Vec<u8>> {
+    let percentiles = resolved_percentiles(opts)?;
+    let mut stats = describe_report_impl(lazy_frame, opts)?.to_typed_dataframe()?;
+
+    let list: Vec<String> = percentiles.iter().map(ToString::to_string).collect();
+    let metadata = BTreeMap::from([(
+        PERCENTILES_METADATA_KEY.into(),
+        format!("[{}]", list.join(",")).into(),
+    )]);
+
+    let mut bytes = Vec::new();
+    let mut writer = IpcStreamWriter::new(&mut bytes);
+    writer.set_custom_schema_metadata(Arc::new(metadata));
+    writer.finish(&mut stats)?;
+    Ok(bytes)
+}
+
+/// Read describe statistics written by `Describable::describe_ipc`
+///
+/// Returns the typed layout of `DescribeReport::to_typed_dataframe()`. Any
+/// Arrow IPC stream is accepted; the percentile metadata is not required.
+#[cfg(feature = "ipc")]
+pub fn describe_from_ipc(bytes: &[u8]) -> Result<DataFrame> {
+    IpcStreamReader::new(Cursor::new(bytes))
+        .finish()
+        .context("failed to read describe statistics from IPC stream")
+}
+
+/// Create (or truncate) the output file, naming the path on failure
+#[cfg(any(feature = "csv", feature = "parquet"))]
+pub(crate) fn create_file(path: &Path) -> Result<File> {
+    File::create(path).with_context(|| format!("failed to create {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(any(feature = "csv", feature = "parquet"))]
+    use std::path::PathBuf;
+
+    use super::*;
+    #[cfg(feature = "csv")]
+    use crate::Compat;
+    use crate::Describable;
+
+    #[cfg(any(feature = "csv", feature = "parquet"))]
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("describe_df_{}_{name}", std::process::id()))
+    }
+
+    fn sample() -> Result<DataFrame> {
+        Ok(df! {
+            "x" => [Some(1.5), None, Some(-2.0), Some(4.25)],
+            "n" => [3, 1, 4, 1],
+            "s" => ["b", "a", "d", "c"],
+        }?)
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_round_trip() -> Result<()> {
+        let df = sample()?;
+        let opts = DescribeOptions::new();
+        let path = temp_path("round_trip.csv");
+        df.describe_to_csv(&path, &opts)?;
+
+        // Read every column back as String, like the in-memory layout
+        let read = CsvReadOptions::default()
+            .with_infer_schema_length(Some(0))
+            .try_into_reader_with_file_path(Some(path.clone()))?
+            .finish()?;
+        std::fs::remove_file(&path)?;
+
+        assert!(read.equals_missing(&df.describe_with(&opts)?));
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_round_trip_is_typed() -> Result<()> {
+        let df = sample()?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let path = temp_path("round_trip.parquet");
+        df.describe_to_parquet(&path, &opts)?;
+
+        let read = ParquetReader::new(File::open(&path)?).finish()?;
+        std::fs::remove_file(&path)?;
+
+        let expected = df.describe_report(&opts)?.to_typed_dataframe()?;
+        assert!(read.equals_missing(&expected));
+        assert_eq!(read.column("mean")?.dtype(), &DataType::Float64);
+        assert_eq!(read.column("count")?.dtype(), &DataType::UInt64);
+        Ok(())
+    }
+
+    #[cfg(feature = "ipc")]
+    #[test]
+    fn test_ipc_round_trip() -> Result<()> {
+        let df = sample()?;
+        let opts = DescribeOptions::new();
+        let bytes = df.describe_ipc(&opts)?;
+
+        let read = describe_from_ipc(&bytes)?;
+        let expected = df.describe_report(&opts)?.to_typed_dataframe()?;
+        assert!(read.equals_missing(&expected));
+        assert_eq!(read.column("null_count")?.dtype(), &DataType::UInt64);
+        assert_eq!(read.column("25%")?.dtype(), &DataType::Float64);
+        Ok(())
+    }
+
+    #[cfg(feature = "ipc")]
+    #[test]
+    fn test_ipc_metadata_records_percentiles() -> Result<()> {
+        let opts = DescribeOptions::new().percentiles(vec![0.1, 0.5, 0.9]);
+        let bytes = sample()?.lazy().describe_ipc(&opts)?;
+
+        let metadata = IpcStreamReader::new(Cursor::new(bytes))
+            .custom_metadata()?
+            .expect("stream has schema metadata");
+        assert_eq!(
+            metadata.get(PERCENTILES_METADATA_KEY).map(|v| v.as_str()),
+            Some("[0.1,0.5,0.9]")
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_missing_parent_directory_names_path() -> Result<()> {
+        let path = temp_path("missing_dir").join("out.csv");
+        let err = sample()?
+            .describe_to_csv(&path, &DescribeOptions::new())
+            .unwrap_err();
+        assert!(format!("{err:#}").contains(&path.display().to_string()));
+        Ok(())
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_csv_ignores_compat() -> Result<()> {
+        let df = sample()?;
+        let native = temp_path("native.csv");
+        df.describe_to_csv(&native, &DescribeOptions::new())?;
+        let native_text = std::fs::read_to_string(&native)?;
+        std::fs::remove_file(&native)?;
+
+        for compat in [Compat::PyPolars, Compat::Pandas] {
+            let path = temp_path("compat.csv");
+            df.describe_to_csv(&path, &DescribeOptions::new().compat(compat))?;
+            let text = std::fs::read_to_string(&path)?;
+            std::fs::remove_file(&path)?;
+            assert_eq!(text, native_text, "{compat:?}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+//! JSON rendering of describe output for logging and piping into tools
+//!
+//! The JSON object is keyed by column name; each column maps statistic names
+//! to values in describe order. Numbers are JSON numbers, missing values are
+//! `null` and temporal values are their ISO-8601 strings.
+
+use anyhow::Result;
+use polars::prelude::*;
+use serde_json::{Map, Value};
+
+use crate::describe::describe_report_impl;
+use crate::options::DescribeOptions;
+use crate::report::{DescribeReport, StatValue, label_percent};
+
+pub(crate) fn describe_json_impl(
+    lazy_frame: &LazyFrame,
+    percentiles: Option<Vec<f64>>,
+    pretty: bool,
+) -> Result<String> {
+    let report = describe_report_impl(lazy_frame, &DescribeOptions::from_percentiles(percentiles))?;
+    let value = report_json(&report);
+    let json = if pretty {
+        serde_json::to_string_pretty(&value)?
+    } else {
+        serde_json::to_string(&value)?
+    };
+    Ok(json)
+}
+
+fn report_json(report: &DescribeReport) -> Value {
+    let float = |v: Option<f64>| v.map_or(Value::Null, Value::from);
+
+    let mut columns = Map::new();
+    for summary in &report.columns {
+        let mut stats = Map::new();
+        stats.insert("count".to_string(), Value::from(summary.count));
+        stats.insert("null_count".to_string(), Value::from(summary.null_count));
+        stats.insert("mean".to_string(), float(summary.mean));
+        stats.insert("std".to_string(), float(summary.std));
+        stats.insert("min".to_string(), stat_value_json(summary.min.as_ref()));
+
+        let mut percentiles: Vec<(&String, &f64)> = summary.percentiles.iter().collect();
+        percentiles.sort_by(|a, b| label_percent(a.0).total_cmp(&label_percent(b.0)));
+        for (label, value) in percentiles {
+            stats.insert(label.clone(), Value::from(*value));
+        }
+
+        stats.insert("max".to_string(), stat_value_json(summary.max.as_ref()));
+        columns.insert(summary.name.clone(), Value::Object(stats));
+    }
+    Value::Object(columns)
+}
+
+/// Numbers and booleans stay JSON scalars, everything else is its string
+/// rendering
+fn stat_value_json(value: Option<&StatValue>) -> Value {
+    match value {
+        None => Value::Null,
+        Some(StatValue::Int(v)) => Value::from(*v),
+        Some(StatValue::UInt(v)) => Value::from(*v),
+        Some(StatValue::Float(v)) => Value::from(*v),
+        Some(StatValue::Bool(v)) => Value::from(*v),
+        Some(other) => Value::from(other.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Describable;
+    use anyhow::Result;
+    use polars::prelude::*;
+
+    #[test]
+    fn test_describe_json_values() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(3.0), None],
+            "name" => ["b", "a", "c"],
+            "day" => [19_000, 19_001, 19_002],
+        }?
+        .lazy()
+        .with_column(col("day").cast(DataType::Date))
+        .collect()?;
+
+        let json: serde_json::Value = serde_json::from_str(&df.describe_json(None, false)?)?;
+        assert_eq!(json["x"]["count"], 2);
+        assert_eq!(json["x"]["mean"], 2.0);
+        assert_eq!(json["x"]["50%"], 2.0);
+        assert_eq!(json["name"]["mean"], serde_json::Value::Null);
+        assert_eq!(json["name"]["min"], "a");
+        assert_eq!(json["day"]["max"], "2022-01-10");
+
+        // Statistics keep the describe order
+        let keys: Vec<&String> = json["x"].as_object().unwrap().keys().collect();
+        assert_eq!(
+            keys,
+            [
+                "count",
+                "null_count",
+                "mean",
+                "std",
+                "min",
+                "25%",
+                "50%",
+                "75%",
+                "max"
+            ]
+        );
+
+        let pretty = df.lazy().describe_json(Some(vec![0.5]), true)?;
+        assert!(pretty.contains("\n  \"x\": {"));
+        Ok(())
+    }
+}
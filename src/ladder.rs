@@ -0,0 +1,117 @@
+//! Evenly spaced quantile ladders
+//!
+//! `describe_quantiles(n)` describes every column at the n + 1 quantiles
+//! 0, 1/n, ..., 1 (deciles for n = 10, percentiles for n = 100), keeping
+//! only the count, min, ladder and max rows.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::{describe_lazy_impl, percentile_label};
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+/// Largest number of ladder steps
+const MAX_STEPS: usize = 1_000;
+
+/// The n + 1 quantiles 0, 1/n, ..., 1
+fn ladder(n: usize) -> Vec<f64> {
+    #[allow(clippy::cast_precision_loss)]
+    (0..=n).map(|i| i as f64 / n as f64).collect()
+}
+
+pub(crate) fn describe_quantiles_impl(lazy_frame: &LazyFrame, n: usize) -> Result<DataFrame> {
+    if !(1..=MAX_STEPS).contains(&n) {
+        return Err(DescribeError::InvalidArgument(format!(
+            "quantile ladder steps {n} is outside the range [1, {MAX_STEPS}]"
+        ))
+        .into());
+    }
+    let quantiles = ladder(n);
+    let mut kept: Vec<String> = vec!["count".to_string(), "min".to_string()];
+    kept.extend(quantiles.iter().map(|q| percentile_label(*q)));
+    kept.push("max".to_string());
+
+    let stats = describe_lazy_impl(lazy_frame, &DescribeOptions::new().percentiles(quantiles))?;
+    let labels = stats.column("statistic")?.str()?;
+    let mask: BooleanChunked = labels
+        .into_iter()
+        .map(|label| label.is_some_and(|label| kept.iter().any(|k| k == label)))
+        .collect();
+    stats.filter(&mask).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+    use crate::samples::skewed_frame;
+
+    fn labels(stats: &DataFrame) -> Result<Vec<String>> {
+        Ok(stats
+            .column("statistic")?
+            .str()?
+            .into_no_null_iter()
+            .map(str::to_string)
+            .collect())
+    }
+
+    #[test]
+    fn test_quartile_ladder_matches_describe() -> Result<()> {
+        let df = df! {
+            "x" => [Some(3.0), Some(1.0), None, Some(7.5), Some(2.0), Some(4.0)],
+            "name" => ["a", "b", "c", "d", "e", "f"],
+        }?;
+        let ladder = df.describe_quantiles(4)?;
+        assert_eq!(
+            labels(&ladder)?,
+            ["count", "min", "0%", "25%", "50%", "75%", "100%", "max"]
+        );
+
+        let stats = df.describe(None)?;
+        for label in ["count", "min", "25%", "50%", "75%", "max"] {
+            let row = |frame: &DataFrame| -> Result<DataFrame> {
+                Ok(frame
+                    .clone()
+                    .lazy()
+                    .filter(col("statistic").eq(lit(label)))
+                    .collect()?)
+            };
+            assert!(row(&ladder)?.equals_missing(&row(&stats)?), "{label}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ladder_is_monotone() -> Result<()> {
+        let lf = skewed_frame(2_000, 11)?.lazy();
+        let ladder = lf.describe_quantiles(100)?;
+        assert_eq!(ladder.height(), 104);
+        for name in ["exponential", "lognormal", "left_skewed"] {
+            let values: Vec<f64> = ladder
+                .column(name)?
+                .str()?
+                .into_no_null_iter()
+                .skip(1)
+                .map(str::parse)
+                .collect::<Result<_, _>>()?;
+            assert!(values.is_sorted(), "{name}: {values:?}");
+            assert_eq!(values.first(), values.get(1), "{name}: 0% is the min");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_ladder_steps_out_of_range() -> Result<()> {
+        let df = df! { "x" => [1, 2, 3] }?;
+        for n in [0, 1_001] {
+            let err = df.describe_quantiles(n).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DescribeError>(),
+                Some(DescribeError::InvalidArgument(_))
+            ));
+        }
+        assert_eq!(df.describe_quantiles(1_000)?.height(), 1_004);
+        Ok(())
+    }
+}
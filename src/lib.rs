@@ -1,2 +1,84 @@
+mod compat;
+#[cfg(feature = "correlation")]
+mod correlation;
 mod describe;
+mod diff;
+mod duplicates;
+mod eav;
+mod ecdf;
+mod error;
+mod expressions;
+mod format;
+mod frame;
+mod frequency;
+mod histogram;
+#[cfg(all(feature = "temporal", feature = "parquet"))]
+mod history;
+mod html;
+mod incremental;
+#[cfg(any(feature = "csv", feature = "parquet", feature = "ipc"))]
+mod io;
+#[cfg(feature = "serde")]
+mod json;
+mod ladder;
+mod many;
+mod markdown;
+mod missing;
+mod options;
+mod partial;
+mod partition;
+mod pretty;
+mod report;
+pub mod samples;
+mod sampling;
+#[cfg(any(
+    feature = "csv",
+    feature = "ndjson",
+    feature = "parquet",
+    feature = "ipc"
+))]
+mod scan;
+mod schema;
+mod statistics;
+mod structs;
+mod summation;
+pub mod testing;
+mod validation;
+mod weighted;
+#[cfg(feature = "temporal")]
+mod window;
+pub use compat::Compat;
+#[cfg(feature = "correlation")]
+pub use correlation::CorrMethod;
 pub use describe::Describable;
+pub use diff::{StatDiff, describe_diff};
+pub use duplicates::DuplicatesReport;
+pub use error::DescribeError;
+pub use frame::FrameSummary;
+pub use histogram::Histogram;
+#[cfg(all(feature = "temporal", feature = "parquet"))]
+pub use history::DescribeHistory;
+pub use incremental::describe_incremental;
+#[cfg(feature = "parquet")]
+pub use incremental::describe_incremental_parquet;
+#[cfg(feature = "ipc")]
+pub use io::describe_from_ipc;
+pub use many::describe_many;
+pub use missing::NullReport;
+pub use options::{
+    CancellationToken, ColumnOpts, ColumnOrder, DescribeOptions, Engine, LabelStyle, Progress,
+    TrimMode,
+};
+pub use partial::{Extreme, PartialColumn, PartialSummary};
+pub use partition::DescribableGroupBy;
+pub use report::{ColumnSummary, DescribeReport, StatValue};
+#[cfg(feature = "csv")]
+pub use scan::describe_csv;
+#[cfg(feature = "ipc")]
+pub use scan::describe_ipc;
+#[cfg(feature = "ndjson")]
+pub use scan::describe_ndjson;
+#[cfg(feature = "parquet")]
+pub use scan::describe_parquet;
+pub use statistics::Statistic;
+pub use validation::{Expectations, RuleResult, ValidationReport};
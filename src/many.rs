@@ -0,0 +1,119 @@
+//! Side-by-side describes of several frames
+//!
+//! `describe_many` describes each frame with the same options and stacks
+//! the results behind a `source` column. Sources don't need the same
+//! schema: the describes are concatenated diagonally, so a column missing
+//! from one source shows up as nulls in that source's rows.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::describe_lazy_impl;
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+/// Name of the leading column holding each row's source label
+const SOURCE_COLUMN: &str = "source";
+
+/// Describe several frames and stack the results, labelled by source
+///
+/// Returns the describe rows of every frame in the given order, led by a
+/// `source` column with the frame's label and the `statistic` column.
+/// Columns follow their first appearance across the sources. Fails when
+/// no frames are given, when a frame has a `source` column itself, or when
+/// a frame can't be described; errors name the source.
+///
+/// # Example
+/// ```rust
+/// use polars::prelude::*;
+/// use describe_df::{DescribeOptions, describe_many};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let staging = df! { "price" => [1.0, 2.0], "qty" => [1, 2] }?.lazy();
+/// let prod = df! { "price" => [1.5, 2.5, 3.5] }?.lazy();
+///
+/// let stats = describe_many(&[("staging", staging), ("prod", prod)], &DescribeOptions::new())?;
+/// println!("{stats}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn describe_many(frames: &[(&str, LazyFrame)], opts: &DescribeOptions) -> Result<DataFrame> {
+    if frames.is_empty() {
+        return Err(DescribeError::InvalidArgument(
+            "describe_many needs at least one frame".to_string(),
+        )
+        .into());
+    }
+
+    let mut described = Vec::with_capacity(frames.len());
+    for (label, lazy_frame) in frames {
+        let describe = || -> Result<LazyFrame> {
+            let schema = lazy_frame.clone().collect_schema()?;
+            if schema.contains(SOURCE_COLUMN) {
+                return Err(DescribeError::InvalidArgument(format!(
+                    "column '{SOURCE_COLUMN}' clashes with the source label column"
+                ))
+                .into());
+            }
+            let stats = describe_lazy_impl(lazy_frame, opts)?;
+            let mut columns = vec![Column::new(
+                SOURCE_COLUMN.into(),
+                vec![*label; stats.height()],
+            )];
+            columns.extend(stats.take_columns());
+            Ok(DataFrame::new(columns)?.lazy())
+        };
+        described.push(describe().with_context(|| format!("failed to describe source '{label}'"))?);
+    }
+    concat_lf_diagonal(described, UnionArgs::default())?
+        .collect()
+        .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_describe_many_aligns_columns() -> Result<()> {
+        let staging = df! { "price" => [1.0, 2.0], "qty" => [1, 2] }?.lazy();
+        let prod = df! { "price" => [1.5, 2.5, 3.5], "city" => ["a", "b", "c"] }?.lazy();
+        let stats = describe_many(
+            &[("staging", staging), ("prod", prod)],
+            &DescribeOptions::new(),
+        )?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["source", "statistic", "price", "qty", "city"]
+        );
+        // Nine describe rows per source
+        assert_eq!(stats.height(), 18);
+
+        let sources: Vec<Option<&str>> = stats
+            .column("source")?
+            .str()?
+            .into_iter()
+            .step_by(9)
+            .collect();
+        assert_eq!(sources, [Some("staging"), Some("prod")]);
+        let cell = |row: usize, column: &str| stats.column(column).unwrap().str().unwrap().get(row);
+        // Row 0 is staging's count, row 9 prod's
+        assert_eq!(cell(0, "price"), Some("2"));
+        assert_eq!(cell(9, "price"), Some("3"));
+        assert_eq!(cell(0, "qty"), Some("2"));
+        assert_eq!(cell(9, "qty"), None);
+        assert_eq!(cell(0, "city"), None);
+        assert_eq!(cell(9, "city"), Some("3"));
+        assert_eq!(cell(11, "price"), Some("2.5"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_many_errors() -> Result<()> {
+        assert!(describe_many(&[], &DescribeOptions::new()).is_err());
+        let clash = df! { "source" => ["x"] }?.lazy();
+        let err = describe_many(&[("raw", clash)], &DescribeOptions::new()).unwrap_err();
+        assert!(format!("{err:#}").contains("'raw'"), "{err:#}");
+        Ok(())
+    }
+}
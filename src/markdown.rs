@@ -0,0 +1,166 @@
+//! GitHub-flavored markdown rendering of describe output
+//!
+//! Pipe tables survive being pasted into PR descriptions and chat, unlike the
+//! box-drawing table Polars prints.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::{GAP_COLUMN, describe_native_impl};
+use crate::options::DescribeOptions;
+
+pub(crate) fn describe_markdown_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+    max_columns: Option<usize>,
+) -> Result<String> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let stats = describe_native_impl(lazy_frame, opts)?;
+    let is_numeric = |name: &str| schema.get(name).is_some_and(DataType::is_numeric);
+    render_markdown(&stats, &is_numeric, max_columns)
+}
+
+/// Render a describe output as a pipe table
+///
+/// The statistic column is left-aligned and the columns of numeric source
+/// columns right-aligned. With `max_columns`, only that many described
+/// columns are kept and an `…` column marks the truncation. The `…` column
+/// of `DescribeOptions::max_columns` ends the kept columns when it is
+/// reached first, so the table has one `…` column at most.
+fn render_markdown(
+    stats: &DataFrame,
+    is_numeric: &dyn Fn(&str) -> bool,
+    max_columns: Option<usize>,
+) -> Result<String> {
+    let mut columns: Vec<&Column> = stats.get_columns().iter().collect();
+    let gap = columns.iter().position(|c| c.name() == GAP_COLUMN);
+    let described = columns.len() - 1 - usize::from(gap.is_some());
+    let truncated = max_columns.is_some_and(|max| described > max);
+    if let Some(max) = max_columns.filter(|_| truncated) {
+        columns.truncate(gap.map_or(max + 1, |gap| gap.min(max + 1)));
+    }
+
+    let mut header: Vec<String> = columns.iter().map(|c| escape(c.name())).collect();
+    let mut separator: Vec<&str> = columns
+        .iter()
+        .enumerate()
+        .map(|(i, c)| {
+            if i > 0 && is_numeric(c.name()) {
+                "---:"
+            } else {
+                "---"
+            }
+        })
+        .collect();
+    if truncated {
+        header.push(GAP_COLUMN.to_string());
+        separator.push("---");
+    }
+
+    let mut lines = vec![table_row(&header), table_row(&separator)];
+    for row in 0..stats.height() {
+        let mut cells = columns
+            .iter()
+            .map(|c| {
+                let value = c.get(row)?;
+                Ok(escape(value.get_str().unwrap_or("null")))
+            })
+            .collect::<Result<Vec<String>>>()?;
+        if truncated {
+            cells.push(GAP_COLUMN.to_string());
+        }
+        lines.push(table_row(&cells));
+    }
+
+    let mut markdown = lines.join("\n");
+    markdown.push('\n');
+    Ok(markdown)
+}
+
+fn table_row<S: AsRef<str>>(cells: &[S]) -> String {
+    let cells: Vec<&str> = cells.iter().map(AsRef::as_ref).collect();
+    format!("| {} |", cells.join(" | "))
+}
+
+/// Escape pipes so they don't end the cell, and keep the cell on one line
+fn escape(value: &str) -> String {
+    value.replace('|', "\\|").replace('\n', " ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Compat;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_markdown_table() -> Result<()> {
+        let df = df! {
+            "n" => [1, 3],
+            "s" => ["a|b", "c"],
+            "f" => [0.5, 1.5],
+        }?;
+
+        let markdown =
+            df.describe_markdown(&DescribeOptions::new().percentiles(vec![0.5]), None)?;
+        assert_eq!(
+            markdown,
+            "\
+| statistic | n | s | f |
+| --- | ---: | --- | ---: |
+| count | 2 | 2 | 2 |
+| null_count | 0 | 0 | 0 |
+| mean | 2.0 | null | 1.0 |
+| std | 1.414214 | null | 0.707107 |
+| min | 1 | a\\|b | 0.5 |
+| 50% | 2.0 | null | 1.0 |
+| max | 3 | c | 1.5 |
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_markdown_truncates_columns() -> Result<()> {
+        let df = df! { "a" => [1], "b" => [2], "c" => [3] }?;
+        let opts = DescribeOptions::new();
+
+        let header = |max| -> Result<String> {
+            let markdown = df.describe_markdown(&opts, max)?;
+            Ok(markdown.lines().next().unwrap().to_string())
+        };
+        assert_eq!(header(Some(2))?, "| statistic | a | b | … |");
+        assert_eq!(header(Some(3))?, "| statistic | a | b | c |");
+        assert_eq!(header(None)?, "| statistic | a | b | c |");
+
+        let markdown = df.describe_markdown(&opts, Some(1))?;
+        assert!(markdown.lines().nth(2).unwrap().ends_with("| 1 | … |"));
+
+        // The gap column of max_columns isn't doubled by the truncation
+        let wide = df! { "a" => [1], "b" => [2], "c" => [3], "d" => [4], "e" => [5] }?;
+        let limited = DescribeOptions::new()
+            .on_warning(|_| {})
+            .max_columns_head_tail(2, 2);
+        let header = |max| -> Result<String> {
+            let markdown = wide.describe_markdown(&limited, max)?;
+            Ok(markdown.lines().next().unwrap().to_string())
+        };
+        assert_eq!(header(None)?, "| statistic | a | b | … | d | e |");
+        assert_eq!(header(Some(4))?, "| statistic | a | b | … | d | e |");
+        assert_eq!(header(Some(3))?, "| statistic | a | b | … |");
+        assert_eq!(header(Some(1))?, "| statistic | a | … |");
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_markdown_ignores_compat() -> Result<()> {
+        let df = df! { "x" => [1.5, 2.5], "s" => ["a", "b"], "b" => [true, false] }?;
+        let native = df.describe_markdown(&DescribeOptions::new(), None)?;
+        assert!(native.contains("| mean | 2.0 | null | 0.5 |"), "{native}");
+        for compat in [Compat::PyPolars, Compat::Pandas] {
+            let markdown = df.describe_markdown(&DescribeOptions::new().compat(compat), None)?;
+            assert_eq!(markdown, native, "{compat:?}");
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,155 @@
+//! Missingness report
+//!
+//! `null_report()` goes beyond the null_count row of `describe()`: where the
+//! nulls of each column sit and, optionally, which columns are null
+//! together. Everything is aggregated in a single lazy pass, so scans are
+//! read once.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+/// Result of `Describable::null_report`
+#[derive(Debug, Clone)]
+pub struct NullReport {
+    /// One row per column: `column`, `null_count` (UInt64), `null_pct`
+    /// (percent of rows, Float64) and `first_null`/`last_null` (UInt64 row
+    /// positions, null when the column has no nulls)
+    pub columns: DataFrame,
+    /// Square matrix with a leading `column` column and one UInt64 column
+    /// per column, counting the rows where both columns are null; the
+    /// diagonal is the null count. Only computed when requested.
+    pub co_missing: Option<DataFrame>,
+}
+
+pub(crate) fn null_report_impl(lazy_frame: &LazyFrame, co_missing: bool) -> Result<NullReport> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let names: Vec<&PlSmallStr> = schema.iter_names().collect();
+
+    let mut exprs = vec![len().cast(DataType::UInt64).alias("__rows")];
+    for name in &names {
+        let nulls = col((*name).clone()).is_null();
+        // arg_max of a boolean mask is the position of its first true value
+        let position = |expr: Expr| {
+            when(nulls.clone().any(true))
+                .then(expr.cast(DataType::UInt64))
+                .otherwise(lit(NULL).cast(DataType::UInt64))
+        };
+        exprs.extend([
+            nulls
+                .clone()
+                .sum()
+                .cast(DataType::UInt64)
+                .alias(format!("null_count:{name}")),
+            position(nulls.clone().arg_max()).alias(format!("first_null:{name}")),
+            position(
+                len().cast(DataType::Int64)
+                    - lit(1)
+                    - nulls.clone().reverse().arg_max().cast(DataType::Int64),
+            )
+            .alias(format!("last_null:{name}")),
+        ]);
+    }
+    if co_missing {
+        for (i, a) in names.iter().enumerate() {
+            for b in &names[i + 1..] {
+                exprs.push(
+                    col((*a).clone())
+                        .is_null()
+                        .and(col((*b).clone()).is_null())
+                        .sum()
+                        .cast(DataType::UInt64)
+                        .alias(format!("both:{a}:{b}")),
+                );
+            }
+        }
+    }
+    let stats = lazy_frame.clone().select(exprs).collect()?;
+
+    let value = |alias: &str| -> Result<Option<u64>> { Ok(stats.column(alias)?.u64()?.get(0)) };
+    let rows = value("__rows")?.unwrap_or(0);
+
+    let mut null_count = Vec::with_capacity(names.len());
+    let mut null_pct = Vec::with_capacity(names.len());
+    let mut first_null = Vec::with_capacity(names.len());
+    let mut last_null = Vec::with_capacity(names.len());
+    for name in &names {
+        let count = value(&format!("null_count:{name}"))?.unwrap_or(0);
+        null_count.push(count);
+        #[allow(clippy::cast_precision_loss)]
+        null_pct.push((rows > 0).then(|| count as f64 / rows as f64 * 100.0));
+        first_null.push(value(&format!("first_null:{name}"))?);
+        last_null.push(value(&format!("last_null:{name}"))?);
+    }
+    let column_names: Vec<&str> = names.iter().map(|name| name.as_str()).collect();
+    let columns = DataFrame::new(vec![
+        Column::new("column".into(), &column_names),
+        Column::new("null_count".into(), &null_count),
+        Column::new("null_pct".into(), null_pct),
+        Column::new("first_null".into(), first_null),
+        Column::new("last_null".into(), last_null),
+    ])?;
+
+    let co_missing = if co_missing {
+        let mut matrix = vec![Column::new("column".into(), &column_names)];
+        for (j, b) in names.iter().enumerate() {
+            let counts = names
+                .iter()
+                .enumerate()
+                .map(|(i, a)| match i.cmp(&j) {
+                    std::cmp::Ordering::Equal => Ok(null_count[i]),
+                    std::cmp::Ordering::Less => Ok(value(&format!("both:{a}:{b}"))?.unwrap_or(0)),
+                    std::cmp::Ordering::Greater => {
+                        Ok(value(&format!("both:{b}:{a}"))?.unwrap_or(0))
+                    }
+                })
+                .collect::<Result<Vec<u64>>>()?;
+            matrix.push(Column::new((*b).clone(), counts));
+        }
+        Some(DataFrame::new(matrix)?)
+    } else {
+        None
+    };
+
+    Ok(NullReport {
+        columns,
+        co_missing,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_null_report_with_correlated_missingness() -> Result<()> {
+        // a and b are missing together in rows 1 and 3; c only in row 3
+        let df = df! {
+            "a" => [Some(1), None, Some(3), None, Some(5)],
+            "b" => [Some("x"), None, Some("y"), None, None],
+            "c" => [Some(1.0), Some(2.0), Some(3.0), None, Some(5.0)],
+            "d" => [1, 2, 3, 4, 5],
+        }?;
+        let report = df.lazy().null_report(true)?;
+
+        let columns = &report.columns;
+        let counts: Vec<Option<u64>> = columns.column("null_count")?.u64()?.into_iter().collect();
+        assert_eq!(counts, [2, 3, 1, 0].map(Some));
+        assert_eq!(columns.column("null_pct")?.f64()?.get(1), Some(60.0));
+        assert_eq!(columns.column("first_null")?.u64()?.get(1), Some(1));
+        assert_eq!(columns.column("last_null")?.u64()?.get(1), Some(4));
+        assert_eq!(columns.column("first_null")?.u64()?.get(3), None);
+
+        let matrix = report.co_missing.unwrap();
+        let column = |name: &str| -> Result<Vec<u64>> {
+            Ok(matrix.column(name)?.u64()?.into_no_null_iter().collect())
+        };
+        assert_eq!(column("a")?, [2, 2, 1, 0]);
+        assert_eq!(column("b")?, [2, 3, 1, 0]);
+        assert_eq!(column("c")?, [1, 1, 1, 0]);
+        assert_eq!(column("d")?, [0, 0, 0, 0]);
+
+        assert!(df!("a" => [1])?.null_report(false)?.co_missing.is_none());
+        Ok(())
+    }
+}
@@ -0,0 +1,948 @@
+//! Options controlling how `describe` computes and reports statistics
+
+use std::fmt;
+use std::panic::{AssertUnwindSafe, catch_unwind};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use polars::prelude::*;
+
+use crate::compat::Compat;
+use crate::error::DescribeError;
+use crate::statistics::{CustomStat, Statistic};
+
+/// Percentiles computed when none are configured
+pub(crate) const DEFAULT_PERCENTILES: [f64; 3] = [0.25, 0.50, 0.75];
+
+/// Callback receiving non-fatal warnings raised while describing
+pub(crate) type WarningHook = Arc<dyn Fn(&str) + Send + Sync>;
+
+/// Callback receiving the milestones of a describe
+pub(crate) type ProgressHook = Arc<dyn Fn(Progress) + Send + Sync>;
+
+/// A milestone reported through `DescribeOptions::on_progress`, in the
+/// order they occur
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+    /// The columns to describe and their dtypes are known
+    SchemaResolved,
+    /// The aggregation expressions are built
+    ExpressionsBuilt,
+    /// The aggregation was handed to Polars
+    CollectionStarted,
+    /// A batch of columns collected on its own was aggregated (in-memory
+    /// frames with a hook, or scans wider than one batch)
+    Batch {
+        columns_done: usize,
+        columns_total: usize,
+    },
+    /// The aggregation (and any second pass) finished
+    CollectionFinished,
+    /// The output was built from the aggregation result
+    ReshapeDone,
+}
+
+/// Which leading/trailing whitespace is stripped before computing string
+/// statistics
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrimMode {
+    /// Use the strings as they are
+    #[default]
+    None,
+    /// Strip whitespace on both ends
+    Both,
+    /// Strip leading whitespace
+    Start,
+    /// Strip trailing whitespace
+    End,
+}
+
+/// Spelling of the statistic labels, see `DescribeOptions::label_style`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum LabelStyle {
+    /// Percentiles as percents: `25%`, `99.9%`
+    #[default]
+    Human,
+    /// Percentiles as identifiers: `p25`, `p99_9`
+    Snake,
+}
+
+/// Order of the described columns in the output, see
+/// `DescribeOptions::order_by`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ColumnOrder {
+    /// The order of the frame's schema
+    #[default]
+    SchemaOrder,
+    /// By column name
+    Alphabetical,
+    /// Most nulls first
+    NullCountDesc,
+    /// Largest share of nulls first; empty columns have 0% nulls
+    NullPctDesc,
+    /// The listed columns first, in the listed order, then the others;
+    /// names that aren't described are ignored
+    Names(Vec<String>),
+}
+
+impl ColumnOrder {
+    /// Whether the order depends on the computed statistics
+    pub(crate) fn needs_counts(&self) -> bool {
+        matches!(self, ColumnOrder::NullCountDesc | ColumnOrder::NullPctDesc)
+    }
+
+    /// Positions of `names` in output order
+    ///
+    /// `counts` holds the (count, null_count) of each column, in the order
+    /// of `names`; it is only read when `needs_counts`. The sort is stable,
+    /// so ties keep the schema order.
+    pub(crate) fn arrange(&self, names: &[&str], counts: &[(u64, u64)]) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..names.len()).collect();
+        let null_pct = |i: usize| {
+            let (count, null_count) = counts[i];
+            let total = count + null_count;
+            if total == 0 {
+                0.0
+            } else {
+                null_count as f64 / total as f64
+            }
+        };
+        match self {
+            ColumnOrder::SchemaOrder => {}
+            ColumnOrder::Alphabetical => order.sort_by_key(|&i| names[i]),
+            ColumnOrder::NullCountDesc => order.sort_by_key(|&i| std::cmp::Reverse(counts[i].1)),
+            ColumnOrder::NullPctDesc => {
+                order.sort_by(|&a, &b| null_pct(b).total_cmp(&null_pct(a)));
+            }
+            ColumnOrder::Names(listed) => order.sort_by_key(|&i| {
+                listed
+                    .iter()
+                    .position(|name| name == names[i])
+                    .unwrap_or(listed.len())
+            }),
+        }
+        order
+    }
+}
+
+impl TrimMode {
+    /// Apply the trimming to a string expression
+    pub(crate) fn apply(self, expr: Expr) -> Expr {
+        match self {
+            TrimMode::None => expr,
+            TrimMode::Both => expr.str().strip_chars(lit(NULL)),
+            TrimMode::Start => expr.str().strip_chars_start(lit(NULL)),
+            TrimMode::End => expr.str().strip_chars_end(lit(NULL)),
+        }
+    }
+}
+
+/// Flag aborting the describes it was passed to, see
+/// `DescribeOptions::cancel_with`
+///
+/// Clones share the flag, so keep one to cancel from another thread.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    /// A token that isn't cancelled yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Make every describe using this token stop at its next checkpoint
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether `cancel` was called on this token or one of its clones
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Polars engine executing the describe aggregations
+///
+/// Statistics that stream (count, null_count, mean, std, min, max and the
+/// count-like optional statistics) run in bounded memory on the streaming
+/// engine. Percentiles, distinct counts and the order-dependent statistics
+/// (e.g. sortedness) need all values of a column at once; Polars either
+/// evaluates them in memory or falls back for that part of the query, so
+/// for frames much larger than RAM use approximate percentiles via
+/// `describe_sampled` or leave them out with `percentiles(vec![])`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Engine {
+    /// Polars' default in-memory engine
+    #[default]
+    InMemory,
+    /// Polars' streaming engine, processing scans in batches
+    #[cfg(feature = "streaming")]
+    Streaming,
+}
+
+impl Engine {
+    /// Collect `lazy_frame` on this engine
+    pub(crate) fn collect(self, lazy_frame: LazyFrame) -> PolarsResult<DataFrame> {
+        match self {
+            Engine::InMemory => lazy_frame.collect(),
+            #[cfg(feature = "streaming")]
+            Engine::Streaming => lazy_frame.collect_with_engine(polars::prelude::Engine::Streaming),
+        }
+    }
+}
+
+/// Settings of a single column, overriding `DescribeOptions` for it
+///
+/// Unset fields fall back to the global options.
+///
+/// # Example
+/// ```rust
+/// use describe_df::{ColumnOpts, DescribeOptions};
+///
+/// let opts = DescribeOptions::new()
+///     .for_column("latency_ms", ColumnOpts::new().percentiles(vec![0.5, 0.99, 0.999]))
+///     .for_column("user_id", ColumnOpts::new().counts_only());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColumnOpts {
+    pub(crate) percentiles: Option<Vec<f64>>,
+    pub(crate) statistics: Option<Vec<Statistic>>,
+    pub(crate) counts_only: bool,
+}
+
+impl ColumnOpts {
+    /// Overrides that change nothing until a setting is added
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Percentiles of this column, replacing the global list
+    pub fn percentiles(mut self, percentiles: Vec<f64>) -> Self {
+        self.percentiles = Some(percentiles);
+        self
+    }
+
+    /// Optional statistics of this column, replacing the global ones
+    pub fn statistics(mut self, statistics: Vec<Statistic>) -> Self {
+        self.statistics = Some(statistics);
+        self
+    }
+
+    /// Only compute count and null_count; every other row is null
+    pub fn counts_only(mut self) -> Self {
+        self.counts_only = true;
+        self
+    }
+}
+
+/// Builder-style options for the describe functions
+///
+/// # Example
+/// ```rust
+/// use describe_df::DescribeOptions;
+///
+/// let opts = DescribeOptions::new()
+///     .percentiles(vec![0.1, 0.5, 0.9])
+///     .histograms(20);
+/// ```
+#[derive(Clone, Default)]
+pub struct DescribeOptions {
+    pub(crate) percentiles: Option<Vec<f64>>,
+    pub(crate) histogram_bins: Option<usize>,
+    pub(crate) precision: Option<usize>,
+    pub(crate) scientific: bool,
+    pub(crate) statistics: Vec<Statistic>,
+    pub(crate) custom_stats: Vec<CustomStat>,
+    /// Set through `for_column`, at most one entry per column
+    pub(crate) column_overrides: Vec<(String, ColumnOpts)>,
+    pub(crate) iqr_multiplier: Option<f64>,
+    pub(crate) gap_multiplier: Option<f64>,
+    pub(crate) zscore_threshold: Option<f64>,
+    pub(crate) mad_normal: bool,
+    pub(crate) trim_fraction: Option<f64>,
+    pub(crate) confidence_level: Option<f64>,
+    pub(crate) high_precision: bool,
+    pub(crate) trim_strings: TrimMode,
+    pub(crate) columns: Option<Vec<String>>,
+    pub(crate) order_by: ColumnOrder,
+    pub(crate) label_style: LabelStyle,
+    /// Set by `max_columns`/`max_columns_head_tail`: (head, tail)
+    pub(crate) column_limit: Option<(usize, usize)>,
+    pub(crate) unnest_structs: bool,
+    pub(crate) unnest_depth: Option<usize>,
+    pub(crate) dedupe_names: bool,
+    pub(crate) strict_percentiles: bool,
+    pub(crate) strict_columns: bool,
+    pub(crate) strict_dtypes: bool,
+    pub(crate) nan_is_error: bool,
+    pub(crate) strict_quantiles: bool,
+    pub(crate) strict_counts: bool,
+    pub(crate) reject_describe_output: bool,
+    pub(crate) error_on_schema_change: bool,
+    pub(crate) skip_errors: bool,
+    pub(crate) diff_abs_tolerance: f64,
+    pub(crate) diff_rel_tolerance: f64,
+    pub(crate) highlight_null_count: Option<u64>,
+    pub(crate) highlight_null_pct: Option<f64>,
+    pub(crate) color: Option<bool>,
+    pub(crate) max_cell_width: Option<usize>,
+    /// Set by `describe_weighted`
+    pub(crate) weight_col: Option<String>,
+    pub(crate) compat: Compat,
+    pub(crate) engine: Engine,
+    pub(crate) batch_columns: Option<usize>,
+    pub(crate) infer_schema_length: Option<usize>,
+    pub(crate) warning_hook: Option<WarningHook>,
+    pub(crate) progress_hook: Option<ProgressHook>,
+    pub(crate) cancel_token: Option<CancellationToken>,
+    pub(crate) timeout: Option<Duration>,
+}
+
+impl fmt::Debug for DescribeOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DescribeOptions")
+            .field("percentiles", &self.percentiles)
+            .field("histogram_bins", &self.histogram_bins)
+            .field("precision", &self.precision)
+            .field("scientific", &self.scientific)
+            .field("statistics", &self.statistics)
+            .field("custom_stats", &self.custom_stats)
+            .field("column_overrides", &self.column_overrides)
+            .field("iqr_multiplier", &self.iqr_multiplier)
+            .field("gap_multiplier", &self.gap_multiplier)
+            .field("zscore_threshold", &self.zscore_threshold)
+            .field("mad_normal", &self.mad_normal)
+            .field("trim_fraction", &self.trim_fraction)
+            .field("confidence_level", &self.confidence_level)
+            .field("high_precision", &self.high_precision)
+            .field("trim_strings", &self.trim_strings)
+            .field("columns", &self.columns)
+            .field("order_by", &self.order_by)
+            .field("label_style", &self.label_style)
+            .field("column_limit", &self.column_limit)
+            .field("unnest_structs", &self.unnest_structs)
+            .field("unnest_depth", &self.unnest_depth)
+            .field("dedupe_names", &self.dedupe_names)
+            .field("strict_percentiles", &self.strict_percentiles)
+            .field("strict_columns", &self.strict_columns)
+            .field("strict_dtypes", &self.strict_dtypes)
+            .field("nan_is_error", &self.nan_is_error)
+            .field("strict_quantiles", &self.strict_quantiles)
+            .field("strict_counts", &self.strict_counts)
+            .field("reject_describe_output", &self.reject_describe_output)
+            .field("error_on_schema_change", &self.error_on_schema_change)
+            .field("skip_errors", &self.skip_errors)
+            .field("diff_abs_tolerance", &self.diff_abs_tolerance)
+            .field("diff_rel_tolerance", &self.diff_rel_tolerance)
+            .field("highlight_null_count", &self.highlight_null_count)
+            .field("highlight_null_pct", &self.highlight_null_pct)
+            .field("color", &self.color)
+            .field("max_cell_width", &self.max_cell_width)
+            .field("weight_col", &self.weight_col)
+            .field("compat", &self.compat)
+            .field("engine", &self.engine)
+            .field("batch_columns", &self.batch_columns)
+            .field("infer_schema_length", &self.infer_schema_length)
+            .field("warning_hook", &self.warning_hook.as_ref().map(|_| "<fn>"))
+            .field(
+                "progress_hook",
+                &self.progress_hook.as_ref().map(|_| "<fn>"),
+            )
+            .field("cancel_token", &self.cancel_token)
+            .field("timeout", &self.timeout)
+            .finish()
+    }
+}
+
+impl DescribeOptions {
+    /// Options with every setting at its default
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Options carrying only a percentile list, as accepted by `describe()`
+    pub(crate) fn from_percentiles(percentiles: Option<Vec<f64>>) -> Self {
+        Self {
+            percentiles,
+            ..Self::default()
+        }
+    }
+
+    /// Percentiles to compute (values between 0.0 and 1.0)
+    ///
+    /// Defaults to [0.25, 0.50, 0.75] when not set
+    pub fn percentiles(mut self, percentiles: Vec<f64>) -> Self {
+        self.percentiles = Some(percentiles);
+        self
+    }
+
+    /// Embed an equal-width histogram with `n_bins` bins in the report
+    /// for every numeric column
+    pub fn histograms(mut self, n_bins: usize) -> Self {
+        self.histogram_bins = Some(n_bins);
+        self
+    }
+
+    /// Number of decimal places used for mean, std and percentile values
+    ///
+    /// When not set, values keep the default Polars rendering. A precision
+    /// of 0 renders values as integers without a decimal point.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Render mean, std and percentile values in scientific notation
+    /// (e.g. `3.20e-9`), combined with `precision` when set
+    pub fn scientific(mut self, scientific: bool) -> Self {
+        self.scientific = scientific;
+        self
+    }
+
+    /// Add an optional statistic row after the default rows
+    ///
+    /// Statistics appear in the order they were added; adding the same
+    /// statistic twice has no effect.
+    pub fn statistic(mut self, stat: Statistic) -> Self {
+        if !self.statistics.contains(&stat) {
+            self.statistics.push(stat);
+        }
+        self
+    }
+
+    /// Add a statistic row `name` computed by the aggregation `f` returns
+    ///
+    /// `f` receives the column expression and dtype of every described
+    /// column; return a null literal (e.g. `lit(NULL)`) for dtypes the
+    /// statistic doesn't apply to. Custom rows follow the optional
+    /// statistics, in the order they were added. Describing fails if
+    /// `name` is already taken by a built-in or another custom statistic.
+    ///
+    /// # Example
+    /// ```rust
+    /// use describe_df::DescribeOptions;
+    /// use polars::prelude::*;
+    ///
+    /// let opts = DescribeOptions::new().custom_stat("spread", |col, _| {
+    ///     col.clone().max() - col.min()
+    /// });
+    /// ```
+    pub fn custom_stat(
+        mut self,
+        name: &str,
+        f: impl Fn(Expr, &DataType) -> Expr + Send + Sync + 'static,
+    ) -> Self {
+        self.custom_stats.push(CustomStat {
+            name: name.to_string(),
+            expr: Arc::new(f),
+        });
+        self
+    }
+
+    /// Override the options of one column, see `ColumnOpts`
+    ///
+    /// The output keeps one row per statistic requested by any column;
+    /// columns that didn't request a row show null in it. Overrides apply
+    /// to the native describe layouts, not to `Compat` output. Setting
+    /// the same column again replaces its overrides.
+    pub fn for_column(mut self, name: &str, column_opts: ColumnOpts) -> Self {
+        self.column_overrides.retain(|(column, _)| column != name);
+        self.column_overrides.push((name.to_string(), column_opts));
+        self
+    }
+
+    /// Multiplier k of the IQR fences used by `Statistic::OutlierCount`
+    ///
+    /// Defaults to 1.5 (Tukey's fences); 3.0 only flags far-out values.
+    pub fn iqr_multiplier(mut self, k: f64) -> Self {
+        self.iqr_multiplier = Some(k);
+        self
+    }
+
+    /// Multiple k of the median interval above which
+    /// `Statistic::GapCount` counts an interval as a gap
+    ///
+    /// Defaults to 1.5, so a daily series counts every missing stretch of
+    /// days once.
+    pub fn gap_multiplier(mut self, k: f64) -> Self {
+        self.gap_multiplier = Some(k);
+        self
+    }
+
+    /// z-score above which `Statistic::ZScoreOutliers` counts a value
+    ///
+    /// Defaults to 3.0.
+    pub fn zscore_threshold(mut self, threshold: f64) -> Self {
+        self.zscore_threshold = Some(threshold);
+        self
+    }
+
+    /// Scale `Statistic::Mad` by 1.4826, making it a consistent estimator
+    /// of the std for normally distributed data
+    pub fn mad_normal(mut self, normal: bool) -> Self {
+        self.mad_normal = normal;
+        self
+    }
+
+    /// Add the `Statistic::TrimmedMean` row, cutting `fraction` of the
+    /// values from each tail
+    ///
+    /// `fraction` must lie in [0, 0.5); describing fails otherwise. With 0
+    /// the row equals the mean.
+    pub fn trimmed_mean(mut self, fraction: f64) -> Self {
+        self.trim_fraction = Some(fraction);
+        self.statistic(Statistic::TrimmedMean)
+    }
+
+    /// Confidence level of the `Statistic::MeanCiLow` and
+    /// `Statistic::MeanCiHigh` rows
+    ///
+    /// Defaults to 0.95. `level` must lie strictly between 0 and 1;
+    /// describing fails otherwise.
+    pub fn confidence_level(mut self, level: f64) -> Self {
+        self.confidence_level = Some(level);
+        self
+    }
+
+    /// Compute the mean and std of numeric columns in Float64 with
+    /// compensated (Kahan–Neumaier) summation
+    ///
+    /// Use it when reconciling means of huge or badly scaled float columns:
+    /// the error of the default pairwise summation scales with the sum of
+    /// magnitudes rather than with the result, and Float32 means stay
+    /// Float32. The compensated sums
+    /// run single-threaded over each materialized column, so expect those
+    /// two rows to cost several times more. Weighted describes ignore it.
+    pub fn high_precision(mut self, high_precision: bool) -> Self {
+        self.high_precision = high_precision;
+        self
+    }
+
+    /// Strip leading/trailing whitespace before computing string statistics
+    ///
+    /// Applies to min/max and distinct counts of String columns; count and
+    /// null_count always see the raw values.
+    pub fn trim_strings(mut self, mode: TrimMode) -> Self {
+        self.trim_strings = mode;
+        self
+    }
+
+    /// Only describe the named columns, in the given order
+    ///
+    /// Unknown names are skipped with a warning unless `strict_columns` is set.
+    /// The other columns are projected away before any statistic is built,
+    /// so scans never read them.
+    pub fn columns<S: Into<String>>(mut self, columns: impl IntoIterator<Item = S>) -> Self {
+        self.columns = Some(columns.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only describe the first `max` columns
+    ///
+    /// The columns left out are stood in for by an `…` output column (and
+    /// counted in `DescribeReport::skipped_columns`), and a warning names
+    /// how many were described. An explicit `columns` selection lifts the
+    /// limit.
+    pub fn max_columns(self, max: usize) -> Self {
+        self.max_columns_head_tail(max, 0)
+    }
+
+    /// Only describe the first `head` and the last `tail` columns, with the
+    /// `…` column of `max_columns` between them
+    pub fn max_columns_head_tail(mut self, head: usize, tail: usize) -> Self {
+        self.column_limit = Some((head, tail));
+        self
+    }
+
+    /// Order of the described columns in the output
+    ///
+    /// Applies to the output columns of `describe_with()` (and the layouts
+    /// rendered from it) and to the columns of `describe_report`. Ties keep
+    /// the schema order. Defaults to `ColumnOrder::SchemaOrder`.
+    pub fn order_by(mut self, order: ColumnOrder) -> Self {
+        self.order_by = order;
+        self
+    }
+
+    /// Spelling of the statistic labels
+    ///
+    /// Applies to the `statistic` column of every string layout, the
+    /// `Compat` ones included, and so to the column names of a transposed
+    /// output. `LabelStyle::Snake` spells percentiles as identifiers
+    /// (`p25`, `p99_9`) to join on or reference them in code; the other
+    /// labels are identifiers already. Custom statistics keep their names.
+    /// Typed reports are unaffected. Defaults to `LabelStyle::Human`.
+    pub fn label_style(mut self, style: LabelStyle) -> Self {
+        self.label_style = style;
+        self
+    }
+
+    /// Describe the fields of Struct columns instead of the struct itself
+    ///
+    /// Fields are named `parent.field`; nested structs are flattened
+    /// recursively, up to `unnest_depth` levels when set. When disabled,
+    /// struct columns only get count and null_count.
+    pub fn unnest_structs(mut self, unnest: bool) -> Self {
+        self.unnest_structs = unnest;
+        self
+    }
+
+    /// Maximum number of struct levels expanded by `unnest_structs`
+    ///
+    /// Structs nested deeper are described as a single column.
+    pub fn unnest_depth(mut self, depth: usize) -> Self {
+        self.unnest_depth = Some(depth);
+        self
+    }
+
+    /// Suffix repeated output column names with `_1`, `_2`, ... instead of
+    /// failing
+    ///
+    /// Names repeat when an unnested struct field (`parent.field`) matches
+    /// another column, or when a column is named `statistic`. Without this
+    /// option such frames fail with `DescribeError::DuplicateColumns`
+    /// naming the repeated columns.
+    pub fn dedupe_names(mut self, dedupe: bool) -> Self {
+        self.dedupe_names = dedupe;
+        self
+    }
+
+    /// Turn on (or off) every strict-mode check at once
+    ///
+    /// `strict(true)` is shorthand for:
+    /// - `strict_percentiles(true)`: duplicate percentiles are an error
+    ///   instead of being dropped
+    /// - `strict_columns(true)`: unknown names in `columns` are an error
+    /// - `strict_dtypes(true)`: a column that can't be fully described is
+    ///   an error
+    /// - `nan_is_error(true)`: a NaN in any float column is an error
+    /// - `strict_quantiles(true)`: percentiles out of order with min, max
+    ///   or each other are an error instead of being clamped
+    /// - `strict_counts(true)`: a column whose count + null_count isn't
+    ///   the frame height is an error
+    /// - `reject_describe_output(true)`: describing the output of
+    ///   `describe()` is an error
+    /// - `error_on_schema_change(true)`: `describe_incremental` errors when
+    ///   the schema differs from the previous report
+    /// - `skip_errors(false)`
+    ///
+    /// Each setting can still be overridden by calling its own builder
+    /// method afterwards.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict_percentiles = strict;
+        self.strict_columns = strict;
+        self.strict_dtypes = strict;
+        self.nan_is_error = strict;
+        self.strict_quantiles = strict;
+        self.strict_counts = strict;
+        self.reject_describe_output = strict;
+        self.error_on_schema_change = strict;
+        if strict {
+            self.skip_errors = false;
+        }
+        self
+    }
+
+    /// Error on duplicate percentiles instead of silently dropping them
+    pub fn strict_percentiles(mut self, strict: bool) -> Self {
+        self.strict_percentiles = strict;
+        self
+    }
+
+    /// Error on names in `columns` that aren't in the frame instead of
+    /// skipping them
+    pub fn strict_columns(mut self, strict: bool) -> Self {
+        self.strict_columns = strict;
+        self
+    }
+
+    /// Error on columns whose dtype only gets counts (nested, Binary and
+    /// unknown dtypes) instead of emitting null value statistics
+    ///
+    /// The error is a `DescribeError::UnsupportedDtype` naming the first such
+    /// column. Without it, `DescribeReport::degraded_columns` lists them.
+    pub fn strict_dtypes(mut self, strict: bool) -> Self {
+        self.strict_dtypes = strict;
+        self
+    }
+
+    /// Treat the presence of NaN in any float column as an error
+    pub fn nan_is_error(mut self, nan_is_error: bool) -> Self {
+        self.nan_is_error = nan_is_error;
+        self
+    }
+
+    /// Fail with `DescribeError::NonMonotoneQuantiles` when a percentile of
+    /// a numeric column falls below min, above max or below a lower
+    /// percentile
+    ///
+    /// Without this check such a percentile is clamped into place, with a
+    /// warning. NaNs never reach the percentiles: they are left out like
+    /// nulls, as min and max do.
+    pub fn strict_quantiles(mut self, strict: bool) -> Self {
+        self.strict_quantiles = strict;
+        self
+    }
+
+    /// Check that count + null_count equals the frame height for every
+    /// column, failing with `DescribeError::InconsistentCounts` otherwise
+    ///
+    /// The height is taken by a `len()` in the same pass as the metrics; see
+    /// `Statistic::TotalRows` to show it as a row.
+    pub fn strict_counts(mut self, strict: bool) -> Self {
+        self.strict_counts = strict;
+        self
+    }
+
+    /// Refuse to describe a frame that looks like `describe()` output (a
+    /// leading `statistic` column followed by String columns only)
+    pub fn reject_describe_output(mut self, reject: bool) -> Self {
+        self.reject_describe_output = reject;
+        self
+    }
+
+    /// Make `describe_incremental` error when columns were added, removed or
+    /// changed dtype since the previous report, instead of adapting to it
+    pub fn error_on_schema_change(mut self, error: bool) -> Self {
+        self.error_on_schema_change = error;
+        self
+    }
+
+    /// When the aggregation fails, retry column by column and leave out the
+    /// columns that fail (with a warning) instead of failing the describe
+    pub fn skip_errors(mut self, skip: bool) -> Self {
+        self.skip_errors = skip;
+        self
+    }
+
+    /// Tolerances below which `describe_diff` doesn't flag a numeric
+    /// statistic as changed
+    ///
+    /// A statistic is unchanged when `|b - a| <= abs` or
+    /// `|b - a| <= rel * |a|`. Both default to 0, so any difference counts.
+    pub fn diff_tolerance(mut self, abs: f64, rel: f64) -> Self {
+        self.diff_abs_tolerance = abs;
+        self.diff_rel_tolerance = rel;
+        self
+    }
+
+    /// Mark columns with more than `max` nulls with the `stat-high-nulls`
+    /// class in `describe_html` and in red in `describe_pretty`
+    pub fn highlight_null_count(mut self, max: u64) -> Self {
+        self.highlight_null_count = Some(max);
+        self
+    }
+
+    /// Mark columns with more than `max_pct` percent nulls with the
+    /// `stat-high-nulls` class in `describe_html` and in red in
+    /// `describe_pretty`
+    pub fn highlight_null_pct(mut self, max_pct: f64) -> Self {
+        self.highlight_null_pct = Some(max_pct);
+        self
+    }
+
+    /// Force ANSI colors in `describe_pretty` on or off
+    ///
+    /// When not set, colors are used only when stdout is a terminal and
+    /// `NO_COLOR` isn't set.
+    pub fn color(mut self, color: bool) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Widest cell of `describe_pretty`, in characters; longer values (e.g.
+    /// string mins and maxes) are cut and end in `…`. The `statistic`
+    /// column is never cut. Defaults to 32.
+    pub fn max_cell_width(mut self, width: usize) -> Self {
+        self.max_cell_width = Some(width);
+        self
+    }
+
+    /// Make `describe_with()` mimic another describe implementation
+    ///
+    /// With `Compat::PyPolars` the output matches `pl.DataFrame.describe()`
+    /// of py-polars 1.33 column for column, including its Float64 columns;
+    /// `Compat::Pandas` gives pandas' `describe(include="all")` rows. See
+    /// `Compat` for the details. `describe_lazy()` and the file scans
+    /// follow it too; the rendered outputs (`describe_html`,
+    /// `describe_markdown`, `describe_pretty`, `describe_to_csv`) and the
+    /// typed reports always use the native layout.
+    pub fn compat(mut self, compat: Compat) -> Self {
+        self.compat = compat;
+        self
+    }
+
+    /// Engine running the aggregation pass, see `Engine`
+    ///
+    /// Defaults to `Engine::InMemory`. Results are the same on both engines.
+    pub fn engine(mut self, engine: Engine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Number of columns aggregated per select
+    ///
+    /// Planning one select over the metrics of tens of thousands of columns
+    /// is slow, so wide frames are aggregated in batches of columns and the
+    /// results stitched back together. In-memory frames on
+    /// `Engine::InMemory` use batches of 256 columns, run in parallel;
+    /// other sources (scans, lazy queries) use batches of 2,000, collected
+    /// one after the other so each batch is a single read of its columns.
+    /// The output doesn't depend on the batch size; `usize::MAX` aggregates
+    /// every column in one select.
+    pub fn batch_columns(mut self, columns: usize) -> Self {
+        self.batch_columns = Some(columns.max(1));
+        self
+    }
+
+    /// Number of rows `describe_csv` and `describe_ndjson` read to infer
+    /// the column dtypes (and, for NDJSON, the keys)
+    ///
+    /// Defaults to 100. Use a larger value when a column only shows its
+    /// real dtype, or an NDJSON key only appears, further down the file.
+    pub fn infer_schema_length(mut self, rows: usize) -> Self {
+        self.infer_schema_length = Some(rows);
+        self
+    }
+
+    /// Receive non-fatal warnings (e.g. skipped columns) through `hook`
+    ///
+    /// Without a hook, warnings are dropped: the library never writes to
+    /// stdout or stderr itself.
+    pub fn on_warning(mut self, hook: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.warning_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Receive the milestones of each describe through `hook`, see
+    /// `Progress`
+    ///
+    /// A `Progress::Batch` is reported per batch of columns collected on
+    /// its own (in-memory frames with a hook, or scans wider than one
+    /// batch); for in-memory frames this means the batches are aggregated
+    /// one after the other instead of in parallel. A describe with a hook runs its aggregation
+    /// right away, so `describe_lazy` no longer defers it. A panicking
+    /// hook doesn't abort the describe: the panic is caught and reported
+    /// as a warning.
+    pub fn on_progress(mut self, hook: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+        self.progress_hook = Some(Arc::new(hook));
+        self
+    }
+
+    /// Abort the describe with `DescribeError::Cancelled` once `token` is
+    /// cancelled
+    ///
+    /// The token is checked between stages: after resolving the schema,
+    /// after building the expressions, after the aggregation and, for wide
+    /// in-memory frames, between batches of columns (which are then
+    /// aggregated one after the other). A Polars query already running
+    /// can't be interrupted, so a single huge scan only stops after it
+    /// finishes. Like a progress hook, a token makes `describe_lazy` run
+    /// right away.
+    pub fn cancel_with(mut self, token: CancellationToken) -> Self {
+        self.cancel_token = Some(token);
+        self
+    }
+
+    /// Abort the describe with `DescribeError::TimedOut` when it runs
+    /// longer than `timeout`
+    ///
+    /// Checked at the same points as `cancel_with`, so a describe can
+    /// overrun the timeout by the duration of one Polars query.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Overrides set for `col_name` with `for_column`
+    pub(crate) fn column_opts(&self, col_name: &str) -> Option<&ColumnOpts> {
+        self.column_overrides
+            .iter()
+            .find(|(column, _)| column == col_name)
+            .map(|(_, column_opts)| column_opts)
+    }
+
+    /// Optional statistics requested globally or by any column, in request
+    /// order
+    pub(crate) fn all_statistics(&self) -> Vec<Statistic> {
+        let mut statistics = self.statistics.clone();
+        let overrides = self
+            .column_overrides
+            .iter()
+            .filter_map(|(_, column_opts)| column_opts.statistics.as_ref())
+            .flatten();
+        for stat in overrides {
+            if !statistics.contains(stat) {
+                statistics.push(*stat);
+            }
+        }
+        statistics
+    }
+
+    /// Whether `col_name` gets the optional statistic `stat`
+    pub(crate) fn statistic_requested(&self, col_name: &str, stat: Statistic) -> bool {
+        match self.column_opts(col_name) {
+            Some(column_opts) if column_opts.counts_only => false,
+            Some(ColumnOpts {
+                statistics: Some(statistics),
+                ..
+            }) => statistics.contains(&stat),
+            _ => self.statistics.contains(&stat),
+        }
+    }
+
+    /// Whether `col_name` gets the percentile `p`
+    pub(crate) fn percentile_requested(&self, col_name: &str, p: f64) -> bool {
+        match self
+            .column_opts(col_name)
+            .and_then(|c| c.percentiles.as_ref())
+        {
+            Some(percentiles) => percentiles.contains(&p),
+            None => self.percentiles.as_ref().map_or_else(
+                || DEFAULT_PERCENTILES.contains(&p),
+                |percentiles| percentiles.contains(&p),
+            ),
+        }
+    }
+
+    /// Report a non-fatal warning through the configured hook, if any
+    pub(crate) fn warn(&self, message: &str) {
+        if let Some(hook) = &self.warning_hook {
+            hook(message);
+        }
+    }
+
+    /// Whether a cancellation token or a timeout is set
+    pub(crate) fn interruptible(&self) -> bool {
+        self.cancel_token.is_some() || self.timeout.is_some()
+    }
+
+    /// Fail when the token was cancelled or the describe that began at
+    /// `started` exceeded the timeout
+    pub(crate) fn check_interrupted(&self, started: Instant) -> Result<(), DescribeError> {
+        if self
+            .cancel_token
+            .as_ref()
+            .is_some_and(CancellationToken::is_cancelled)
+        {
+            return Err(DescribeError::Cancelled);
+        }
+        if self
+            .timeout
+            .is_some_and(|timeout| started.elapsed() > timeout)
+        {
+            return Err(DescribeError::TimedOut);
+        }
+        Ok(())
+    }
+
+    /// Report a milestone through the progress hook, if any
+    pub(crate) fn progress(&self, event: Progress) {
+        if let Some(hook) = &self.progress_hook
+            && catch_unwind(AssertUnwindSafe(|| hook(event))).is_err()
+        {
+            self.warn(&format!("progress hook panicked on {event:?}"));
+        }
+    }
+}
@@ -0,0 +1,370 @@
+//! Mergeable partial summaries for describing data that arrives in chunks
+//!
+//! A `PartialSummary` holds the exactly mergeable parts of a describe (count,
+//! null_count, mean, the sum of squared deviations behind std, min and max)
+//! for every column of one chunk. Summaries of different chunks merge into
+//! the summary of their concatenation without rescanning the data, and can
+//! be stored between runs with serde. Percentiles are not mergeable exactly
+//! and are not part of the summary.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::is_dtype_enabled;
+use crate::error::DescribeError;
+use crate::format::format_value;
+
+/// Mergeable statistics for every column of a frame, in schema order
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialSummary {
+    pub columns: Vec<PartialColumn>,
+}
+
+/// Mergeable statistics for a single column
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PartialColumn {
+    pub name: String,
+    /// Polars dtype of the column, as displayed by Polars
+    pub dtype: String,
+    pub count: u64,
+    pub null_count: u64,
+    /// Mean of the non-null values (numeric and boolean columns)
+    pub mean: Option<f64>,
+    /// Sum of squared deviations from the mean, from which std is derived
+    pub m2: Option<f64>,
+    pub min: Option<Extreme>,
+    pub max: Option<Extreme>,
+}
+
+/// A min or max value, kept in a form that can be compared across chunks
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Extreme {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    /// Native rendering of a value that orders lexically (strings,
+    /// categoricals, dates, datetimes and times)
+    Text(String),
+}
+
+impl Extreme {
+    fn as_f64(&self) -> Option<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        match self {
+            Extreme::Int(v) => Some(*v as f64),
+            Extreme::UInt(v) => Some(*v as f64),
+            Extreme::Float(v) => Some(*v),
+            Extreme::Text(_) => None,
+        }
+    }
+
+    /// `true` when `self` sorts before `other`; values that can't be
+    /// compared (e.g. after a dtype change) never do
+    fn is_less(&self, other: &Extreme) -> bool {
+        match (self, other) {
+            (Extreme::Int(a), Extreme::Int(b)) => a < b,
+            (Extreme::UInt(a), Extreme::UInt(b)) => a < b,
+            (Extreme::Text(a), Extreme::Text(b)) => a < b,
+            _ => match (self.as_f64(), other.as_f64()) {
+                (Some(a), Some(b)) => a < b,
+                _ => false,
+            },
+        }
+    }
+
+    fn render(&self) -> String {
+        match self {
+            Extreme::Int(v) => v.to_string(),
+            Extreme::UInt(v) => v.to_string(),
+            Extreme::Float(v) => AnyValue::Float64(*v).to_string(),
+            Extreme::Text(s) => s.clone(),
+        }
+    }
+
+    fn from_any_value(val: &AnyValue<'_>, dtype: &DataType) -> Option<Extreme> {
+        if val.is_null() {
+            return None;
+        }
+        if dtype.is_float() {
+            val.extract::<f64>().map(Extreme::Float)
+        } else if dtype.is_unsigned_integer() {
+            val.extract::<u64>().map(Extreme::UInt)
+        } else if dtype.is_integer() {
+            val.extract::<i64>().map(Extreme::Int)
+        } else {
+            Some(Extreme::Text(format_value(val)))
+        }
+    }
+}
+
+/// Whether min/max of `dtype` can be merged by comparing `Extreme` values
+///
+/// Enum values order by category and durations render humanized, so neither
+/// orders correctly as text; their min/max are left out of the summary.
+fn has_mergeable_extremes(dtype: &DataType) -> bool {
+    if !is_dtype_enabled(dtype) {
+        return false;
+    }
+    dtype.is_primitive_numeric()
+        || dtype.is_categorical()
+        || matches!(
+            dtype,
+            DataType::Boolean
+                | DataType::String
+                | DataType::Date
+                | DataType::Datetime(..)
+                | DataType::Time
+        )
+}
+
+pub(crate) fn summarize_impl(lazy_frame: &LazyFrame) -> Result<PartialSummary> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    if schema.is_empty() {
+        return Err(DescribeError::EmptySchema.into());
+    }
+
+    let mut exprs = Vec::new();
+    for (col_name, dtype) in schema.iter() {
+        let column = col(col_name.clone());
+        exprs.push(
+            column
+                .clone()
+                .count()
+                .cast(DataType::UInt64)
+                .alias(format!("count:{col_name}")),
+        );
+        exprs.push(
+            column
+                .clone()
+                .null_count()
+                .cast(DataType::UInt64)
+                .alias(format!("null_count:{col_name}")),
+        );
+        if dtype.is_primitive_numeric() || dtype == &DataType::Boolean {
+            let values = column.clone().cast(DataType::Float64);
+            exprs.push(values.clone().mean().alias(format!("mean:{col_name}")));
+            // Like describe(), std is only reported for numeric columns
+            if dtype.is_primitive_numeric() {
+                exprs.push(
+                    (values.clone().var(0) * values.count().cast(DataType::Float64))
+                        .alias(format!("m2:{col_name}")),
+                );
+            }
+        }
+        if has_mergeable_extremes(dtype) {
+            let values = if dtype.is_categorical() {
+                column.cast(DataType::String)
+            } else {
+                column
+            };
+            exprs.push(values.clone().min().alias(format!("min:{col_name}")));
+            exprs.push(values.max().alias(format!("max:{col_name}")));
+        }
+    }
+
+    let df = lazy_frame.clone().select(exprs).collect()?;
+    let value = |key: String| -> Result<Option<AnyValue<'_>>> {
+        match df.column(&key) {
+            Ok(column) => Ok(Some(column.get(0)?)),
+            Err(_) => Ok(None),
+        }
+    };
+
+    let mut columns = Vec::with_capacity(schema.len());
+    for (col_name, dtype) in schema.iter() {
+        let float = |key: String| -> Result<Option<f64>> {
+            Ok(value(key)?.and_then(|v| v.extract::<f64>()))
+        };
+        let extreme = |key: String| -> Result<Option<Extreme>> {
+            Ok(value(key)?.and_then(|v| Extreme::from_any_value(&v, dtype)))
+        };
+        columns.push(PartialColumn {
+            name: col_name.to_string(),
+            dtype: dtype.to_string(),
+            count: value(format!("count:{col_name}"))?
+                .and_then(|v| v.extract::<u64>())
+                .unwrap_or(0),
+            null_count: value(format!("null_count:{col_name}"))?
+                .and_then(|v| v.extract::<u64>())
+                .unwrap_or(0),
+            mean: float(format!("mean:{col_name}"))?,
+            m2: float(format!("m2:{col_name}"))?,
+            min: extreme(format!("min:{col_name}"))?,
+            max: extreme(format!("max:{col_name}"))?,
+        });
+    }
+
+    Ok(PartialSummary { columns })
+}
+
+impl PartialSummary {
+    /// Look up the summary of a column by name
+    pub fn column(&self, name: &str) -> Option<&PartialColumn> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Combine with the summary of another chunk
+    ///
+    /// Columns are matched by name. Columns present in only one summary are
+    /// kept as they are, in the order of `self` followed by new columns of
+    /// `other`.
+    pub fn merge(&self, other: &PartialSummary) -> PartialSummary {
+        let mut columns: Vec<PartialColumn> = self
+            .columns
+            .iter()
+            .map(|column| match other.column(&column.name) {
+                Some(other_column) => column.merge(other_column),
+                None => column.clone(),
+            })
+            .collect();
+        columns.extend(
+            other
+                .columns
+                .iter()
+                .filter(|column| self.column(&column.name).is_none())
+                .cloned(),
+        );
+        PartialSummary { columns }
+    }
+
+    /// Render the summary in the layout of `describe()`
+    ///
+    /// The percentile rows are left out since they can't be merged exactly.
+    pub fn finalize(&self) -> Result<DataFrame> {
+        let statistic = ["count", "null_count", "mean", "std", "min", "max"];
+        let mut result_columns = vec![Column::new("statistic".into(), statistic)];
+        for column in &self.columns {
+            let float = |v: Option<f64>| v.map(|v| AnyValue::Float64(v).to_string());
+            let values = [
+                Some(column.count.to_string()),
+                Some(column.null_count.to_string()),
+                float(column.mean),
+                float(column.std()),
+                column.min.as_ref().map(Extreme::render),
+                column.max.as_ref().map(Extreme::render),
+            ]
+            .map(|v| v.unwrap_or_else(|| "null".to_string()));
+            result_columns.push(Column::new(column.name.as_str().into(), values));
+        }
+        DataFrame::new(result_columns).map_err(Into::into)
+    }
+}
+
+impl PartialColumn {
+    /// Sample standard deviation (ddof = 1)
+    pub fn std(&self) -> Option<f64> {
+        #[allow(clippy::cast_precision_loss)]
+        let n = self.count as f64;
+        self.m2
+            .filter(|_| self.count > 1)
+            .map(|m2| (m2 / (n - 1.0)).sqrt())
+    }
+
+    fn merge(&self, other: &PartialColumn) -> PartialColumn {
+        let count = self.count + other.count;
+
+        // Chan et al. parallel update of the mean and sum of squares
+        let (mean, m2) = match (self.mean, self.m2, other.mean, other.m2) {
+            (Some(mean_a), m2_a, Some(mean_b), m2_b) => {
+                #[allow(clippy::cast_precision_loss)]
+                let (n_a, n_b) = (self.count as f64, other.count as f64);
+                let n = n_a + n_b;
+                let delta = mean_b - mean_a;
+                let m2 = m2_a
+                    .zip(m2_b)
+                    .map(|(m2_a, m2_b)| m2_a + m2_b + delta * delta * n_a * n_b / n);
+                (Some(mean_a + delta * n_b / n), m2)
+            }
+            (None, None, mean, m2) if self.count == 0 => (mean, m2),
+            (mean, m2, None, None) => (mean, m2),
+            _ => (None, None),
+        };
+
+        let pick =
+            |a: &Option<Extreme>, b: &Option<Extreme>, take_b: fn(&Extreme, &Extreme) -> bool| {
+                match (a, b) {
+                    (Some(a), Some(b)) if take_b(a, b) => Some(b.clone()),
+                    (Some(a), _) => Some(a.clone()),
+                    (None, b) => b.clone(),
+                }
+            };
+
+        PartialColumn {
+            name: self.name.clone(),
+            dtype: self.dtype.clone(),
+            count,
+            null_count: self.null_count + other.null_count,
+            mean,
+            m2,
+            min: pick(&self.min, &other.min, |a, b| b.is_less(a)),
+            max: pick(&self.max, &other.max, |a, b| a.is_less(b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn cell(df: &DataFrame, row: usize, column: &str) -> Result<String> {
+        Ok(df.column(column)?.str()?.get(row).unwrap().to_string())
+    }
+
+    #[test]
+    fn test_merged_halves_match_whole_frame() -> Result<()> {
+        let df = df! {
+            "ints" => [Some(4), Some(-2), None, Some(9), Some(1), None, Some(7)],
+            "floats" => [Some(1.5), None, Some(-3.25), Some(8.0), Some(2.0), Some(0.5), Some(4.0)],
+            "names" => [Some("kiwi"), Some("apple"), Some("pear"), None, Some("fig"), Some("banana"), Some("date")],
+        }?;
+
+        let first = df.slice(0, 3).summarize()?;
+        let second = df.slice(3, 4).summarize()?;
+        let merged = first.merge(&second).finalize()?;
+        let whole = df.describe(None)?;
+        // describe() rows without the percentiles
+        let whole_rows = [0, 1, 2, 3, 4, 8];
+
+        for column in ["ints", "floats", "names"] {
+            for (row, whole_row) in whole_rows.into_iter().enumerate() {
+                let merged_value = cell(&merged, row, column)?;
+                let whole_value = cell(&whole, whole_row, column)?;
+                match (merged_value.parse::<f64>(), whole_value.parse::<f64>()) {
+                    (Ok(a), Ok(b)) if row == 2 || row == 3 => {
+                        assert!((a - b).abs() < 1e-5, "{column} row {row}: {a} vs {b}");
+                    }
+                    _ => assert_eq!(merged_value, whole_value, "{column} row {row}"),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_empty_chunk() -> Result<()> {
+        let df = df! { "x" => [Some(1.0), Some(3.0), None] }?;
+        let empty = df.clear().summarize()?;
+        let summary = df.summarize()?;
+
+        let merged = empty.merge(&summary);
+        assert_eq!(merged, summary);
+        assert_eq!(merged.column("x").unwrap().mean, Some(2.0));
+
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_partial_summary_round_trips_through_json() -> Result<()> {
+        let summary = df! { "x" => [1, 2, 3], "s" => ["a", "b", "c"] }?.summarize()?;
+        let json = serde_json::to_string(&summary)?;
+        let parsed: PartialSummary = serde_json::from_str(&json)?;
+        assert_eq!(parsed, summary);
+        Ok(())
+    }
+}
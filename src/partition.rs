@@ -0,0 +1,226 @@
+//! Describe statistics per partition
+//!
+//! `describe_partitioned()` aggregates every partition with one lazy
+//! `group_by` and the whole frame with a plain select over the same plan.
+//! Both are collected together, so a scan is planned once and keeps its
+//! predicate and projection pushdown.
+//!
+//! `DescribableGroupBy` offers the same describe on a `LazyGroupBy`, so it
+//! can be chained after `group_by()` in an existing lazy pipeline.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::{
+    Metrics, column_metric_exprs, metric_rows, render_grouped_metrics, resolved_percentiles,
+    select_columns_with_gap,
+};
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+/// Partition value of the row group describing the whole frame
+pub(crate) const ALL_PARTITIONS: &str = "__all__";
+
+pub(crate) fn describe_partitioned_impl(
+    lazy_frame: &LazyFrame,
+    partition_cols: &[&str],
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    if partition_cols.is_empty() {
+        return Err(DescribeError::InvalidArgument(
+            "at least one partition column is required".to_string(),
+        )
+        .into());
+    }
+    let full_schema = lazy_frame.clone().collect_schema()?;
+    if let Some(missing) = partition_cols
+        .iter()
+        .find(|name| !full_schema.contains(name))
+    {
+        return Err(DescribeError::ColumnNotFound(missing.to_string()).into());
+    }
+
+    let (_, schema, mut gap) = select_columns_with_gap(lazy_frame, opts)?;
+    let mut described = (*schema).clone();
+    for name in partition_cols {
+        if described.index_of(name).is_some_and(|i| i < gap.head) {
+            gap.head -= 1;
+        }
+        described.shift_remove(name);
+    }
+    if described.is_empty() {
+        return Err(DescribeError::EmptySchema).context("only partition columns to describe");
+    }
+    let percentiles = resolved_percentiles(opts)?;
+
+    let exprs: Vec<Expr> = described
+        .iter()
+        .flat_map(|(name, dtype)| {
+            column_metric_exprs(col(name.clone()), name, dtype, &percentiles, opts)
+        })
+        .collect();
+    // Partition values are shown as strings so the overall row can use
+    // ALL_PARTITIONS
+    let keys: Vec<Expr> = partition_cols
+        .iter()
+        .map(|name| col(*name).cast(DataType::String))
+        .collect();
+    let per_partition = lazy_frame
+        .clone()
+        .group_by(keys)
+        .agg(exprs.clone())
+        .sort(partition_cols.to_vec(), SortMultipleOptions::default());
+    let overall = lazy_frame.clone().select(
+        partition_cols
+            .iter()
+            .map(|name| lit(ALL_PARTITIONS).alias(*name))
+            .chain(exprs)
+            .collect::<Vec<_>>(),
+    );
+    let groups = opts
+        .engine
+        .collect(concat([per_partition, overall], UnionArgs::default())?)?;
+
+    let template = Metrics {
+        schema: Arc::new(described),
+        rows: metric_rows(&percentiles, opts)?,
+        percentiles,
+        frame: DataFrame::empty(),
+        gap,
+    };
+    render_grouped_metrics(&groups, partition_cols, &template, opts)
+}
+
+/// Describe each group of a lazy `group_by()`
+///
+/// # Example
+/// ```rust
+/// use polars::prelude::*;
+/// use describe_df::{DescribableGroupBy, DescribeOptions};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let lf = df! { "k" => ["a", "b", "a"], "x" => [1, 2, 3] }?.lazy();
+/// let stats = lf.group_by(["k"]).describe(&DescribeOptions::new())?;
+/// assert_eq!(stats.get_column_names(), ["k", "statistic", "x"]);
+/// # Ok(())
+/// # }
+/// ```
+pub trait DescribableGroupBy {
+    /// The `Describable::describe_partitioned()` output of the grouped
+    /// frame, with the group-by keys as partition columns
+    ///
+    /// Keys must be plain columns; dynamic and rolling group-bys are
+    /// rejected (see `Describable::describe_over()` for time windows).
+    fn describe(self, opts: &DescribeOptions) -> Result<DataFrame>;
+}
+
+impl DescribableGroupBy for LazyGroupBy {
+    fn describe(self, opts: &DescribeOptions) -> Result<DataFrame> {
+        // The keys are private to LazyGroupBy; an empty aggregation exposes
+        // them along with the input plan
+        let DslPlan::GroupBy {
+            input,
+            keys,
+            #[cfg(feature = "temporal")]
+            options,
+            ..
+        } = self.agg(Vec::<Expr>::new()).logical_plan
+        else {
+            return Err(
+                DescribeError::Internal("group_by without a GroupBy plan".to_string()).into(),
+            );
+        };
+        // Dynamic group-bys only exist with the `temporal` feature
+        #[cfg(feature = "temporal")]
+        if options.dynamic.is_some() || options.rolling.is_some() {
+            return Err(DescribeError::InvalidArgument(
+                "dynamic and rolling group-bys can't be described; use describe_over()".to_string(),
+            )
+            .into());
+        }
+        let names = keys
+            .iter()
+            .map(|key| match key {
+                Expr::Column(name) => Ok(name.as_str()),
+                other => Err(DescribeError::InvalidArgument(format!(
+                    "group-by key {other} is not a plain column"
+                ))),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        describe_partitioned_impl(&LazyFrame::from((*input).clone()), &names, opts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_partitioned_means_and_overall_row() -> Result<()> {
+        let df = df! {
+            "date" => ["2024-01-02", "2024-01-01", "2024-01-02", "2024-01-01"],
+            "value" => [10.0, 1.0, 20.0, 3.0],
+            "name" => ["a", "b", "c", "d"],
+        }?;
+        let stats = df.lazy().describe_partitioned(&["date"], None)?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["date", "statistic", "value", "name"]
+        );
+        // Two partitions and the overall group, nine statistic rows each
+        assert_eq!(stats.height(), 27);
+
+        let value = |partition: &str, statistic: &str| -> Result<String> {
+            let row = stats
+                .clone()
+                .lazy()
+                .filter(
+                    col("date")
+                        .eq(lit(partition))
+                        .and(col("statistic").eq(lit(statistic))),
+                )
+                .collect()?;
+            Ok(row.column("value")?.str()?.get(0).unwrap().to_string())
+        };
+        assert_eq!(value("2024-01-01", "mean")?, "2.0");
+        assert_eq!(value("2024-01-02", "mean")?, "15.0");
+        assert_eq!(value(ALL_PARTITIONS, "mean")?, "8.5");
+        assert_eq!(value(ALL_PARTITIONS, "count")?, "4");
+        let order: Vec<Option<&str>> = stats
+            .column("date")?
+            .str()?
+            .into_iter()
+            .step_by(9)
+            .collect();
+        assert_eq!(
+            order,
+            [Some("2024-01-01"), Some("2024-01-02"), Some(ALL_PARTITIONS)]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_describe_matches_describe_partitioned() -> Result<()> {
+        let df = df! {
+            "k" => ["b", "a", "b", "a", "c"],
+            "x" => [1.0, 2.5, 3.0, 4.0, 8.0],
+            "s" => ["p", "q", "r", "s", "t"],
+        }?;
+        let opts = DescribeOptions::new();
+        let grouped = df.clone().lazy().group_by(["k"]).describe(&opts)?;
+        let partitioned = df.clone().lazy().describe_partitioned(&["k"], None)?;
+        assert!(grouped.equals_missing(&partitioned), "{grouped}");
+
+        let err = df
+            .lazy()
+            .group_by([col("k").str().to_uppercase()])
+            .describe(&opts)
+            .unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DescribeError>(),
+            Some(DescribeError::InvalidArgument(_))
+        ));
+        Ok(())
+    }
+}
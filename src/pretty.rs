@@ -0,0 +1,235 @@
+//! Aligned terminal table rendering of describe output
+//!
+//! The table is drawn with Unicode box characters and sized to its content.
+//! With colors on, columns above the null thresholds of
+//! `DescribeOptions::highlight_null_count`/`highlight_null_pct` get a red
+//! null_count cell and constant columns are dimmed. Colors are ANSI escapes,
+//! so they follow `NO_COLOR` and are off when stdout isn't a terminal unless
+//! `DescribeOptions::color` says otherwise.
+
+use std::io::IsTerminal;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_native_impl;
+use crate::html::exceeds_null_threshold;
+use crate::options::DescribeOptions;
+
+/// Widest cell in characters when `DescribeOptions::max_cell_width` isn't set
+const DEFAULT_MAX_CELL_WIDTH: usize = 32;
+
+const RED: &str = "\x1b[31m";
+const DIM: &str = "\x1b[2m";
+const RESET: &str = "\x1b[0m";
+
+pub(crate) fn describe_pretty_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<String> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    let stats = describe_native_impl(lazy_frame, opts)?;
+    let is_numeric = |name: &str| schema.get(name).is_some_and(DataType::is_numeric);
+    render_pretty(&stats, &is_numeric, opts)
+}
+
+/// Whether to emit ANSI colors: the option when set, otherwise only when
+/// `NO_COLOR` is unset (or empty) and stdout is a terminal
+fn use_color(opts: &DescribeOptions) -> bool {
+    opts.color.unwrap_or_else(|| {
+        std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+            && std::io::stdout().is_terminal()
+    })
+}
+
+/// A single rendered cell: its text and the color it is printed in
+struct Cell {
+    text: String,
+    color: Option<&'static str>,
+}
+
+fn render_pretty(
+    stats: &DataFrame,
+    is_numeric: &dyn Fn(&str) -> bool,
+    opts: &DescribeOptions,
+) -> Result<String> {
+    let max_width = opts.max_cell_width.unwrap_or(DEFAULT_MAX_CELL_WIDTH);
+    let color = use_color(opts);
+    let labels = stats.column("statistic")?.str()?;
+    let row_of = |label: &str| labels.iter().position(|l| l == Some(label));
+    let (count_row, null_row) = (row_of("count"), row_of("null_count"));
+    let (min_row, max_row) = (row_of("min"), row_of("max"));
+
+    // One vector of cells per table column, header first
+    let mut table: Vec<Vec<Cell>> = Vec::with_capacity(stats.width());
+    let mut right_aligned = Vec::with_capacity(stats.width());
+    for (i, column) in stats.get_columns().iter().enumerate() {
+        let values = column.str()?;
+        let value = |row: Option<usize>| row.and_then(|r| values.get(r));
+        let number = |row| value(row).and_then(|v| v.parse::<u64>().ok()).unwrap_or(0);
+
+        let (high_nulls, constant) = if i == 0 {
+            (false, false)
+        } else {
+            (
+                exceeds_null_threshold(number(count_row), number(null_row), opts),
+                value(min_row).is_some_and(|min| min != "null" && value(max_row) == Some(min)),
+            )
+        };
+        let color_of = |row: Option<usize>| {
+            if !color {
+                None
+            } else if high_nulls && row.is_some() && row == null_row {
+                Some(RED)
+            } else if constant {
+                Some(DIM)
+            } else {
+                None
+            }
+        };
+
+        // The statistic labels are never cut, so every row stays readable
+        let max_width = if i == 0 { usize::MAX } else { max_width };
+        let mut cells = vec![Cell {
+            text: truncate(column.name(), max_width),
+            color: color_of(None),
+        }];
+        for row in 0..stats.height() {
+            cells.push(Cell {
+                text: truncate(values.get(row).unwrap_or("null"), max_width),
+                color: color_of(Some(row)),
+            });
+        }
+        table.push(cells);
+        right_aligned.push(i > 0 && is_numeric(column.name()));
+    }
+
+    let widths: Vec<usize> = table
+        .iter()
+        .map(|cells| {
+            cells
+                .iter()
+                .map(|c| c.text.chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let rule = |left: &str, middle: &str, right: &str| {
+        let segments: Vec<String> = widths.iter().map(|w| "─".repeat(w + 2)).collect();
+        format!("{left}{}{right}\n", segments.join(middle))
+    };
+    let line = |row: usize| {
+        let cells: Vec<String> = table
+            .iter()
+            .zip(&widths)
+            .zip(&right_aligned)
+            .map(|((cells, &width), &right)| {
+                let cell = &cells[row];
+                let padded = if right {
+                    format!("{:>width$}", cell.text)
+                } else {
+                    format!("{:<width$}", cell.text)
+                };
+                match cell.color {
+                    Some(code) => format!("{code}{padded}{RESET}"),
+                    None => padded,
+                }
+            })
+            .collect();
+        format!("│ {} │\n", cells.join(" │ "))
+    };
+
+    let mut out = rule("┌", "┬", "┐");
+    out.push_str(&line(0));
+    out.push_str(&rule("├", "┼", "┤"));
+    for row in 1..=stats.height() {
+        out.push_str(&line(row));
+    }
+    out.push_str(&rule("└", "┴", "┘"));
+    Ok(out)
+}
+
+/// Cut `value` to at most `max_width` characters, ending in `…` when cut;
+/// newlines are shown as spaces so every cell stays on one line
+fn truncate(value: &str, max_width: usize) -> String {
+    let value = value.replace('\n', " ");
+    if value.chars().count() <= max_width {
+        return value;
+    }
+    let mut cut: String = value.chars().take(max_width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Compat;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_pretty_plain_table() -> Result<()> {
+        let df = df! {
+            "n" => [1, 3],
+            "s" => ["a", "c"],
+        }?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]).color(false);
+
+        assert_eq!(
+            df.describe_pretty(&opts)?,
+            "\
+┌────────────┬──────────┬──────┐
+│ statistic  │        n │ s    │
+├────────────┼──────────┼──────┤
+│ count      │        2 │ 2    │
+│ null_count │        0 │ 0    │
+│ mean       │      2.0 │ null │
+│ std        │ 1.414214 │ null │
+│ min        │        1 │ a    │
+│ 50%        │      2.0 │ null │
+│ max        │        3 │ c    │
+└────────────┴──────────┴──────┘
+"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_pretty_truncates_and_colors() -> Result<()> {
+        let df = df! {
+            "city" => [Some("Llanfairpwllgwyngyll"), Some("Oslo"), None],
+            "flag" => [1, 1, 1],
+        }?;
+        let opts = DescribeOptions::new().max_cell_width(8).color(false);
+        let table = df.describe_pretty(&opts)?;
+        assert!(table.contains("│ Llanfai… │"), "{table}");
+        assert!(table.contains("│ statistic  │"), "{table}");
+        assert!(table.contains("│ null_count │"), "{table}");
+        assert!(!table.contains('\x1b'));
+
+        let colored = df.describe_pretty(&opts.color(true).highlight_null_count(0))?;
+        let null_line = colored.lines().nth(4).unwrap();
+        assert!(
+            null_line.contains(&format!("{RED}1       {RESET}")),
+            "{null_line}"
+        );
+        // flag holds a single value, so its column is dimmed
+        assert!(
+            null_line.contains(&format!("{DIM}   0{RESET}")),
+            "{null_line}"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_pretty_ignores_compat() -> Result<()> {
+        let df = df! { "x" => [1.5, 2.5], "s" => ["a", "b"], "b" => [true, false] }?;
+        let opts = DescribeOptions::new().color(false);
+        let native = df.describe_pretty(&opts)?;
+        for compat in [Compat::PyPolars, Compat::Pandas] {
+            let table = df.describe_pretty(&opts.clone().compat(compat))?;
+            assert_eq!(table, native, "{compat:?}");
+        }
+        Ok(())
+    }
+}
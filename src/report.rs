@@ -0,0 +1,716 @@
+//! Structured describe output
+//!
+//! `DescribeReport` carries the same statistics as the string DataFrame
+//! returned by `describe()`, but keeps them typed so they can be serialized
+//! or inspected without parsing formatted values.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::percentile_label;
+use crate::diff::{StatDiff, report_diffs};
+use crate::format::format_value;
+use crate::histogram::Histogram;
+
+/// Summary statistics for every described column, in schema order
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DescribeReport {
+    pub columns: Vec<ColumnSummary>,
+    /// Number of columns left out by `DescribeOptions::max_columns`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub skipped_columns: usize,
+}
+
+/// Summary statistics for a single column
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnSummary {
+    pub name: String,
+    /// Polars dtype of the column, as displayed by Polars
+    pub dtype: String,
+    pub count: u64,
+    pub null_count: u64,
+    pub mean: Option<f64>,
+    pub std: Option<f64>,
+    pub min: Option<StatValue>,
+    pub max: Option<StatValue>,
+    /// Percentile values keyed by their statistic label (e.g. "25%")
+    pub percentiles: BTreeMap<String, f64>,
+    /// Equal-width histogram, only present when requested via
+    /// `DescribeOptions::histograms` and only for numeric columns
+    pub histogram: Option<Histogram>,
+    /// Set when the statistics were copied from a previous report by
+    /// `describe_incremental` instead of being recomputed
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub carried_forward: bool,
+    /// Set when the dtype only gets counts (nested, Binary and unknown
+    /// dtypes), so the value statistics are null whatever the data; see
+    /// `DescribeOptions::strict_dtypes`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub degraded: bool,
+    /// In-memory size in bytes, only present when requested via
+    /// `Statistic::EstimatedSize`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub estimated_size: Option<u64>,
+    /// Digest of the column's Parquet row-group statistics, only present
+    /// in reports of `describe_incremental_parquet`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_digest: Option<u64>,
+}
+
+/// A min/max value tagged with its kind, so consumers of the serialized
+/// report don't have to guess the type from a string
+///
+/// Temporal and decimal values hold their native rendering, e.g. ISO-8601
+/// dates (`2024-01-31`) or decimals with their scale (`3.50`). With serde,
+/// values serialize as `{"type": "date", "value": "2024-01-31"}`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(tag = "type", content = "value", rename_all = "snake_case")
+)]
+pub enum StatValue {
+    Int(i64),
+    UInt(u64),
+    Float(f64),
+    Bool(bool),
+    Str(String),
+    Decimal(String),
+    Date(String),
+    Datetime(String),
+    Time(String),
+    Duration(String),
+}
+
+impl StatValue {
+    /// Tag an aggregation result; nulls have no value
+    pub(crate) fn from_any_value(val: &AnyValue<'_>) -> Option<StatValue> {
+        let value = match val {
+            AnyValue::Null => return None,
+            AnyValue::Int8(v) => StatValue::Int(i64::from(*v)),
+            AnyValue::Int16(v) => StatValue::Int(i64::from(*v)),
+            AnyValue::Int32(v) => StatValue::Int(i64::from(*v)),
+            AnyValue::Int64(v) => StatValue::Int(*v),
+            AnyValue::UInt8(v) => StatValue::UInt(u64::from(*v)),
+            AnyValue::UInt16(v) => StatValue::UInt(u64::from(*v)),
+            AnyValue::UInt32(v) => StatValue::UInt(u64::from(*v)),
+            AnyValue::UInt64(v) => StatValue::UInt(*v),
+            AnyValue::Float32(v) => StatValue::Float(f64::from(*v)),
+            AnyValue::Float64(v) => StatValue::Float(*v),
+            AnyValue::Boolean(v) => StatValue::Bool(*v),
+            #[cfg(feature = "decimal")]
+            AnyValue::Decimal(..) => StatValue::Decimal(format_value(val)),
+            #[cfg(feature = "temporal")]
+            AnyValue::Date(_) => StatValue::Date(format_value(val)),
+            #[cfg(feature = "temporal")]
+            AnyValue::Datetime(..) | AnyValue::DatetimeOwned(..) => {
+                StatValue::Datetime(format_value(val))
+            }
+            #[cfg(feature = "temporal")]
+            AnyValue::Time(_) => StatValue::Time(format_value(val)),
+            #[cfg(feature = "temporal")]
+            AnyValue::Duration(..) => StatValue::Duration(format_value(val)),
+            _ => StatValue::Str(format_value(val)),
+        };
+        Some(value)
+    }
+
+    /// The value as a number, for integers, floats and booleans
+    ///
+    /// Integers beyond ±2^53 are rounded to the nearest float; `Display`
+    /// renders them with every digit.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            StatValue::Int(v) => Some(*v as f64),
+            StatValue::UInt(v) => Some(*v as f64),
+            StatValue::Float(v) => Some(*v),
+            StatValue::Bool(v) => Some(f64::from(u8::from(*v))),
+            _ => None,
+        }
+    }
+
+    /// The value of an integer, without the rounding of `as_f64()`
+    pub(crate) fn as_i128(&self) -> Option<i128> {
+        match self {
+            StatValue::Int(v) => Some(i128::from(*v)),
+            StatValue::UInt(v) => Some(i128::from(*v)),
+            _ => None,
+        }
+    }
+
+    /// `as_f64()`, or `None` for integers beyond ±2^53 that a float can't
+    /// hold exactly (e.g. hashes or snowflake IDs in a UInt64 column)
+    fn as_exact_f64(&self) -> Option<f64> {
+        const MAX_EXACT: i128 = 1 << f64::MANTISSA_DIGITS;
+        match self.as_i128() {
+            Some(v) if v.abs() > MAX_EXACT => None,
+            _ => self.as_f64(),
+        }
+    }
+}
+
+/// The same rendering as the min/max cells of `describe()`
+impl fmt::Display for StatValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StatValue::Int(v) => write!(f, "{v}"),
+            StatValue::UInt(v) => write!(f, "{v}"),
+            StatValue::Float(v) => write!(f, "{}", AnyValue::Float64(*v)),
+            StatValue::Bool(v) => write!(f, "{v}"),
+            StatValue::Str(s)
+            | StatValue::Decimal(s)
+            | StatValue::Date(s)
+            | StatValue::Datetime(s)
+            | StatValue::Time(s)
+            | StatValue::Duration(s) => f.write_str(s),
+        }
+    }
+}
+
+impl DescribeReport {
+    /// Look up the summary of a column by name
+    pub fn column(&self, name: &str) -> Option<&ColumnSummary> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+
+    /// Names of the columns whose dtype only got counts, in schema order
+    pub fn degraded_columns(&self) -> Vec<&str> {
+        self.columns
+            .iter()
+            .filter(|c| c.degraded)
+            .map(|c| c.name.as_str())
+            .collect()
+    }
+
+    /// Render the report in the string layout returned by `describe()`
+    ///
+    /// Percentile rows are the union of the percentiles of all columns,
+    /// ordered by percentile; columns without a value show `null`.
+    pub fn to_dataframe(&self) -> Result<DataFrame> {
+        let pct_labels = self.percentile_labels();
+
+        let mut statistic: Vec<String> = ["count", "null_count", "mean", "std", "min"]
+            .iter()
+            .map(|s| (*s).to_string())
+            .collect();
+        statistic.extend(pct_labels.iter().cloned());
+        statistic.push("max".to_string());
+
+        let mut result_columns = vec![Column::new("statistic".into(), statistic)];
+        for summary in &self.columns {
+            let mut values = vec![
+                summary.count.to_string(),
+                summary.null_count.to_string(),
+                float_cell(summary.mean),
+                float_cell(summary.std),
+                value_cell(summary.min.as_ref()),
+            ];
+            values.extend(
+                pct_labels
+                    .iter()
+                    .map(|label| float_cell(summary.percentiles.get(label).copied())),
+            );
+            values.push(value_cell(summary.max.as_ref()));
+            result_columns.push(Column::new(summary.name.as_str().into(), values));
+        }
+
+        DataFrame::new(result_columns).map_err(Into::into)
+    }
+
+    /// Typed layout with one row per described column
+    ///
+    /// Columns: `column`, `dtype`, `count` and `null_count` (UInt64), `mean`
+    /// and `std` (Float64), `min` (String), one Float64 column per
+    /// percentile (e.g. `25%`, ordered by percentile) and `max` (String).
+    /// Unlike `to_dataframe()`, numeric statistics keep their numeric type
+    /// so snapshots can be compared numerically.
+    pub fn to_typed_dataframe(&self) -> Result<DataFrame> {
+        let labels = self.percentile_labels();
+        let summaries = &self.columns;
+
+        let count =
+            |f: fn(&ColumnSummary) -> u64| -> Vec<u64> { summaries.iter().map(f).collect() };
+        let text = |f: fn(&ColumnSummary) -> &Option<StatValue>| -> Vec<Option<String>> {
+            summaries
+                .iter()
+                .map(|c| f(c).as_ref().map(ToString::to_string))
+                .collect()
+        };
+
+        let mut columns = vec![
+            Column::new(
+                "column".into(),
+                summaries
+                    .iter()
+                    .map(|c| c.name.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "dtype".into(),
+                summaries
+                    .iter()
+                    .map(|c| c.dtype.as_str())
+                    .collect::<Vec<_>>(),
+            ),
+            Column::new("count".into(), count(|c| c.count)),
+            Column::new("null_count".into(), count(|c| c.null_count)),
+            Column::new(
+                "mean".into(),
+                summaries.iter().map(|c| c.mean).collect::<Vec<_>>(),
+            ),
+            Column::new(
+                "std".into(),
+                summaries.iter().map(|c| c.std).collect::<Vec<_>>(),
+            ),
+            Column::new("min".into(), text(|c| &c.min)),
+        ];
+        for label in &labels {
+            let values: Vec<Option<f64>> = summaries
+                .iter()
+                .map(|c| c.percentiles.get(label).copied())
+                .collect();
+            columns.push(Column::new(label.as_str().into(), values));
+        }
+        columns.push(Column::new("max".into(), text(|c| &c.max)));
+
+        DataFrame::new(columns).map_err(Into::into)
+    }
+
+    /// Numeric statistics as plain maps: column name -> statistic label ->
+    /// value
+    ///
+    /// Every column has `count`, `null_count`, `mean`, `std` and its
+    /// percentiles (e.g. `25%`); `min` and `max` are only included when
+    /// they are numbers a float holds exactly (booleans count as 0 and 1).
+    /// Other min/max, such as those of string or temporal columns or
+    /// integers beyond ±2^53, are in `to_text_map()`.
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_nested_map(&self) -> HashMap<String, HashMap<String, Option<f64>>> {
+        self.columns
+            .iter()
+            .map(|summary| {
+                let mut stats = HashMap::new();
+                stats.insert("count".to_string(), Some(summary.count as f64));
+                stats.insert("null_count".to_string(), Some(summary.null_count as f64));
+                stats.insert("mean".to_string(), summary.mean);
+                stats.insert("std".to_string(), summary.std);
+                for (label, value) in &summary.percentiles {
+                    stats.insert(label.clone(), Some(*value));
+                }
+                for (label, value) in [("min", &summary.min), ("max", &summary.max)] {
+                    // Text values and integers a float would round go to
+                    // `to_text_map()` instead
+                    if value.as_ref().is_none_or(|v| v.as_exact_f64().is_some()) {
+                        stats.insert(
+                            label.to_string(),
+                            value.as_ref().and_then(StatValue::as_f64),
+                        );
+                    }
+                }
+                (summary.name.clone(), stats)
+            })
+            .collect()
+    }
+
+    /// The min/max values left out of `to_nested_map()`: column name ->
+    /// `min`/`max` -> rendered value, for the columns that have any
+    pub fn to_text_map(&self) -> HashMap<String, HashMap<String, String>> {
+        self.columns
+            .iter()
+            .filter_map(|summary| {
+                let stats: HashMap<String, String> = [("min", &summary.min), ("max", &summary.max)]
+                    .into_iter()
+                    .filter_map(|(label, value)| {
+                        let value = value.as_ref()?;
+                        value
+                            .as_exact_f64()
+                            .is_none()
+                            .then(|| (label.to_string(), value.to_string()))
+                    })
+                    .collect();
+                (!stats.is_empty()).then(|| (summary.name.clone(), stats))
+            })
+            .collect()
+    }
+
+    /// The report as a `serde_json::Value`, in its serde layout
+    ///
+    /// Deserializes back into an equal report. For a flat object keyed by
+    /// column name, see `Describable::describe_json()`.
+    #[cfg(feature = "serde")]
+    pub fn to_json_value(&self) -> Result<serde_json::Value> {
+        serde_json::to_value(self).map_err(Into::into)
+    }
+
+    /// Whether `other` has the same columns and statistics, with numeric
+    /// statistics allowed to differ by up to `abs_tol`, or `rel_tol`
+    /// relative to the value in `self`
+    ///
+    /// Text statistics (dtype, non-numeric min/max) must be equal. Both
+    /// bounds are inclusive.
+    pub fn approx_eq(&self, other: &DescribeReport, rel_tol: f64, abs_tol: f64) -> bool {
+        report_diffs(self, other, abs_tol, rel_tol).is_empty()
+    }
+
+    /// The statistics that differ between `self` and `other`, exactly
+    ///
+    /// Shared columns come first, in the order of `self`, each listing its
+    /// changed statistics in describe order; columns present in one report
+    /// only list every statistic. `describe_diff()` gives the same
+    /// comparison as a DataFrame, with tolerances.
+    pub fn diff(&self, other: &DescribeReport) -> Vec<StatDiff> {
+        report_diffs(self, other, 0.0, 0.0)
+    }
+
+    /// Percentile labels of all columns, ordered by percentile
+    pub(crate) fn percentile_labels(&self) -> Vec<String> {
+        let mut labels: Vec<String> = self
+            .columns
+            .iter()
+            .flat_map(|c| c.percentiles.keys().cloned())
+            .collect();
+        labels.sort_by(|a, b| label_percent(a).total_cmp(&label_percent(b)));
+        labels.dedup();
+        labels
+    }
+}
+
+/// The table of `to_dataframe()`, as printed by Polars
+impl fmt::Display for DescribeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table = self.to_dataframe().map_err(|_| fmt::Error)?;
+        write!(f, "{table}")
+    }
+}
+
+impl ColumnSummary {
+    /// Number of non-null values
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Number of null values
+    pub fn null_count(&self) -> u64 {
+        self.null_count
+    }
+
+    /// Mean of a numeric or boolean column
+    pub fn mean(&self) -> Option<f64> {
+        self.mean
+    }
+
+    /// Sample standard deviation of a numeric or boolean column
+    pub fn std(&self) -> Option<f64> {
+        self.std
+    }
+
+    /// Smallest non-null value, typed after the column dtype
+    pub fn min(&self) -> Option<&StatValue> {
+        self.min.as_ref()
+    }
+
+    /// Largest value, like `min`
+    pub fn max(&self) -> Option<&StatValue> {
+        self.max.as_ref()
+    }
+
+    /// Value of the percentile `p` (between 0 and 1), if it was computed
+    ///
+    /// Percentiles are stored by their statistic label (e.g. "99.9%").
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        self.percentiles.get(&percentile_label(p)).copied()
+    }
+}
+
+/// Numeric value of a percentile label such as "25%", for ordering rows
+pub(crate) fn label_percent(label: &str) -> f64 {
+    label.trim_end_matches('%').parse().unwrap_or(f64::MAX)
+}
+
+/// Float cell rendered the way Polars displays floats in `describe()`
+fn float_cell(value: Option<f64>) -> String {
+    value.map_or_else(|| "null".to_string(), |v| AnyValue::Float64(v).to_string())
+}
+
+fn value_cell(value: Option<&StatValue>) -> String {
+    value.map_or_else(|| "null".to_string(), ToString::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Describable, DescribeOptions};
+
+    #[test]
+    fn test_report_to_dataframe_matches_describe() -> Result<()> {
+        let df = df! {
+            "ints" => [Some(3), None, Some(-1), Some(8)],
+            "floats" => [0.5, 2.25, -1.0, 4.0],
+            "names" => ["pear", "apple", "fig", "kiwi"],
+            "flags" => [true, false, true, true],
+        }?;
+        let report = df.describe_report(&DescribeOptions::new())?;
+
+        assert_eq!(report.column("ints").unwrap().min, Some(StatValue::Int(-1)));
+        assert_eq!(
+            report.column("names").unwrap().max,
+            Some(StatValue::Str("pear".to_string()))
+        );
+        assert!(report.to_dataframe()?.equals_missing(&df.describe(None)?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_large_integers_keep_every_digit() -> Result<()> {
+        let df = df! {
+            "id" => [Some(u64::MAX - 1), None, Some(3)],
+            "signed" => [Some(i64::MIN + 1), Some(7), None],
+        }?;
+        let opts = DescribeOptions::new().precision(2);
+        let stats = df.describe_with(&opts)?;
+        assert_eq!(
+            stats.column("id")?.str()?.get(8),
+            Some("18446744073709551614")
+        );
+        assert_eq!(
+            stats.column("signed")?.str()?.get(4),
+            Some("-9223372036854775807")
+        );
+
+        let report = df.describe_report(&opts)?;
+        let id = report.column("id").unwrap();
+        assert_eq!(id.max, Some(StatValue::UInt(u64::MAX - 1)));
+        assert!(report.to_dataframe()?.equals_missing(&df.describe(None)?));
+        let typed = report.to_typed_dataframe()?;
+        assert_eq!(
+            typed.column("max")?.str()?.get(0),
+            Some("18446744073709551614")
+        );
+
+        // A float would round the max to 2^64, so it is only in the text map
+        let numbers = report.to_nested_map();
+        assert_eq!(numbers["id"].get("min"), Some(&Some(3.0)));
+        assert_eq!(numbers["id"].get("max"), None);
+        let text = report.to_text_map();
+        assert_eq!(text["id"]["max"], "18446744073709551614");
+        assert_eq!(text["signed"]["min"], "-9223372036854775807");
+        assert_eq!(text["signed"].get("max"), None);
+
+        #[cfg(feature = "serde")]
+        {
+            let json = df.describe_json(None, false)?;
+            assert!(json.contains(r#""max":18446744073709551614"#), "{json}");
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_map_getters() -> Result<()> {
+        let df = df! {
+            "price" => [Some(2.0), Some(4.0), Some(9.0), None],
+            "city" => [Some("Oslo"), Some("Bergen"), None, Some("Tromsø")],
+        }?;
+        let summaries = df.describe_map(&DescribeOptions::new())?;
+
+        let price = &summaries["price"];
+        assert_eq!(price.count(), 3);
+        assert_eq!(price.null_count(), 1);
+        assert_eq!(price.mean(), Some(5.0));
+        assert_eq!(price.percentile(0.5), Some(4.0));
+        assert_eq!(price.percentile(0.1), None);
+
+        let Some(StatValue::Str(min)) = summaries["city"].min() else {
+            panic!("expected a string min, got {:?}", summaries["city"].min());
+        };
+        assert_eq!(min, "Bergen");
+        assert_eq!(summaries["city"].mean(), None);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_report_json_round_trip_with_tagged_values() -> Result<()> {
+        let df = df! {
+            "x" => [1.5, 2.5],
+            "s" => ["a", "b"],
+            "day" => [19_000, 19_001],
+        }?
+        .lazy()
+        .with_column(col("day").cast(DataType::Date))
+        .collect()?;
+        let report = df.describe_report(&DescribeOptions::new())?;
+
+        let json: serde_json::Value = serde_json::to_value(&report)?;
+        let day = &json["columns"][2];
+        assert_eq!(day["min"]["type"], "date");
+        assert_eq!(day["min"]["value"], "2022-01-08");
+        assert_eq!(json["columns"][1]["max"]["type"], "str");
+        assert_eq!(json["columns"][0]["min"]["value"], 1.5);
+
+        let parsed: DescribeReport = serde_json::from_value(json)?;
+        assert_eq!(parsed, report);
+        assert!(
+            parsed
+                .to_dataframe()?
+                .equals_missing(&report.to_dataframe()?)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_nested_maps_split_numbers_and_text() -> Result<()> {
+        let df = df! {
+            "ints" => [Some(3), None, Some(-1)],
+            "names" => ["pear", "apple", "fig"],
+            "flags" => [true, false, true],
+        }?;
+        let report = df.describe_report(&DescribeOptions::new())?;
+        let numbers = report.to_nested_map();
+        let text = report.to_text_map();
+
+        let ints = &numbers["ints"];
+        assert_eq!(ints["count"], Some(2.0));
+        assert_eq!(ints["null_count"], Some(1.0));
+        assert_eq!(ints["mean"], Some(1.0));
+        assert_eq!(ints["min"], Some(-1.0));
+        assert_eq!(ints["50%"], report.column("ints").unwrap().percentile(0.5));
+        assert_eq!(numbers["flags"]["max"], Some(1.0));
+
+        // Text min/max only live in the text map
+        let names = &numbers["names"];
+        assert_eq!(names["count"], Some(3.0));
+        assert_eq!(names["mean"], None);
+        assert!(!names.contains_key("min") && !names.contains_key("max"));
+        assert_eq!(text["names"]["min"], "apple");
+        assert_eq!(text["names"]["max"], "pear");
+        assert_eq!(text.len(), 1);
+        Ok(())
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_report_json_value_round_trip() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.5), None, Some(4.0)],
+            "s" => ["a", "b", "c"],
+            "day" => [19_000, 19_001, 19_002],
+        }?
+        .lazy()
+        .with_column(col("day").cast(DataType::Date))
+        .collect()?;
+        let report = df.describe_report(&DescribeOptions::new())?;
+
+        let value = report.to_json_value()?;
+        assert_eq!(value["columns"][0]["mean"], 2.75);
+        let parsed: DescribeReport = serde_json::from_value(value)?;
+        assert_eq!(parsed, report);
+        assert_eq!(parsed.to_nested_map(), report.to_nested_map());
+        assert_eq!(parsed.to_text_map()["day"]["min"], "2022-01-08");
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_display_snapshot() -> Result<()> {
+        let df = df! { "x" => [Some(1.0), None, Some(3.0)], "s" => ["b", "a", "c"] }?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let report = df.describe_report(&opts)?;
+        let expected = "\
+shape: (7, 3)
+┌────────────┬──────────┬──────┐
+│ statistic  ┆ x        ┆ s    │
+│ ---        ┆ ---      ┆ ---  │
+│ str        ┆ str      ┆ str  │
+╞════════════╪══════════╪══════╡
+│ count      ┆ 2        ┆ 3    │
+│ null_count ┆ 1        ┆ 0    │
+│ mean       ┆ 2.0      ┆ null │
+│ std        ┆ 1.414214 ┆ null │
+│ min        ┆ 1.0      ┆ a    │
+│ 50%        ┆ 2.0      ┆ null │
+│ max        ┆ 3.0      ┆ c    │
+└────────────┴──────────┴──────┘";
+        assert_eq!(report.to_string(), expected);
+        assert_eq!(report.to_string(), df.describe_with(&opts)?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_approx_eq_boundaries() -> Result<()> {
+        let opts = DescribeOptions::new().percentiles(vec![]);
+        let a = df! { "x" => [1.0, 3.0] }?.describe_report(&opts)?;
+        // Shifts the mean and the extremes by 0.5, keeping the std
+        let b = df! { "x" => [1.5, 3.5] }?.describe_report(&opts)?;
+
+        assert!(a.approx_eq(&a, 0.0, 0.0));
+        assert!(a.approx_eq(&b, 0.0, 0.5));
+        assert!(!a.approx_eq(&b, 0.0, 0.49));
+        // Relative to the mean of 2.0 and to the min of 1.0
+        assert!(a.approx_eq(&b, 0.5, 0.0));
+        assert!(!a.approx_eq(&b, 0.49, 0.0));
+        // min/max are compared as text
+        let s = df! { "x" => ["a", "b"] }?.describe_report(&opts)?;
+        assert!(!a.approx_eq(&s, 1.0, 1.0));
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_diff_lists_changed_stats() -> Result<()> {
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let a = df! {
+            "x" => [Some(1.0), Some(2.0), Some(3.0)],
+            "s" => ["a", "b", "c"],
+            "old" => [1, 2, 3],
+        }?
+        .describe_report(&opts)?;
+        let b = df! {
+            "x" => [Some(1.0), None, Some(3.0)],
+            "s" => ["a", "b", "c"],
+        }?
+        .describe_report(&opts)?;
+
+        assert!(a.diff(&a).is_empty());
+        let diffs = a.diff(&b);
+        let changed: Vec<(&str, &str)> = diffs
+            .iter()
+            .map(|d| (d.column.as_str(), d.statistic.as_str()))
+            .collect();
+        // Dropping the middle value keeps the mean, min, median and max
+        assert_eq!(
+            changed[..3],
+            [("x", "count"), ("x", "null_count"), ("x", "std")]
+        );
+        assert_eq!(diffs[0].delta, Some(-1.0));
+        assert!(changed[3..].iter().all(|(column, _)| *column == "old"));
+        assert!(diffs[3..].iter().all(|d| d.b.is_none()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_report_diff_is_reflexive_with_nans() -> Result<()> {
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let report = df! { "x" => [1.0, f64::NAN, 3.0], "n" => [f64::NAN, f64::NAN, f64::NAN] }?
+            .describe_report(&opts)?;
+        assert!(report.column("x").unwrap().mean.is_some_and(f64::is_nan));
+
+        assert!(report.diff(&report).is_empty());
+        assert!(report.approx_eq(&report, 0.0, 0.0));
+        // A NaN against a number is still a change
+        let other = df! { "x" => [1.0, 2.0, 3.0], "n" => [f64::NAN, f64::NAN, f64::NAN] }?
+            .describe_report(&opts)?;
+        assert!(
+            report
+                .diff(&other)
+                .iter()
+                .any(|d| d.column == "x" && d.statistic == "mean")
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,572 @@
+//! Deterministic synthetic frames for examples, tests and benchmarks
+//!
+//! `taxi_like(n_rows)` builds a taxi-trip shaped frame mixing strings,
+//! integers, floats, booleans and (with the `temporal` feature) timestamps.
+//! `TaxiLike` sets the share of nulls and the seed; the same settings always
+//! give the same frame, on every platform.
+//!
+//! `numeric_frame()`, `mixed_frame()` and `skewed_frame()` draw from
+//! distributions with known moments, documented on each function, so code
+//! consuming describe output can be tested against expected values. Sample
+//! statistics approach them as the number of rows grows.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::error::DescribeError;
+
+/// Null rate of `taxi_like()`
+const DEFAULT_NULL_RATE: f64 = 0.05;
+
+/// Seed of `taxi_like()`
+const DEFAULT_SEED: u64 = 42;
+
+const VENDORS: [&str; 3] = ["CMT", "VTS", "DDS"];
+
+/// Payment types with their weights out of 100
+const PAYMENT_TYPES: [(&str, u64); 4] =
+    [("card", 70), ("cash", 25), ("no_charge", 3), ("dispute", 2)];
+
+/// `n_rows` taxi trips with 5% nulls, see `TaxiLike`
+///
+/// # Example
+/// ```rust
+/// use describe_df::Describable;
+/// use describe_df::samples::taxi_like;
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let trips = taxi_like(1_000)?;
+/// assert_eq!(trips.height(), 1_000);
+/// println!("{}", trips.describe(None)?);
+/// # Ok(())
+/// # }
+/// ```
+pub fn taxi_like(n_rows: usize) -> Result<DataFrame> {
+    TaxiLike::new(n_rows).build()
+}
+
+/// Settings of a synthetic taxi-trip frame
+///
+/// Columns, in order:
+/// - `vendor` (String): one of three vendor codes, never null
+/// - `pickup_at` (Datetime in microseconds, `temporal` feature only): trips
+///   from 2024-01-01 onwards, a few minutes apart, in order
+/// - `passenger_count` (Int32): 1 to 6, mostly 1
+/// - `trip_distance` (Float64): miles, skewed towards short trips
+/// - `fare_amount` (Float64): a base fare plus a per-mile rate, with noise
+/// - `payment_type` (String): `card`, `cash`, `no_charge` or `dispute`
+/// - `store_and_fwd` (Boolean): rarely true, never null
+///
+/// Every column but `vendor`, `pickup_at` and `store_and_fwd` is null with
+/// probability `null_rate`, independently per cell.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TaxiLike {
+    n_rows: usize,
+    null_rate: f64,
+    seed: u64,
+}
+
+impl TaxiLike {
+    /// `n_rows` trips with 5% nulls and a fixed seed
+    pub fn new(n_rows: usize) -> Self {
+        Self {
+            n_rows,
+            null_rate: DEFAULT_NULL_RATE,
+            seed: DEFAULT_SEED,
+        }
+    }
+
+    /// Probability in [0, 1] that a cell of a nullable column is null
+    pub fn null_rate(mut self, null_rate: f64) -> Self {
+        self.null_rate = null_rate;
+        self
+    }
+
+    /// Seed of the generator; other seeds give other, equally shaped data
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Generate the frame
+    pub fn build(&self) -> Result<DataFrame> {
+        if !(0.0..=1.0).contains(&self.null_rate) {
+            return Err(DescribeError::InvalidArgument(format!(
+                "null rate {} is outside the range [0, 1]",
+                self.null_rate
+            ))
+            .into());
+        }
+
+        let mut rng = SplitMix64(self.seed);
+        let n = self.n_rows;
+        let mut vendor = Vec::with_capacity(n);
+        let mut pickup_at = Vec::with_capacity(n);
+        let mut passenger_count = Vec::with_capacity(n);
+        let mut trip_distance = Vec::with_capacity(n);
+        let mut fare_amount = Vec::with_capacity(n);
+        let mut payment_type = Vec::with_capacity(n);
+        let mut store_and_fwd = Vec::with_capacity(n);
+
+        // 2024-01-01T00:00:00 in microseconds
+        let mut micros: i64 = 1_704_067_200_000_000;
+        for _ in 0..n {
+            vendor.push(VENDORS[rng.below(VENDORS.len() as u64) as usize]);
+            micros += 1_000_000 * (30 + rng.below(600) as i64);
+            pickup_at.push(micros);
+
+            let passengers = match rng.below(100) {
+                0..70 => 1,
+                70..85 => 2,
+                85..92 => 3,
+                92..96 => 4,
+                96..98 => 5,
+                _ => 6,
+            };
+            // Exponential with a mean of 3 miles, to the hundredth
+            let distance = (3.0 * rng.exponential() * 100.0).round() / 100.0;
+            let fare = ((3.0 + 2.5 * distance + rng.next_f64()) * 100.0).round() / 100.0;
+            let mut weight = rng.below(100);
+            let payment = PAYMENT_TYPES
+                .iter()
+                .find(|(_, share)| {
+                    let hit = weight < *share;
+                    weight = weight.saturating_sub(*share);
+                    hit
+                })
+                .map_or("card", |(name, _)| *name);
+
+            passenger_count.push(rng.keep(self.null_rate, passengers));
+            trip_distance.push(rng.keep(self.null_rate, distance));
+            fare_amount.push(rng.keep(self.null_rate, fare));
+            payment_type.push(rng.keep(self.null_rate, payment));
+            store_and_fwd.push(rng.below(50) == 0);
+        }
+
+        let mut columns = vec![Column::new("vendor".into(), vendor)];
+        #[cfg(feature = "temporal")]
+        columns.push(
+            Column::new("pickup_at".into(), pickup_at)
+                .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+        );
+        #[cfg(not(feature = "temporal"))]
+        drop(pickup_at);
+        columns.extend([
+            Column::new("passenger_count".into(), passenger_count),
+            Column::new("trip_distance".into(), trip_distance),
+            Column::new("fare_amount".into(), fare_amount),
+            Column::new("payment_type".into(), payment_type),
+            Column::new("store_and_fwd".into(), store_and_fwd),
+        ]);
+        DataFrame::new(columns).map_err(Into::into)
+    }
+}
+
+/// `rows` rows of `cols` Float64 columns `x0`, `x1`, ...
+///
+/// `x{i}` is uniform on [0, 2(i + 1)): its mean is `i + 1` and its standard
+/// deviation `(i + 1) / sqrt(3)`. Every cell is null with probability
+/// `null_pct` percent, independently; a `null_pct` outside [0, 100] is an
+/// `InvalidArgument` error.
+///
+/// # Example
+/// ```rust
+/// use describe_df::samples::numeric_frame;
+/// use describe_df::{Describable, DescribeOptions};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let df = numeric_frame(10_000, 3, 5.0, 7)?;
+/// let report = df.describe_report(&DescribeOptions::new())?;
+/// let mean = report.column("x2").unwrap().mean.unwrap();
+/// assert!((mean - 3.0).abs() < 0.1);
+/// # Ok(())
+/// # }
+/// ```
+pub fn numeric_frame(rows: usize, cols: usize, null_pct: f64, seed: u64) -> Result<DataFrame> {
+    if !(0.0..=100.0).contains(&null_pct) {
+        return Err(DescribeError::InvalidArgument(format!(
+            "null percentage {null_pct} is outside the range [0, 100]"
+        ))
+        .into());
+    }
+    let mut rng = SplitMix64(seed);
+    let columns = (0..cols)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let width = 2.0 * (i + 1) as f64;
+            let values: Vec<Option<f64>> = (0..rows)
+                .map(|_| {
+                    let value = width * rng.next_f64();
+                    rng.keep(null_pct / 100.0, value)
+                })
+                .collect();
+            Column::new(format!("x{i}").into(), values)
+        })
+        .collect();
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+/// Labels of the `string` and `category` columns of `mixed_frame()`
+const LABELS: [&str; 4] = ["alpha", "beta", "gamma", "delta"];
+
+/// `rows` rows of one column per dtype the crate describes
+///
+/// - `int` (Int64): uniform on 0..=99, mean 49.5, std `sqrt(9999 / 12)`
+///   (about 28.87)
+/// - `float` (Float64): standard normal, mean 0, std 1, null with
+///   probability 10%
+/// - `string` (String): `alpha`, `beta`, `gamma` or `delta`, equally
+///   likely, so 4 unique values with a mean length of 4.75
+/// - `bool` (Boolean): true with probability 0.25, so a mean of 0.25
+/// - `date` (Date, `temporal` feature only): uniform over the 366 days of
+///   2024, mean 2024-07-01T12:00
+/// - `datetime` (Datetime in microseconds, `temporal` feature only):
+///   uniform over the seconds of 2024-01-01, mean about 12:00
+/// - `category` (Categorical, `categorical` feature only): the `string`
+///   column, cast
+/// - `list` (List of Int64): 0 to 4 elements, equally likely, so a mean
+///   length of 2; elements are uniform on 0..=9
+pub fn mixed_frame(rows: usize, seed: u64) -> Result<DataFrame> {
+    let mut rng = SplitMix64(seed);
+    let mut ints = Vec::with_capacity(rows);
+    let mut floats = Vec::with_capacity(rows);
+    let mut strings = Vec::with_capacity(rows);
+    let mut bools = Vec::with_capacity(rows);
+    let mut days = Vec::with_capacity(rows);
+    let mut micros = Vec::with_capacity(rows);
+    let mut lists = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        ints.push(rng.below(100) as i64);
+        let normal = rng.normal();
+        floats.push(rng.keep(0.1, normal));
+        strings.push(LABELS[rng.below(LABELS.len() as u64) as usize]);
+        bools.push(rng.below(4) == 0);
+        // 2024-01-01 is day 19,723
+        days.push(19_723 + rng.below(366) as i32);
+        micros.push(1_704_067_200_000_000 + 1_000_000 * rng.below(86_400) as i64);
+        let len = rng.below(5);
+        let list: Vec<i64> = (0..len).map(|_| rng.below(10) as i64).collect();
+        lists.push(Series::new(PlSmallStr::EMPTY, list));
+    }
+
+    let string = Column::new("string".into(), strings);
+    let mut columns = vec![
+        Column::new("int".into(), ints),
+        Column::new("float".into(), floats),
+        string.clone(),
+        Column::new("bool".into(), bools),
+    ];
+    #[cfg(feature = "temporal")]
+    columns.extend([
+        Column::new("date".into(), days).cast(&DataType::Date)?,
+        Column::new("datetime".into(), micros)
+            .cast(&DataType::Datetime(TimeUnit::Microseconds, None))?,
+    ]);
+    #[cfg(not(feature = "temporal"))]
+    drop((days, micros));
+    #[cfg(feature = "categorical")]
+    columns.push(
+        string
+            .cast(&DataType::from_categories(Categories::global()))?
+            .with_name("category".into()),
+    );
+    #[cfg(not(feature = "categorical"))]
+    drop(string);
+    // Without rows the list dtype can't be inferred
+    columns
+        .push(Column::new("list".into(), lists).cast(&DataType::List(Box::new(DataType::Int64)))?);
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+/// `rows` rows of skewed Float64 columns
+///
+/// - `exponential`: rate 1, mean 1, std 1, median `ln 2` (about 0.693),
+///   skewness 2
+/// - `lognormal`: `exp` of a standard normal, mean `sqrt(e)` (about 1.649),
+///   std `sqrt((e - 1) e)` (about 2.161), median 1, skewness about 6.18
+/// - `left_skewed`: the negated `exponential`, mean -1, std 1,
+///   skewness -2
+///
+/// The lognormal tail is heavy: its sample std needs many rows to settle.
+pub fn skewed_frame(rows: usize, seed: u64) -> Result<DataFrame> {
+    let mut rng = SplitMix64(seed);
+    let mut exponential = Vec::with_capacity(rows);
+    let mut lognormal = Vec::with_capacity(rows);
+    let mut left_skewed = Vec::with_capacity(rows);
+    for _ in 0..rows {
+        exponential.push(rng.exponential());
+        lognormal.push(rng.normal().exp());
+        left_skewed.push(-rng.exponential());
+    }
+    DataFrame::new(vec![
+        Column::new("exponential".into(), exponential),
+        Column::new("lognormal".into(), lognormal),
+        Column::new("left_skewed".into(), left_skewed),
+    ])
+    .map_err(Into::into)
+}
+
+/// SplitMix64, a small generator with the same output on every platform
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    #[allow(clippy::cast_precision_loss)]
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Uniform in [0, n)
+    fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+
+    /// Standard normal, by the Box-Muller transform
+    fn normal(&mut self) -> f64 {
+        let radius = (-2.0 * (1.0 - self.next_f64()).ln()).sqrt();
+        radius * (std::f64::consts::TAU * self.next_f64()).cos()
+    }
+
+    /// Exponential with rate 1
+    fn exponential(&mut self) -> f64 {
+        -(1.0 - self.next_f64()).ln()
+    }
+
+    /// `value`, or null with probability `null_rate`
+    fn keep<T>(&mut self, null_rate: f64, value: T) -> Option<T> {
+        (self.next_f64() >= null_rate).then_some(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Describable, DescribeOptions};
+
+    fn null_share(df: &DataFrame, name: &str) -> Result<f64> {
+        let column = df.column(name)?;
+        #[allow(clippy::cast_precision_loss)]
+        Ok(column.null_count() as f64 / column.len() as f64)
+    }
+
+    #[test]
+    fn test_taxi_like_is_deterministic() -> Result<()> {
+        let first = taxi_like(500)?;
+        assert!(first.equals_missing(&taxi_like(500)?));
+        assert!(!first.equals_missing(&TaxiLike::new(500).seed(7).build()?));
+
+        // A longer frame starts with the same rows
+        assert!(taxi_like(1_000)?.head(Some(500)).equals_missing(&first));
+        Ok(())
+    }
+
+    #[test]
+    fn test_taxi_like_schema() -> Result<()> {
+        let df = taxi_like(200)?;
+        let mut expected = vec![
+            ("vendor", DataType::String),
+            ("passenger_count", DataType::Int32),
+            ("trip_distance", DataType::Float64),
+            ("fare_amount", DataType::Float64),
+            ("payment_type", DataType::String),
+            ("store_and_fwd", DataType::Boolean),
+        ];
+        if cfg!(feature = "temporal") {
+            expected.insert(
+                1,
+                (
+                    "pickup_at",
+                    DataType::Datetime(TimeUnit::Microseconds, None),
+                ),
+            );
+        }
+        let schema: Vec<(&str, DataType)> = df
+            .get_columns()
+            .iter()
+            .map(|column| (column.name().as_str(), column.dtype().clone()))
+            .collect();
+        assert_eq!(schema, expected);
+        assert_eq!(df.column("vendor")?.null_count(), 0);
+
+        let distance = df.column("trip_distance")?.f64()?;
+        assert!(distance.min().unwrap() >= 0.0);
+        let passengers = df.column("passenger_count")?.i32()?;
+        assert!((1..=6).contains(&passengers.min().unwrap()));
+        assert!((1..=6).contains(&passengers.max().unwrap()));
+        assert_eq!(taxi_like(0)?.height(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_taxi_like_null_rate() -> Result<()> {
+        let df = TaxiLike::new(10_000).null_rate(0.2).build()?;
+        for name in [
+            "passenger_count",
+            "trip_distance",
+            "fare_amount",
+            "payment_type",
+        ] {
+            let share = null_share(&df, name)?;
+            assert!((0.18..0.22).contains(&share), "{name}: {share}");
+        }
+        assert_eq!(null_share(&df, "store_and_fwd")?, 0.0);
+
+        let none = TaxiLike::new(1_000).null_rate(0.0).build()?;
+        assert_eq!(none.column("fare_amount")?.null_count(), 0);
+        let all = TaxiLike::new(1_000).null_rate(1.0).build()?;
+        assert_eq!(all.column("fare_amount")?.null_count(), 1_000);
+
+        for null_rate in [-0.1, 1.5, f64::NAN] {
+            let err = TaxiLike::new(10).null_rate(null_rate).build().unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DescribeError>(),
+                Some(DescribeError::InvalidArgument(_))
+            ));
+        }
+        Ok(())
+    }
+
+    /// examples/data/taxi_like.csv is `taxi_like(200)` written as CSV
+    #[cfg(all(feature = "temporal", feature = "csv"))]
+    #[test]
+    fn test_bundled_fixture_matches_generator() -> Result<()> {
+        let expected = taxi_like(200)?;
+        let fixture = LazyCsvReader::new(PlPath::new(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/examples/data/taxi_like.csv"
+        )))
+        .with_try_parse_dates(true)
+        .finish()?
+        .with_column(col("passenger_count").cast(DataType::Int32))
+        .collect()?;
+        assert!(fixture.equals_missing(&expected));
+        Ok(())
+    }
+
+    /// `|actual - expected| <= tolerance`, with both in the message
+    fn assert_close(actual: Option<f64>, expected: f64, tolerance: f64, what: &str) {
+        let actual = actual.unwrap_or(f64::NAN);
+        assert!(
+            (actual - expected).abs() <= tolerance,
+            "{what}: {actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_numeric_frame_documented_statistics() -> Result<()> {
+        let df = numeric_frame(20_000, 3, 10.0, 1)?;
+        assert_eq!(df.get_column_names_str(), ["x0", "x1", "x2"]);
+        let report = df.describe_report(&DescribeOptions::new())?;
+        for (i, summary) in report.columns.iter().enumerate() {
+            #[allow(clippy::cast_precision_loss)]
+            let mean = (i + 1) as f64;
+            assert_close(summary.mean, mean, 0.03 * mean, &summary.name);
+            assert_close(summary.std, mean / 3f64.sqrt(), 0.03 * mean, &summary.name);
+            assert_close(Some(null_share(&df, &summary.name)?), 0.1, 0.01, "nulls");
+        }
+
+        assert!(df.equals_missing(&numeric_frame(20_000, 3, 10.0, 1)?));
+        assert!(!df.equals_missing(&numeric_frame(20_000, 3, 10.0, 2)?));
+        assert_eq!(numeric_frame(5, 2, 100.0, 1)?.column("x1")?.null_count(), 5);
+        assert_eq!(numeric_frame(0, 0, 0.0, 1)?.shape(), (0, 0));
+        for null_pct in [-1.0, 100.5, f64::NAN] {
+            let err = numeric_frame(5, 1, null_pct, 1).unwrap_err();
+            assert!(matches!(
+                err.downcast_ref::<DescribeError>(),
+                Some(DescribeError::InvalidArgument(_))
+            ));
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_frame_documented_statistics() -> Result<()> {
+        let df = mixed_frame(20_000, 3)?;
+        assert!(df.equals_missing(&mixed_frame(20_000, 3)?));
+        let report = df.describe_report(&DescribeOptions::new())?;
+        // Lists get counts and length statistics only
+        assert_eq!(report.degraded_columns(), ["list"]);
+
+        let summary = |name: &str| report.column(name).unwrap();
+        assert_close(summary("int").mean, 49.5, 0.5, "int mean");
+        assert_close(summary("int").std, (9_999f64 / 12.0).sqrt(), 0.3, "int std");
+        assert_close(summary("float").mean, 0.0, 0.03, "float mean");
+        assert_close(summary("float").std, 1.0, 0.03, "float std");
+        assert_close(Some(null_share(&df, "float")?), 0.1, 0.01, "float nulls");
+        assert_close(summary("bool").mean, 0.25, 0.01, "bool mean");
+
+        let string = df.column("string")?.as_materialized_series();
+        assert_eq!(string.n_unique()?, 4);
+        let lengths = string.str()?.str_len_chars();
+        assert_close(
+            lengths.cast(&DataType::Float64)?.mean(),
+            4.75,
+            0.02,
+            "length",
+        );
+        let list_lengths = df.column("list")?.list()?.lst_lengths();
+        assert_close(
+            list_lengths.cast(&DataType::Float64)?.mean(),
+            2.0,
+            0.03,
+            "list",
+        );
+
+        #[cfg(feature = "temporal")]
+        {
+            let physical_mean = |name: &str| -> Result<Option<f64>> {
+                Ok(df
+                    .column(name)?
+                    .to_physical_repr()
+                    .as_materialized_series()
+                    .mean())
+            };
+            assert_close(physical_mean("date")?, 19_723.0 + 182.5, 3.0, "date");
+            let noon = 1_704_067_200_000_000.0 + 43_200_000_000.0;
+            assert_close(physical_mean("datetime")?, noon, 600_000_000.0, "datetime");
+        }
+        #[cfg(feature = "categorical")]
+        assert!(df.column("category")?.dtype().is_categorical());
+
+        let empty = mixed_frame(0, 3)?;
+        assert_eq!(empty.height(), 0);
+        assert_eq!(
+            empty.column("list")?.dtype(),
+            &DataType::List(Box::new(DataType::Int64))
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_skewed_frame_documented_statistics() -> Result<()> {
+        let df = skewed_frame(100_000, 5)?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let report = df.describe_report(&opts)?;
+        let summary = |name: &str| report.column(name).unwrap();
+
+        let exponential = summary("exponential");
+        assert_close(exponential.mean, 1.0, 0.02, "exponential mean");
+        assert_close(exponential.std, 1.0, 0.03, "exponential std");
+        assert_close(exponential.percentile(0.5), 2f64.ln(), 0.02, "median");
+
+        let e = std::f64::consts::E;
+        let lognormal = summary("lognormal");
+        assert_close(lognormal.mean, e.sqrt(), 0.05, "lognormal mean");
+        let std = ((e - 1.0) * e).sqrt();
+        assert_close(lognormal.std, std, 0.1 * std, "lognormal std");
+        assert_close(lognormal.percentile(0.5), 1.0, 0.02, "lognormal median");
+
+        let left_skewed = summary("left_skewed");
+        assert_close(left_skewed.mean, -1.0, 0.02, "left_skewed mean");
+        assert!(left_skewed.percentile(0.5) > left_skewed.mean);
+        Ok(())
+    }
+}
@@ -0,0 +1,194 @@
+//! Describe over a sample or a window of the rows
+//!
+//! The sample is taken lazily before the aggregation pass. Mean, std, min,
+//! max and percentiles are those of the sample; count and null_count are
+//! scaled back up to estimates for the full table. Windows (`describe_head`,
+//! `describe_slice`) are described as they are, counts included.
+
+#[cfg(feature = "sampling")]
+use std::collections::hash_map::RandomState;
+#[cfg(feature = "sampling")]
+use std::hash::{BuildHasher, Hasher};
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_plan_impl;
+#[cfg(feature = "sampling")]
+use crate::describe::{MetricRow, compute_metrics, render_metrics};
+#[cfg(feature = "sampling")]
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+#[cfg(feature = "sampling")]
+pub(crate) fn describe_sampled_impl(
+    lazy_frame: &LazyFrame,
+    fraction: f64,
+    seed: Option<u64>,
+    percentiles: Option<Vec<f64>>,
+) -> Result<DataFrame> {
+    if !(fraction > 0.0 && fraction <= 1.0) {
+        return Err(DescribeError::InvalidArgument(format!(
+            "sample fraction {fraction} is outside the range (0, 1]"
+        ))
+        .into());
+    }
+
+    // Every column is sampled with the same seed so the same rows are kept
+    // in all of them
+    let seed = seed.unwrap_or_else(|| RandomState::new().build_hasher().finish());
+    let sampled = lazy_frame.clone().select([all().as_expr().sample_frac(
+        lit(fraction),
+        false,
+        false,
+        Some(seed),
+    )]);
+
+    let opts = DescribeOptions::from_percentiles(percentiles);
+    let mut metrics = compute_metrics(&sampled, &opts)?;
+
+    for col_name in metrics.schema.iter_names() {
+        for row in [MetricRow::Count, MetricRow::NullCount] {
+            let alias = row.alias(&metrics.percentiles, col_name);
+            let sampled_count = metrics
+                .frame
+                .column(&alias)?
+                .get(0)?
+                .extract::<u64>()
+                .unwrap_or(0);
+            metrics.frame.with_column(Column::new(
+                alias.into(),
+                [scale_count(sampled_count, fraction)],
+            ))?;
+        }
+    }
+
+    render_metrics(&metrics, &opts)
+}
+
+pub(crate) fn describe_slice_impl(
+    lazy_frame: &LazyFrame,
+    offset: i64,
+    len: usize,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    Ok(opts
+        .engine
+        .collect(slice_plan(lazy_frame, offset, len, opts)?)?)
+}
+
+/// The describe plan of a window of the rows, with the slice ahead of the
+/// aggregation where Polars can push it into the scan
+fn slice_plan(
+    lazy_frame: &LazyFrame,
+    offset: i64,
+    len: usize,
+    opts: &DescribeOptions,
+) -> Result<LazyFrame> {
+    // Longer windows than IdxSize can index are truncated by the frame anyway
+    let len = IdxSize::try_from(len).unwrap_or(IdxSize::MAX);
+    describe_plan_impl(&lazy_frame.clone().slice(offset, len), opts)
+}
+
+/// Estimate a full-table count from a count over the sampled rows
+#[cfg(feature = "sampling")]
+fn scale_count(sampled_count: u64, fraction: f64) -> u64 {
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    let estimate = (sampled_count as f64 / fraction).round() as u64;
+    estimate
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn frame() -> Result<DataFrame> {
+        let values: Vec<Option<f64>> = (0..10_000)
+            .map(|i| (i % 4 != 0).then(|| f64::from(i)))
+            .collect();
+        Ok(df! { "x" => values }?)
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn test_describe_sampled_is_reproducible() -> Result<()> {
+        let df = frame()?;
+        let first = df.describe_sampled(0.1, Some(42), None)?;
+        let second = df.lazy().describe_sampled(0.1, Some(42), None)?;
+        assert!(first.equals_missing(&second));
+        Ok(())
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn test_describe_sampled_scales_counts() -> Result<()> {
+        let stats = frame()?.describe_sampled(0.1, Some(7), None)?;
+        let x = stats.column("x")?.str()?;
+        let count: u64 = x.get(0).unwrap().parse()?;
+        let null_count: u64 = x.get(1).unwrap().parse()?;
+
+        // The sample keeps exactly 10% of the rows
+        assert_eq!(count + null_count, 10_000);
+        assert!((6_500..=8_500).contains(&count), "count estimate {count}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_head_matches_sliced_frame() -> Result<()> {
+        let df = frame()?;
+        let opts = DescribeOptions::new();
+        let head = df.describe_head(100, &opts)?;
+        assert!(head.equals_missing(&df.slice(0, 100).describe_with(&opts)?));
+        assert!(head.equals_missing(&df.clone().lazy().describe_head(100, &opts)?));
+
+        // Longer than the frame describes all of it
+        let all = df.describe_with(&opts)?;
+        assert!(df.describe_head(1_000_000, &opts)?.equals_missing(&all));
+
+        // Negative offsets count from the end
+        let tail = df.describe_slice(-10, 100, &opts)?;
+        assert!(tail.equals_missing(&df.slice(-10, 100).describe_with(&opts)?));
+        assert_eq!(tail.column("x")?.str()?.get(0), Some("8"));
+        Ok(())
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_describe_slice_pushes_into_scans() -> Result<()> {
+        let path =
+            std::env::temp_dir().join(format!("describe_df_{}_sliced.csv", std::process::id()));
+        std::fs::write(&path, "v\n1\n2\n3\n")?;
+        let scan = LazyCsvReader::new(PlPath::Local(Arc::from(path.as_path()))).finish()?;
+        let opts = DescribeOptions::new();
+        let head = scan.describe_head(2, &opts);
+        let plan = slice_plan(&scan, 0, 2, &opts)?.explain(true);
+        std::fs::remove_file(&path)?;
+
+        assert_eq!(head?.column("v")?.str()?.get(0), Some("2"));
+        // The slice is part of the scan node rather than applied after it
+        let plan = plan?;
+        let scan_node = plan.find("Csv SCAN").expect("a CSV scan");
+        assert!(
+            plan[scan_node..].contains("SLICE: Positive { offset: 0, len: 2 }"),
+            "{plan}"
+        );
+        Ok(())
+    }
+
+    #[cfg(feature = "sampling")]
+    #[test]
+    fn test_describe_sampled_validates_fraction() -> Result<()> {
+        let df = frame()?;
+        for fraction in [0.0, -0.5, 1.5, f64::NAN] {
+            assert!(df.describe_sampled(fraction, None, None).is_err());
+        }
+        assert!(df.describe_sampled(1.0, None, None).is_ok());
+        Ok(())
+    }
+}
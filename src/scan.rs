@@ -0,0 +1,275 @@
+//! Describing files without loading them first
+//!
+//! Each function scans the file lazily and runs `describe_with()` on the
+//! scan, so only the described columns are read (projection pushdown).
+//! Errors name the file.
+
+#[cfg(feature = "ndjson")]
+use std::num::NonZeroUsize;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::describe_lazy_impl;
+use crate::options::DescribeOptions;
+
+/// Rows read to infer CSV column dtypes and NDJSON keys when
+/// `DescribeOptions::infer_schema_length` isn't set
+#[cfg(any(feature = "csv", feature = "ndjson"))]
+pub(crate) const DEFAULT_INFER_SCHEMA_LENGTH: usize = 100;
+
+/// Describe a CSV file with a header row
+///
+/// Column dtypes are inferred from the first
+/// `DescribeOptions::infer_schema_length` rows (100 by default).
+#[cfg(feature = "csv")]
+pub fn describe_csv(path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<DataFrame> {
+    let path = path.as_ref();
+    let infer_rows = opts
+        .infer_schema_length
+        .unwrap_or(DEFAULT_INFER_SCHEMA_LENGTH);
+    describe_scan(path, opts, |path| {
+        LazyCsvReader::new(path)
+            .with_infer_schema_length(Some(infer_rows))
+            .finish()
+    })
+}
+
+/// Describe a Parquet file
+#[cfg(feature = "parquet")]
+pub fn describe_parquet(path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<DataFrame> {
+    describe_scan(path.as_ref(), opts, |path| {
+        LazyFrame::scan_parquet(path, ScanArgsParquet::default())
+    })
+}
+
+/// Describe a newline-delimited JSON file
+///
+/// Keys and dtypes are inferred from the first
+/// `DescribeOptions::infer_schema_length` lines (100 by default), with the
+/// supertype of their values there. Keys that only appear further down the
+/// file aren't described; raise `infer_schema_length` to reach them. A key
+/// that is null on every sampled line is described as a String column.
+#[cfg(feature = "ndjson")]
+pub fn describe_ndjson(path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<DataFrame> {
+    let infer_rows = opts
+        .infer_schema_length
+        .unwrap_or(DEFAULT_INFER_SCHEMA_LENGTH);
+    describe_scan(path.as_ref(), opts, |path| {
+        let reader = || {
+            LazyJsonLineReader::new(path.clone())
+                .with_infer_schema_length(NonZeroUsize::new(infer_rows.max(1)))
+        };
+        let mut lazy_frame = reader().finish()?;
+        let schema = lazy_frame.collect_schema()?;
+        let unresolved = |dtype: &DataType| matches!(dtype, DataType::Null | DataType::Unknown(_));
+        if !schema.iter_values().any(unresolved) {
+            return Ok(lazy_frame);
+        }
+        let resolved: Schema = schema
+            .iter()
+            .map(|(name, dtype)| {
+                let dtype = if unresolved(dtype) {
+                    DataType::String
+                } else {
+                    dtype.clone()
+                };
+                Field::new(name.clone(), dtype)
+            })
+            .collect();
+        reader().with_schema(Some(Arc::new(resolved))).finish()
+    })
+}
+
+/// Describe an Arrow IPC (Feather v2) file
+///
+/// Not to be confused with `Describable::describe_ipc`, which serializes
+/// describe statistics as an IPC stream.
+#[cfg(feature = "ipc")]
+pub fn describe_ipc(path: impl AsRef<Path>, opts: &DescribeOptions) -> Result<DataFrame> {
+    describe_scan(path.as_ref(), opts, |path| {
+        LazyFrame::scan_ipc(path, ScanArgsIpc::default())
+    })
+}
+
+/// Scan `path` and describe the scan; scans only fail on collect, so the
+/// path context covers the whole describe
+fn describe_scan(
+    path: &Path,
+    opts: &DescribeOptions,
+    scan: impl FnOnce(PlPath) -> PolarsResult<LazyFrame>,
+) -> Result<DataFrame> {
+    let describe = || -> Result<DataFrame> {
+        let lazy_frame = scan(PlPath::Local(Arc::from(path)))?;
+        describe_lazy_impl(&lazy_frame, opts)
+    };
+    describe().with_context(|| format!("failed to describe {}", path.display()))
+}
+
+#[cfg(all(test, any(feature = "csv", feature = "ndjson", feature = "parquet")))]
+mod tests {
+    #[cfg(all(feature = "csv", feature = "parquet"))]
+    use std::fs::File;
+    use std::path::PathBuf;
+
+    use super::*;
+    #[cfg(all(feature = "csv", feature = "parquet"))]
+    use crate::Describable;
+    #[cfg(feature = "ndjson")]
+    use crate::statistics::Statistic;
+
+    #[cfg(any(feature = "csv", feature = "parquet"))]
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("describe_df_{}_{name}", std::process::id()))
+    }
+
+    #[cfg(all(feature = "csv", feature = "parquet"))]
+    fn sample() -> Result<DataFrame> {
+        Ok(df! {
+            "x" => [Some(1.5), None, Some(-2.0), Some(4.25)],
+            "n" => [Some(3i64), Some(1), None, Some(7)],
+            "name" => [Some("b"), Some("a"), Some("c"), None],
+        }?)
+    }
+
+    #[cfg(all(
+        feature = "csv",
+        feature = "ndjson",
+        feature = "parquet",
+        feature = "ipc"
+    ))]
+    #[test]
+    fn test_describe_files_match_in_memory() -> Result<()> {
+        let mut df = sample()?;
+        let opts = DescribeOptions::new().percentiles(vec![0.5]);
+        let expected = df.describe_with(&opts)?;
+
+        let csv = temp_path("scan.csv");
+        CsvWriter::new(File::create(&csv)?).finish(&mut df)?;
+        let parquet = temp_path("scan.parquet");
+        ParquetWriter::new(File::create(&parquet)?).finish(&mut df)?;
+        let ndjson = temp_path("scan.ndjson");
+        JsonWriter::new(File::create(&ndjson)?)
+            .with_json_format(JsonFormat::JsonLines)
+            .finish(&mut df)?;
+        let ipc = temp_path("scan.arrow");
+        IpcWriter::new(File::create(&ipc)?).finish(&mut df)?;
+
+        let described = [
+            describe_csv(&csv, &opts),
+            describe_parquet(&parquet, &opts),
+            describe_ndjson(&ndjson, &opts),
+            describe_ipc(&ipc, &opts),
+        ];
+        for path in [&csv, &parquet, &ndjson, &ipc] {
+            std::fs::remove_file(path)?;
+        }
+        for stats in described {
+            let stats = stats?;
+            assert!(stats.equals_missing(&expected), "{stats}");
+        }
+        Ok(())
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_describe_file_errors_name_the_path() {
+        let path = temp_path("does_not_exist.parquet");
+        let err = describe_parquet(&path, &DescribeOptions::new()).unwrap_err();
+        assert!(format!("{err:#}").contains(&path.display().to_string()));
+    }
+
+    #[cfg(feature = "csv")]
+    #[test]
+    fn test_describe_csv_infer_schema_length() -> Result<()> {
+        // The first row looks like an integer, the second doesn't
+        let path = temp_path("infer.csv");
+        std::fs::write(&path, "v\n1\n2.5\n")?;
+        let one_row = describe_csv(&path, &DescribeOptions::new().infer_schema_length(1));
+        let default = describe_csv(&path, &DescribeOptions::new());
+        std::fs::remove_file(&path)?;
+
+        assert!(one_row.is_err());
+        let default = default?;
+        assert_eq!(default.column("v")?.str()?.get(2), Some("1.75"));
+        Ok(())
+    }
+
+    #[cfg(all(feature = "csv", feature = "parquet"))]
+    #[test]
+    fn test_describe_scan_only_reads_selected_columns() -> Result<()> {
+        // With one row of inference `bad` is an integer column its second
+        // row can't be parsed as, so reading it fails
+        let path = temp_path("poisoned.csv");
+        std::fs::write(&path, "a,bad,b\n1,1,x\n2,2.5,y\n")?;
+        let opts = DescribeOptions::new().infer_schema_length(1);
+        let all = describe_csv(&path, &opts);
+        let selected = describe_csv(&path, &opts.clone().columns(["a", "b"]));
+        let limited = describe_csv(&path, &opts.max_columns(1));
+        std::fs::remove_file(&path)?;
+
+        assert!(all.is_err());
+        assert_eq!(selected?.get_column_names_str(), ["statistic", "a", "b"]);
+        assert_eq!(limited?.get_column_names_str(), ["statistic", "a", "…"]);
+
+        let mut df = sample()?;
+        let parquet = temp_path("projection.parquet");
+        ParquetWriter::new(File::create(&parquet)?).finish(&mut df)?;
+        let plan = LazyFrame::scan_parquet(
+            PlPath::Local(Arc::from(parquet.as_path())),
+            ScanArgsParquet::default(),
+        )?
+        .describe_lazy(&DescribeOptions::new().columns(["n"]))?
+        .explain(true);
+        std::fs::remove_file(&parquet)?;
+        assert!(plan?.contains("PROJECT 1/3 COLUMNS"));
+        Ok(())
+    }
+
+    #[cfg(feature = "ndjson")]
+    #[test]
+    fn test_describe_ndjson_describes_late_sparse_keys() -> Result<()> {
+        // Besides `id`, the keys only appear on one line in ten, all of them
+        // past line 100; `empty` is null on the one line that has it
+        let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/sparse.ndjson");
+        let opts = DescribeOptions::new()
+            .percentiles(vec![])
+            .statistic(Statistic::NUnique);
+        let sampled = describe_ndjson(&path, &opts)?;
+        assert_eq!(sampled.get_column_names_str(), ["statistic", "id"]);
+
+        let stats = describe_ndjson(&path, &opts.infer_schema_length(200))?;
+        assert_eq!(
+            stats.get_column_names_str(),
+            ["statistic", "id", "note", "score", "flag", "mixed", "empty"]
+        );
+
+        let row = |name: &str| -> Result<Vec<Option<String>>> {
+            let labels = stats.column("statistic")?.str()?;
+            let i = labels
+                .into_iter()
+                .position(|label| label == Some(name))
+                .unwrap();
+            let values = stats.get_columns()[2..]
+                .iter()
+                .map(|column| Ok(column.str()?.get(i).map(str::to_string)))
+                .collect::<Result<_>>()?;
+            Ok(values)
+        };
+        let strings = |values: [&str; 5]| values.map(|v| Some(v.to_string())).to_vec();
+        assert_eq!(row("count")?, strings(["20", "20", "20", "20", "0"]));
+        assert_eq!(
+            row("null_count")?,
+            strings(["180", "180", "180", "180", "200"])
+        );
+        assert_eq!(row("n_unique")?, strings(["3", "20", "2", "20", "0"]));
+        assert_eq!(row("min")?, strings(["late", "0.0", "false", "1", "null"]));
+        assert_eq!(
+            row("max")?,
+            strings(["latest", "28.5", "true", "v8", "null"])
+        );
+        assert_eq!(row("mean")?[1].as_deref(), Some("14.25"));
+        Ok(())
+    }
+}
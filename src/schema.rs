@@ -0,0 +1,98 @@
+//! Structural summary of a frame
+//!
+//! `describe_schema()` groups the columns by dtype; the only data it reads
+//! is a null count per column.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+pub(crate) fn describe_schema_impl(lazy_frame: &LazyFrame) -> Result<DataFrame> {
+    let schema = lazy_frame.clone().collect_schema()?;
+
+    let null_counts = if schema.is_empty() {
+        DataFrame::empty()
+    } else {
+        let exprs: Vec<Expr> = schema
+            .iter_names()
+            .map(|name| col(name.clone()).null_count().alias(name.clone()))
+            .collect();
+        lazy_frame.clone().select(exprs).collect()?
+    };
+
+    // dtype groups in order of first appearance
+    let mut groups: Vec<(String, Vec<&str>, u32)> = Vec::new();
+    for (name, dtype) in schema.iter() {
+        let has_nulls = null_counts
+            .column(name)?
+            .get(0)?
+            .extract::<u64>()
+            .is_some_and(|n| n > 0);
+        let dtype = dtype.to_string();
+        let index = match groups.iter().position(|(d, _, _)| *d == dtype) {
+            Some(index) => index,
+            None => {
+                groups.push((dtype, Vec::new(), 0));
+                groups.len() - 1
+            }
+        };
+        let (_, names, with_nulls) = &mut groups[index];
+        names.push(name.as_str());
+        *with_nulls += u32::from(has_nulls);
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    let n_columns: Vec<u32> = groups
+        .iter()
+        .map(|(_, names, _)| names.len() as u32)
+        .collect();
+    DataFrame::new(vec![
+        Column::new(
+            "dtype".into(),
+            groups
+                .iter()
+                .map(|(d, _, _)| d.as_str())
+                .collect::<Vec<_>>(),
+        ),
+        Column::new("n_columns".into(), n_columns),
+        Column::new(
+            "column_names".into(),
+            groups
+                .iter()
+                .map(|(_, names, _)| names.join(", "))
+                .collect::<Vec<_>>(),
+        ),
+        Column::new(
+            "n_with_nulls".into(),
+            groups.iter().map(|(_, _, n)| *n).collect::<Vec<_>>(),
+        ),
+    ])
+    .map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_schema_groups_dtypes() -> Result<()> {
+        let df = df! {
+            "a" => [Some(1), None, Some(3)],
+            "name" => ["x", "y", "z"],
+            "b" => [4, 5, 6],
+            "ratio" => [Some(0.5), Some(1.5), None],
+            "c" => [Some(7), Some(8), None],
+            "note" => [None, Some("n"), None],
+        }?;
+        let summary = df.lazy().describe_schema()?;
+
+        let expected = df! {
+            "dtype" => ["i32", "str", "f64"],
+            "n_columns" => [3u32, 2, 1],
+            "column_names" => ["a, b, c", "name, note", "ratio"],
+            "n_with_nulls" => [2u32, 1, 1],
+        }?;
+        assert!(summary.equals(&expected), "{summary}");
+        Ok(())
+    }
+}
@@ -0,0 +1,826 @@
+//! Optional statistics that can be added to the default describe rows
+//!
+//! The default row set (count, null_count, mean, std, min, percentiles, max)
+//! is always produced. Anything listed here is opt-in through
+//! `DescribeOptions::statistic` and appended after the max row, in the order
+//! it was requested. Statistics registered with
+//! `DescribeOptions::custom_stat` follow them.
+
+use std::fmt;
+use std::sync::Arc;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::duplicates::dup_count_expr;
+use crate::options::DescribeOptions;
+
+/// IQR multiplier of the `OutlierCount` fences unless configured otherwise
+pub(crate) const DEFAULT_IQR_MULTIPLIER: f64 = 1.5;
+
+/// `ZScoreOutliers` threshold unless configured otherwise
+pub(crate) const DEFAULT_ZSCORE_THRESHOLD: f64 = 3.0;
+
+/// Fraction cut from each tail by `TrimmedMean` unless configured otherwise
+pub(crate) const DEFAULT_TRIM_FRACTION: f64 = 0.1;
+
+/// Confidence level of `MeanCiLow` / `MeanCiHigh` unless configured
+/// otherwise
+pub(crate) const DEFAULT_CONFIDENCE_LEVEL: f64 = 0.95;
+
+/// Multiple of the median interval above which `GapCount` counts an
+/// interval unless configured otherwise
+pub(crate) const DEFAULT_GAP_MULTIPLIER: f64 = 1.5;
+
+/// Fewest non-null values for which `JarqueBera` is computed
+pub(crate) const MIN_JARQUE_BERA_COUNT: u32 = 8;
+
+/// Two-sided z-values of the common confidence levels
+const Z_VALUES: [(f64, f64); 3] = [(0.90, 1.644_854), (0.95, 1.959_964), (0.99, 2.575_829)];
+
+/// Factor making the MAD of normal data an estimate of its std, 1 / Φ⁻¹(3/4)
+pub(crate) const MAD_NORMAL_SCALE: f64 = 1.4826;
+
+/// An optional statistic row
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Statistic {
+    /// Number of distinct non-null values
+    NUnique,
+    /// Shortest string length in characters (String columns only)
+    LenMin,
+    /// Mean string length in characters (String columns only)
+    LenMean,
+    /// Longest string length in characters (String columns only)
+    LenMax,
+    /// Number of empty strings, not counting nulls (String columns only)
+    EmptyCount,
+    /// Number of values outside the IQR fences `[Q1 - k·IQR, Q3 + k·IQR]`,
+    /// with k set by `DescribeOptions::iqr_multiplier` (numeric columns
+    /// only). With a zero IQR every value different from Q1 = Q3 counts.
+    OutlierCount,
+    /// Number of values whose z-score `|x - mean| / std` exceeds the
+    /// threshold set by `DescribeOptions::zscore_threshold` (numeric columns
+    /// only). Null when the std is 0 or null.
+    ZScoreOutliers,
+    /// Whether the column is sorted in ascending order (equal neighbours
+    /// allowed); null for nested, binary and null columns
+    ///
+    /// Like Polars' sorted flags, nulls are allowed as long as they are all
+    /// at the start or all at the end of the column; nulls in between make
+    /// the column unsorted. Single-row and empty columns are sorted.
+    IsSortedAsc,
+    /// Whether the column is sorted in descending order, with the same null
+    /// rules as `IsSortedAsc`
+    IsSortedDesc,
+    /// Whether the column holds at most one distinct non-null value
+    /// (all-null columns included)
+    IsConstant,
+    /// Whether every value of the column is null
+    AllNull,
+    /// In-memory size of the column in bytes, as estimated by Polars
+    ///
+    /// Rendered human-readable (`12.4 MiB`) in the string layout and as raw
+    /// bytes in `ColumnSummary::estimated_size`. Sizes aren't aggregations:
+    /// requesting this statistic materializes the described columns of a
+    /// LazyFrame in a second pass.
+    EstimatedSize,
+    /// Row index of the minimum, counting from 0 in the row order of the
+    /// described frame; ties report the first occurrence and nulls are
+    /// skipped. Null for nested, binary and null columns, like `min`.
+    ArgMin,
+    /// Row index of the maximum, with the same rules as `ArgMin`
+    ArgMax,
+    /// Interquartile range, the linear 75th minus 25th percentile
+    ///
+    /// A Duration for Duration and Time columns, null for Date and
+    /// Datetime columns (which have no percentiles) and other non-numeric
+    /// columns.
+    Iqr,
+    /// Max minus min; a Duration for temporal columns, null for
+    /// non-numeric ones
+    Range,
+    /// Coefficient of variation, std / mean (numeric columns only); null
+    /// when the mean is 0
+    Cv,
+    /// Standard error of the mean, std / sqrt(count) (numeric columns
+    /// only); null for fewer than two values
+    Sem,
+    /// Median absolute deviation, median(|x - median(x)|), of numeric
+    /// columns; scaled by 1.4826 with `DescribeOptions::mad_normal`
+    Mad,
+    /// Geometric mean, exp(mean(ln x)), of numeric columns; null when any
+    /// value is 0 or negative
+    GeoMean,
+    /// Harmonic mean, n / sum(1 / x), of numeric columns; null when any
+    /// value is 0 or negative
+    HarmonicMean,
+    /// Mean of the values between the f and 1 - f linear quantiles, with
+    /// f set by `DescribeOptions::trimmed_mean` (0.1 by default; numeric
+    /// columns only)
+    TrimmedMean,
+    /// Share of distinct values, n_unique / count, shown as a percentage;
+    /// near 100% for key-like columns
+    UniqueRatio,
+    /// Share of the most frequent value, its count / count, shown as a
+    /// percentage; near 100% for near-constant columns
+    TopFreqRatio,
+    /// Number of non-null values repeating an earlier value, count -
+    /// n_unique, as in `Describable::duplicates_report()`; nested values
+    /// are compared whole. Null for Null columns.
+    DupCount,
+    /// Lower bound of the normal confidence interval of the mean,
+    /// mean - z * sem, at `DescribeOptions::confidence_level` (95% by
+    /// default; numeric columns only); null for fewer than two values
+    MeanCiLow,
+    /// Upper bound of the confidence interval of the mean, mean + z * sem
+    MeanCiHigh,
+    /// Jarque–Bera normality statistic, n / 6 * (S² + K² / 4) from the
+    /// sample skewness S and excess kurtosis K (numeric columns only);
+    /// near 0 for normal data, null below 8 values or for constant columns
+    JarqueBera,
+    /// p-value of `JarqueBera` under the chi-squared(2) distribution,
+    /// exp(-JB / 2); small values reject normality
+    JarqueBeraPValue,
+    /// Height of the described frame, the same for every column; equals
+    /// count + null_count (checked under `DescribeOptions::strict_counts`)
+    TotalRows,
+    /// Max minus min as a Duration, for Date and Datetime columns only
+    Span,
+    /// Typical spacing of a Date or Datetime column, the median difference
+    /// between successive distinct timestamps, as a Duration
+    ///
+    /// The non-null values are sorted inside the aggregation, so the column
+    /// doesn't need to be sorted. Null below two distinct values.
+    MedianInterval,
+    /// Number of intervals between successive timestamps longer than k
+    /// times `MedianInterval`, with k set by
+    /// `DescribeOptions::gap_multiplier` (Date and Datetime columns only)
+    GapCount,
+    /// Number of values equal to zero, -0.0 included (numeric columns only)
+    ///
+    /// Nulls and NaNs are in none of `ZeroCount`, `NegCount` and
+    /// `PosCount`: the three counts, the NaNs and null_count add up to the
+    /// height of the frame.
+    ZeroCount,
+    /// Number of values below zero, -inf included (numeric columns only)
+    NegCount,
+    /// Number of values above zero, +inf included (numeric columns only)
+    PosCount,
+    /// Number of values starting with whitespace (String columns only)
+    ///
+    /// Like the other string statistics this sees the values after
+    /// `DescribeOptions::trim_strings`.
+    LeadingWsCount,
+    /// Number of values ending with whitespace (String columns only)
+    TrailingWsCount,
+    /// Number of non-null values colliding with another value once trimmed
+    /// and lowercased, i.e. the values of every folded group of two or
+    /// more (String columns only); `" a"`, `"A"` and `"a"` count 3
+    CasefoldDupCount,
+    /// Row index of the first non-null value, counting from 0 in the row
+    /// order of the described frame (the collected order of a LazyFrame);
+    /// null for an all-null column. Applies to every dtype.
+    FirstValidIndex,
+    /// Row index of the last non-null value, with the same rules as
+    /// `FirstValidIndex`
+    LastValidIndex,
+    /// Number of true values, not counting nulls (Boolean columns only)
+    ///
+    /// Boolean columns get no percentiles, as in Python Polars; the mean is
+    /// the fraction of the non-null values that are true, and with
+    /// `FalseCount` this gives the counts behind it.
+    TrueCount,
+    /// Number of false values, not counting nulls (Boolean columns only)
+    FalseCount,
+}
+
+impl Statistic {
+    pub(crate) const ALL: [Statistic; 43] = [
+        Statistic::NUnique,
+        Statistic::LenMin,
+        Statistic::LenMean,
+        Statistic::LenMax,
+        Statistic::EmptyCount,
+        Statistic::OutlierCount,
+        Statistic::ZScoreOutliers,
+        Statistic::IsSortedAsc,
+        Statistic::IsSortedDesc,
+        Statistic::IsConstant,
+        Statistic::AllNull,
+        Statistic::EstimatedSize,
+        Statistic::ArgMin,
+        Statistic::ArgMax,
+        Statistic::Iqr,
+        Statistic::Range,
+        Statistic::Cv,
+        Statistic::Sem,
+        Statistic::Mad,
+        Statistic::GeoMean,
+        Statistic::HarmonicMean,
+        Statistic::TrimmedMean,
+        Statistic::UniqueRatio,
+        Statistic::TopFreqRatio,
+        Statistic::DupCount,
+        Statistic::MeanCiLow,
+        Statistic::MeanCiHigh,
+        Statistic::JarqueBera,
+        Statistic::JarqueBeraPValue,
+        Statistic::TotalRows,
+        Statistic::Span,
+        Statistic::MedianInterval,
+        Statistic::GapCount,
+        Statistic::ZeroCount,
+        Statistic::NegCount,
+        Statistic::PosCount,
+        Statistic::LeadingWsCount,
+        Statistic::TrailingWsCount,
+        Statistic::CasefoldDupCount,
+        Statistic::FirstValidIndex,
+        Statistic::LastValidIndex,
+        Statistic::TrueCount,
+        Statistic::FalseCount,
+    ];
+
+    /// Label of the statistic row
+    pub fn name(self) -> &'static str {
+        match self {
+            Statistic::NUnique => "n_unique",
+            Statistic::LenMin => "len_min",
+            Statistic::LenMean => "len_mean",
+            Statistic::LenMax => "len_max",
+            Statistic::EmptyCount => "empty_count",
+            Statistic::OutlierCount => "outlier_count",
+            Statistic::ZScoreOutliers => "zscore_outliers",
+            Statistic::IsSortedAsc => "is_sorted_asc",
+            Statistic::IsSortedDesc => "is_sorted_desc",
+            Statistic::IsConstant => "is_constant",
+            Statistic::AllNull => "all_null",
+            Statistic::EstimatedSize => "estimated_size",
+            Statistic::ArgMin => "arg_min",
+            Statistic::ArgMax => "arg_max",
+            Statistic::Iqr => "iqr",
+            Statistic::Range => "range",
+            Statistic::Cv => "cv",
+            Statistic::Sem => "sem",
+            Statistic::Mad => "mad",
+            Statistic::GeoMean => "geo_mean",
+            Statistic::HarmonicMean => "harmonic_mean",
+            Statistic::TrimmedMean => "trimmed_mean",
+            Statistic::UniqueRatio => "unique_ratio",
+            Statistic::TopFreqRatio => "top_freq_ratio",
+            Statistic::DupCount => "dup_count",
+            Statistic::MeanCiLow => "mean_ci_low",
+            Statistic::MeanCiHigh => "mean_ci_high",
+            Statistic::JarqueBera => "jarque_bera",
+            Statistic::JarqueBeraPValue => "jarque_bera_p",
+            Statistic::TotalRows => "total_rows",
+            Statistic::Span => "span",
+            Statistic::MedianInterval => "median_interval",
+            Statistic::GapCount => "gap_count",
+            Statistic::ZeroCount => "zero_count",
+            Statistic::NegCount => "neg_count",
+            Statistic::PosCount => "pos_count",
+            Statistic::LeadingWsCount => "leading_ws_count",
+            Statistic::TrailingWsCount => "trailing_ws_count",
+            Statistic::CasefoldDupCount => "casefold_dup_count",
+            Statistic::FirstValidIndex => "first_valid_index",
+            Statistic::LastValidIndex => "last_valid_index",
+            Statistic::TrueCount => "true_count",
+            Statistic::FalseCount => "false_count",
+        }
+    }
+}
+
+/// Builds the aggregation of a custom statistic from the column expression
+/// and the column dtype
+pub(crate) type CustomStatFn = Arc<dyn Fn(Expr, &DataType) -> Expr + Send + Sync>;
+
+/// A statistic registered with `DescribeOptions::custom_stat`
+#[derive(Clone)]
+pub(crate) struct CustomStat {
+    pub(crate) name: String,
+    pub(crate) expr: CustomStatFn,
+}
+
+impl fmt::Debug for CustomStat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("CustomStat").field(&self.name).finish()
+    }
+}
+
+/// Aggregation expression for an optional statistic over `values`
+///
+/// `values` is the column expression after string normalization, so string
+/// statistics honour options such as `trim_strings`. Dtypes a statistic
+/// doesn't apply to get a typed null literal.
+///
+/// String lengths count characters rather than bytes, so a multi-byte UTF-8
+/// character has length 1.
+pub(crate) fn statistic_expr(
+    stat: Statistic,
+    values: &Expr,
+    dtype: &DataType,
+    opts: &DescribeOptions,
+) -> Expr {
+    let is_string = dtype == &DataType::String;
+    match stat {
+        Statistic::NUnique => {
+            if dtype.is_nested() || matches!(dtype, DataType::Null | DataType::Unknown(_)) {
+                lit(NULL).cast(DataType::UInt32)
+            } else {
+                values.clone().drop_nulls().n_unique()
+            }
+        }
+        Statistic::UniqueRatio | Statistic::TopFreqRatio
+            if dtype.is_nested() || matches!(dtype, DataType::Null | DataType::Unknown(_)) =>
+        {
+            lit(NULL).cast(DataType::Float64)
+        }
+        Statistic::UniqueRatio => ratio_of_count(values, values.clone().drop_nulls().n_unique()),
+        Statistic::TopFreqRatio => {
+            let top_count = values
+                .clone()
+                .drop_nulls()
+                .value_counts(false, false, "count", false)
+                .struct_()
+                .field_by_name("count")
+                .max();
+            ratio_of_count(values, top_count)
+        }
+        Statistic::DupCount => {
+            if matches!(dtype, DataType::Null | DataType::Unknown(_)) {
+                lit(NULL).cast(DataType::UInt64)
+            } else {
+                dup_count_expr(values)
+            }
+        }
+        Statistic::LenMean if !is_string => lit(NULL).cast(DataType::Float64),
+        Statistic::LenMin | Statistic::LenMax | Statistic::EmptyCount if !is_string => {
+            lit(NULL).cast(DataType::UInt32)
+        }
+        Statistic::LeadingWsCount | Statistic::TrailingWsCount | Statistic::CasefoldDupCount
+            if !is_string =>
+        {
+            lit(NULL).cast(DataType::UInt32)
+        }
+        Statistic::LeadingWsCount => {
+            let stripped = values.clone().str().strip_chars_start(lit(NULL));
+            stripped.neq(values.clone()).sum().cast(DataType::UInt32)
+        }
+        Statistic::TrailingWsCount => {
+            let stripped = values.clone().str().strip_chars_end(lit(NULL));
+            stripped.neq(values.clone()).sum().cast(DataType::UInt32)
+        }
+        Statistic::CasefoldDupCount => {
+            // The values minus those alone in their folded group
+            let folded = values
+                .clone()
+                .str()
+                .strip_chars(lit(NULL))
+                .str()
+                .to_lowercase();
+            let singletons = folded
+                .clone()
+                .drop_nulls()
+                .value_counts(false, false, "count", false)
+                .struct_()
+                .field_by_name("count")
+                .eq(lit(1))
+                .sum();
+            folded.count().cast(DataType::UInt32) - singletons.cast(DataType::UInt32)
+        }
+        Statistic::TrueCount | Statistic::FalseCount if dtype != &DataType::Boolean => {
+            lit(NULL).cast(DataType::UInt32)
+        }
+        // Sums skip nulls, so neither count includes them
+        Statistic::TrueCount => values.clone().sum().cast(DataType::UInt32),
+        Statistic::FalseCount => values.clone().not().sum().cast(DataType::UInt32),
+        Statistic::LenMin => values.clone().str().len_chars().min(),
+        Statistic::LenMean => values.clone().str().len_chars().mean(),
+        Statistic::LenMax => values.clone().str().len_chars().max(),
+        Statistic::EmptyCount => values.clone().eq(lit("")).sum().cast(DataType::UInt32),
+        Statistic::OutlierCount
+        | Statistic::ZScoreOutliers
+        | Statistic::ZeroCount
+        | Statistic::NegCount
+        | Statistic::PosCount
+            if !dtype.is_numeric() =>
+        {
+            lit(NULL).cast(DataType::UInt32)
+        }
+        Statistic::ZeroCount | Statistic::NegCount | Statistic::PosCount => {
+            let x = values.clone().cast(DataType::Float64);
+            let sign = match stat {
+                Statistic::ZeroCount => x.clone().eq(lit(0.0)),
+                Statistic::NegCount => x.clone().lt(lit(0.0)),
+                _ => x.clone().gt(lit(0.0)),
+            };
+            // Polars orders NaN above every number, so it is left out
+            // explicitly
+            sign.and(x.is_not_nan()).sum().cast(DataType::UInt32)
+        }
+        Statistic::OutlierCount => {
+            let k = opts.iqr_multiplier.unwrap_or(DEFAULT_IQR_MULTIPLIER);
+            let x = quantile_input(values);
+            let q1 = x.clone().quantile(lit(0.25), QuantileMethod::Linear);
+            let q3 = x.clone().quantile(lit(0.75), QuantileMethod::Linear);
+            let iqr = q3.clone() - q1.clone();
+            let lower = q1 - lit(k) * iqr.clone();
+            let upper = q3 + lit(k) * iqr;
+            x.clone()
+                .lt(lower)
+                .or(x.gt(upper))
+                .sum()
+                .cast(DataType::UInt32)
+        }
+        Statistic::ArgMin | Statistic::ArgMax
+            if dtype.is_nested()
+                || matches!(
+                    dtype,
+                    DataType::Binary | DataType::Null | DataType::Unknown(_)
+                ) =>
+        {
+            lit(NULL).cast(IDX_DTYPE)
+        }
+        Statistic::ArgMin => arg_extreme_expr(values, dtype, false),
+        Statistic::ArgMax => arg_extreme_expr(values, dtype, true),
+        Statistic::FirstValidIndex => valid_index_expr(values, false),
+        Statistic::LastValidIndex => valid_index_expr(values, true),
+        Statistic::Iqr => iqr_expr(values, dtype),
+        Statistic::Range if dtype.is_numeric() => {
+            let x = values.clone().cast(DataType::Float64);
+            x.clone().max() - x.min()
+        }
+        Statistic::Range if dtype.is_temporal() => values.clone().max() - values.clone().min(),
+        Statistic::Span | Statistic::MedianInterval | Statistic::GapCount
+            if !(dtype.is_date() || dtype.is_datetime()) =>
+        {
+            match stat {
+                Statistic::GapCount => lit(NULL).cast(DataType::UInt32),
+                _ => lit(NULL).cast(DataType::Duration(TimeUnit::Milliseconds)),
+            }
+        }
+        Statistic::Span => values.clone().max() - values.clone().min(),
+        Statistic::MedianInterval => {
+            let (intervals, interval_dtype) = sorted_intervals(values, dtype);
+            intervals
+                .median()
+                .cast(DataType::Int64)
+                .cast(interval_dtype)
+        }
+        Statistic::GapCount => {
+            let k = opts.gap_multiplier.unwrap_or(DEFAULT_GAP_MULTIPLIER);
+            let (intervals, _) = sorted_intervals(values, dtype);
+            // The median is broadcast over the intervals, as in `Mad`
+            intervals
+                .clone()
+                .cast(DataType::Float64)
+                .gt(intervals.median() * lit(k))
+                .sum()
+                .cast(DataType::UInt32)
+        }
+        Statistic::Range => lit(NULL).cast(DataType::Float64),
+        Statistic::Cv
+        | Statistic::Sem
+        | Statistic::Mad
+        | Statistic::GeoMean
+        | Statistic::HarmonicMean
+        | Statistic::TrimmedMean
+        | Statistic::MeanCiLow
+        | Statistic::MeanCiHigh
+        | Statistic::JarqueBera
+        | Statistic::JarqueBeraPValue
+            if !dtype.is_numeric() =>
+        {
+            lit(NULL).cast(DataType::Float64)
+        }
+        Statistic::Mad => {
+            // The inner median is broadcast over the column, so this stays
+            // one aggregation
+            let x = quantile_input(values);
+            let mad = (x.clone() - x.median()).abs().median();
+            if opts.mad_normal {
+                mad * lit(MAD_NORMAL_SCALE)
+            } else {
+                mad
+            }
+        }
+        Statistic::Cv => {
+            let x = values.clone().cast(DataType::Float64);
+            let mean = x.clone().mean();
+            when(mean.clone().eq(lit(0.0)))
+                .then(lit(NULL).cast(DataType::Float64))
+                .otherwise(x.std(1) / mean)
+        }
+        Statistic::Sem => {
+            let x = values.clone().cast(DataType::Float64);
+            // std(1) is null below two values
+            x.clone().std(1) / x.count().cast(DataType::Float64).sqrt()
+        }
+        Statistic::MeanCiLow | Statistic::MeanCiHigh => {
+            let level = opts.confidence_level.unwrap_or(DEFAULT_CONFIDENCE_LEVEL);
+            let x = values.clone().cast(DataType::Float64);
+            // std(1) is null below two values, and so are the bounds
+            let margin = lit(z_value(level)) * x.clone().std(1)
+                / x.clone().count().cast(DataType::Float64).sqrt();
+            if stat == Statistic::MeanCiLow {
+                x.mean() - margin
+            } else {
+                x.mean() + margin
+            }
+        }
+        Statistic::JarqueBera => jarque_bera_expr(values),
+        Statistic::JarqueBeraPValue => (jarque_bera_expr(values) * lit(-0.5)).exp(),
+        Statistic::GeoMean => {
+            positive_only(values, |x| x.log(lit(std::f64::consts::E)).mean().exp())
+        }
+        Statistic::HarmonicMean => positive_only(values, |x| {
+            x.clone().count().cast(DataType::Float64) / (lit(1.0) / x).sum()
+        }),
+        Statistic::TrimmedMean => {
+            let fraction = opts.trim_fraction.unwrap_or(DEFAULT_TRIM_FRACTION);
+            let x = quantile_input(values);
+            let lower = x.clone().quantile(lit(fraction), QuantileMethod::Linear);
+            let upper = x
+                .clone()
+                .quantile(lit(1.0 - fraction), QuantileMethod::Linear);
+            x.clone()
+                .filter(x.clone().gt_eq(lower).and(x.lt_eq(upper)))
+                .mean()
+        }
+        Statistic::IsSortedAsc | Statistic::IsSortedDesc
+            if dtype.is_nested()
+                || matches!(
+                    dtype,
+                    DataType::Binary | DataType::Null | DataType::Unknown(_)
+                ) =>
+        {
+            lit(NULL).cast(DataType::Boolean)
+        }
+        Statistic::IsSortedAsc => is_sorted_expr(values, false),
+        Statistic::IsSortedDesc => is_sorted_expr(values, true),
+        Statistic::TotalRows => len().cast(DataType::UInt64),
+        Statistic::IsConstant => is_constant_expr(values),
+        Statistic::AllNull => all_null_expr(values),
+        // Placeholder filled in from the materialized columns after the
+        // aggregation pass
+        Statistic::EstimatedSize => lit(NULL).cast(DataType::UInt64),
+        Statistic::ZScoreOutliers => {
+            let threshold = opts.zscore_threshold.unwrap_or(DEFAULT_ZSCORE_THRESHOLD);
+            let x = values.clone().cast(DataType::Float64);
+            let std = x.clone().std(1);
+            let zscores = (x.clone() - x.mean()).abs() / std.clone();
+            when(std.gt(lit(0.0)))
+                .then(zscores.gt(lit(threshold)).sum().cast(DataType::UInt32))
+                .otherwise(lit(NULL).cast(DataType::UInt32))
+        }
+    }
+}
+
+/// Whether the non-null values are ordered and the nulls sit at one end
+fn is_sorted_expr(values: &Expr, descending: bool) -> Expr {
+    let present = values.clone().drop_nulls();
+    let previous = present.clone().shift(lit(1));
+    // The first value has no predecessor; its null comparison is ignored
+    let ordered = if descending {
+        present.lt_eq(previous)
+    } else {
+        present.gt_eq(previous)
+    }
+    .all(true);
+
+    let is_null = values.clone().is_null();
+    let after = |expr: Expr| expr.shift(lit(1)).fill_null(lit(false));
+    let null_after_value = is_null.clone().and(after(is_null.clone().not()));
+    let value_after_null = is_null.clone().not().and(after(is_null));
+    let nulls_at_one_end = null_after_value
+        .any(true)
+        .not()
+        .or(value_after_null.any(true).not());
+
+    ordered.and(nulls_at_one_end)
+}
+
+/// Differences between the successive distinct non-null values of a Date
+/// or Datetime column, as physical integers of the returned Duration dtype
+///
+/// Repeated timestamps (e.g. several events at the same time) don't make
+/// zero-length intervals.
+fn sorted_intervals(values: &Expr, dtype: &DataType) -> (Expr, DataType) {
+    let (ticks, unit) = match dtype {
+        DataType::Datetime(unit, _) => (values.clone().to_physical(), *unit),
+        // Dates go through milliseconds, the unit of Date differences
+        _ => (
+            values
+                .clone()
+                .cast(DataType::Datetime(TimeUnit::Milliseconds, None))
+                .to_physical(),
+            TimeUnit::Milliseconds,
+        ),
+    };
+    let sorted = ticks.drop_nulls().unique().sort(SortOptions::default());
+    let intervals = (sorted.clone() - sorted.shift(lit(1))).drop_nulls();
+    (intervals, DataType::Duration(unit))
+}
+
+/// `values` as Float64 with NaN mapped to null, the input of every
+/// quantile-based statistic (percentiles, IQR, outlier fences, MAD, trimmed
+/// and weighted quantiles)
+///
+/// Polars sorts NaN above every number, so quantiles of a column with NaNs
+/// would land on NaN while min and max skip them.
+pub(crate) fn quantile_input(values: &Expr) -> Expr {
+    values.clone().cast(DataType::Float64).fill_nan(lit(NULL))
+}
+
+/// Linear Q3 - Q1; Duration and Time columns are aggregated on their
+/// physical integers like their percentile rows
+fn iqr_expr(values: &Expr, dtype: &DataType) -> Expr {
+    let quartiles = |x: Expr| {
+        x.clone().quantile(lit(0.75), QuantileMethod::Linear)
+            - x.quantile(lit(0.25), QuantileMethod::Linear)
+    };
+    match dtype {
+        dtype if dtype.is_numeric() => quartiles(quantile_input(values)),
+        DataType::Duration(_) => quartiles(values.clone().to_physical())
+            .cast(DataType::Int64)
+            .cast(dtype.clone()),
+        DataType::Time => quartiles(values.clone().to_physical())
+            .cast(DataType::Int64)
+            .cast(DataType::Duration(TimeUnit::Nanoseconds)),
+        _ => lit(NULL).cast(DataType::Float64),
+    }
+}
+
+/// `numerator` / count of non-null values, null without values
+fn ratio_of_count(values: &Expr, numerator: Expr) -> Expr {
+    let count = values.clone().count();
+    when(count.clone().gt(lit(0)))
+        .then(numerator.cast(DataType::Float64) / count.cast(DataType::Float64))
+        .otherwise(lit(NULL).cast(DataType::Float64))
+}
+
+/// `aggregate` of the column as Float64, or null if a value is 0 or
+/// negative
+fn positive_only(values: &Expr, aggregate: impl FnOnce(Expr) -> Expr) -> Expr {
+    let x = values.clone().cast(DataType::Float64);
+    when(x.clone().min().lt_eq(lit(0.0)))
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise(aggregate(x))
+}
+
+/// Jarque–Bera statistic from the central moments, null below
+/// `MIN_JARQUE_BERA_COUNT` values or without variance
+///
+/// The moments are spelled out (population estimates, as in the original
+/// test) because the mean broadcasts over the column, keeping this one
+/// aggregation.
+fn jarque_bera_expr(values: &Expr) -> Expr {
+    let x = values.clone().cast(DataType::Float64);
+    let deviation = x.clone() - x.clone().mean();
+    let moment = |power: i32| deviation.clone().pow(power).mean();
+    let variance = moment(2);
+    let skewness = moment(3) / variance.clone().pow(1.5);
+    let excess_kurtosis = moment(4) / variance.clone().pow(2) - lit(3.0);
+    let n = x.clone().count();
+    let jarque_bera = n.clone().cast(DataType::Float64) / lit(6.0)
+        * (skewness.pow(2) + excess_kurtosis.pow(2) / lit(4.0));
+    when(n.lt(lit(MIN_JARQUE_BERA_COUNT)).or(variance.eq(lit(0.0))))
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise(jarque_bera)
+}
+
+/// Two-sided z-value of a confidence `level` in (0, 1), e.g. 1.96 for 0.95
+pub(crate) fn z_value(level: f64) -> f64 {
+    Z_VALUES
+        .iter()
+        .find(|(known, _)| (known - level).abs() < 1e-12)
+        .map(|(_, z)| *z)
+        .unwrap_or_else(|| inverse_normal_cdf(1.0 - (1.0 - level) / 2.0))
+}
+
+/// Standard normal quantile Φ⁻¹(p) for p in (0, 1)
+///
+/// Acklam's rational approximation, accurate to about 1e-9 relative
+/// error, which is plenty for interval bounds.
+fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969_683_028_665_376e1,
+        2.209_460_984_245_205e2,
+        -2.759_285_104_469_687e2,
+        1.383_577_518_672_69e2,
+        -3.066_479_806_614_716e1,
+        2.506_628_277_459_239,
+    ];
+    const B: [f64; 5] = [
+        -5.447_609_879_822_406e1,
+        1.615_858_368_580_409e2,
+        -1.556_989_798_598_866e2,
+        6.680_131_188_771_972e1,
+        -1.328_068_155_288_572e1,
+    ];
+    const C: [f64; 6] = [
+        -7.784_894_002_430_293e-3,
+        -3.223_964_580_411_365e-1,
+        -2.400_758_277_161_838,
+        -2.549_732_539_343_734,
+        4.374_664_141_464_968,
+        2.938_163_982_698_783,
+    ];
+    const D: [f64; 4] = [
+        7.784_695_709_041_462e-3,
+        3.224_671_290_700_398e-1,
+        2.445_134_137_142_996,
+        3.754_408_661_907_416,
+    ];
+    const P_LOW: f64 = 0.024_25;
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+    if p < P_LOW {
+        tail((-2.0 * p.ln()).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * (1.0 - p).ln()).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Index of the first row holding the min (or max) value
+///
+/// Spelled out rather than `arg_min()`, which returns the last of several
+/// equal extremes when the column carries a sorted flag.
+fn arg_extreme_expr(values: &Expr, dtype: &DataType, max: bool) -> Expr {
+    // Decimals and temporal columns compare their physical values
+    let x = match dtype {
+        dtype if dtype.is_decimal() => values.clone().cast(DataType::Float64),
+        dtype if dtype.is_temporal() => values.clone().to_physical(),
+        _ => values.clone(),
+    };
+    let extreme = if max {
+        x.clone().max()
+    } else {
+        x.clone().min()
+    };
+    // The first `true` of the mask; an all-null column has no extreme
+    let mask = x.eq(extreme.clone()).fill_null(lit(false));
+    when(extreme.is_null())
+        .then(lit(NULL).cast(IDX_DTYPE))
+        .otherwise(mask.arg_max())
+}
+
+/// Row index of the first (or `last`) non-null value of `values`
+fn valid_index_expr(values: &Expr, last: bool) -> Expr {
+    let valid = values.clone().is_not_null();
+    // arg_max of a boolean mask is the position of its first true value
+    let index = if last {
+        (len().cast(DataType::Int64)
+            - lit(1i64)
+            - valid.clone().reverse().arg_max().cast(DataType::Int64))
+        .cast(IDX_DTYPE)
+    } else {
+        valid.clone().arg_max()
+    };
+    when(valid.any(true))
+        .then(index)
+        .otherwise(lit(NULL).cast(IDX_DTYPE))
+}
+
+fn is_constant_expr(values: &Expr) -> Expr {
+    values.clone().drop_nulls().n_unique().lt_eq(lit(1))
+}
+
+fn all_null_expr(values: &Expr) -> Expr {
+    values.clone().null_count().eq(len())
+}
+
+/// Names of the columns that are constant or entirely null, in schema order
+pub(crate) fn degenerate_columns_impl(lazy_frame: &LazyFrame) -> Result<Vec<String>> {
+    let schema = lazy_frame.clone().collect_schema()?;
+    if schema.is_empty() {
+        return Ok(Vec::new());
+    }
+    let exprs: Vec<Expr> = schema
+        .iter_names()
+        .map(|name| {
+            let values = col(name.clone());
+            is_constant_expr(&values)
+                .or(all_null_expr(&values))
+                .alias(name.clone())
+        })
+        .collect();
+    let flags = lazy_frame.clone().select(exprs).collect()?;
+
+    let mut names = Vec::new();
+    for column in flags.get_columns() {
+        if column.bool()?.get(0) == Some(true) {
+            names.push(column.name().to_string());
+        }
+    }
+    Ok(names)
+}
@@ -0,0 +1,109 @@
+//! Struct-typed describe output
+//!
+//! `describe_structs()` packs the statistics of every described column into
+//! a single Struct value, keeping each statistic in its native dtype, so the
+//! result round-trips through Parquet and Arrow and can be unnested one
+//! column at a time.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::{MetricRow, compute_metrics, styled_percentile_label};
+use crate::error::DescribeError;
+use crate::options::{DescribeOptions, LabelStyle, Progress};
+
+/// Struct field name of a statistic row: its label, with percentiles
+/// spelled as identifiers (e.g. 0.25 -> "p25", 0.999 -> "p99_9") whatever
+/// the label style
+fn field_name(row: MetricRow, percentiles: &[f64], opts: &DescribeOptions) -> String {
+    match row {
+        MetricRow::Percentile(i) => styled_percentile_label(percentiles[i], LabelStyle::Snake),
+        _ => row.label(percentiles, opts),
+    }
+}
+
+pub(crate) fn describe_structs_impl(
+    lazy_frame: &LazyFrame,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let metrics = compute_metrics(lazy_frame, opts)?;
+    let names: Vec<String> = metrics
+        .rows
+        .iter()
+        .map(|row| field_name(*row, &metrics.percentiles, opts))
+        .collect();
+    for (i, name) in names.iter().enumerate() {
+        if names[..i].contains(name) {
+            return Err(DescribeError::InvalidArgument(format!(
+                "statistic '{name}' appears twice as a struct field"
+            ))
+            .into());
+        }
+    }
+
+    let first = |col_name: &str| {
+        metrics
+            .frame
+            .try_get_column_index(&MetricRow::Count.alias(&metrics.percentiles, col_name))
+    };
+    let mut columns = Vec::with_capacity(metrics.schema.len());
+    for (col_name, _) in metrics.ordered_columns(opts)? {
+        // The metrics of a column sit next to each other, in `rows` order
+        let start = first(col_name)?;
+        let fields: Vec<Column> = metrics.frame.get_columns()[start..start + names.len()]
+            .iter()
+            .zip(&names)
+            .map(|(column, name)| column.clone().with_name(name.into()))
+            .collect();
+        let packed = StructChunked::from_columns(col_name.clone(), 1, &fields)?;
+        columns.push(packed.into_column());
+    }
+    opts.progress(Progress::ReshapeDone);
+
+    DataFrame::new(columns).map_err(Into::into)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_structs_unnests_to_describe_values() -> Result<()> {
+        let df = df! {
+            "price" => [Some(1.5), Some(2.5), None, Some(4.0)],
+            "name" => ["a", "b", "c", "d"],
+        }?;
+        let opts = DescribeOptions::new().percentiles(vec![0.25, 0.999]);
+        let structs = df.describe_structs(&opts)?;
+        assert_eq!(structs.shape(), (1, 2));
+
+        let price = structs.select(["price"])?.unnest(["price"])?;
+        let fields = [
+            "count",
+            "null_count",
+            "mean",
+            "std",
+            "min",
+            "p25",
+            "p99_9",
+            "max",
+        ];
+        assert_eq!(price.get_column_names_str(), fields);
+        assert_eq!(price.column("count")?.dtype(), &DataType::UInt64);
+        assert_eq!(price.column("min")?.dtype(), &DataType::Float64);
+
+        let report = df.describe_report(&opts)?;
+        let summary = &report.columns[0];
+        let value = |field: &str| -> Result<Option<f64>> {
+            Ok(price.column(field)?.get(0)?.extract::<f64>())
+        };
+        assert_eq!(price.column("count")?.u64()?.get(0), Some(summary.count));
+        assert_eq!(value("mean")?, summary.mean);
+        assert_eq!(value("std")?, summary.std);
+        assert_eq!(value("p25")?, summary.percentiles.get("25%").copied());
+        assert_eq!(value("p99_9")?, summary.percentiles.get("99.9%").copied());
+        assert_eq!(value("max")?, Some(4.0));
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+//! Compensated mean and std for `DescribeOptions::high_precision`
+//!
+//! Polars sums floats pairwise, which is fast but still loses small values
+//! next to huge ones, and keeps Float32 results in Float32. Here the values
+//! are cast to Float64 and summed sequentially with Neumaier's variant of
+//! Kahan summation, whose error doesn't grow with the number of values.
+//!
+//! The sums run as a groupwise UDF, so each column (or group) is
+//! materialized and summed on one thread, two passes for the std.
+//! Expect several times the cost of the default mean/std.
+
+use polars::prelude::*;
+
+/// Neumaier-compensated sum of `values`
+pub(crate) fn neumaier_sum(values: impl IntoIterator<Item = f64>) -> f64 {
+    let (mut sum, mut compensation) = (0.0f64, 0.0f64);
+    for value in values {
+        let total = sum + value;
+        // Recover the low-order bits lost by whichever operand is smaller
+        if sum.abs() >= value.abs() {
+            compensation += (sum - total) + value;
+        } else {
+            compensation += (value - total) + sum;
+        }
+        sum = total;
+    }
+    sum + compensation
+}
+
+/// Compensated sum of the non-null values of `values` as one Float64
+fn compensated_sum(values: Expr) -> Expr {
+    values
+        .cast(DataType::Float64)
+        .drop_nulls()
+        .apply(
+            |column| {
+                let sum = neumaier_sum(column.f64()?.into_no_null_iter());
+                Ok(Column::new(column.name().clone(), [sum]))
+            },
+            |_, field| Ok(Field::new(field.name().clone(), DataType::Float64)),
+        )
+        .first()
+}
+
+/// Mean of `values`; null without non-null values
+pub(crate) fn compensated_mean(values: &Expr) -> Expr {
+    let count = values.clone().count().cast(DataType::Float64);
+    when(count.clone().eq(lit(0.0)))
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise(compensated_sum(values.clone()) / count)
+}
+
+/// Sample std (ddof = 1) of `values` around the compensated mean; null
+/// below two values
+pub(crate) fn compensated_std(values: &Expr) -> Expr {
+    let x = values.clone().cast(DataType::Float64);
+    let count = x.clone().count().cast(DataType::Float64);
+    let squares = (x.clone() - compensated_mean(&x)).pow(2);
+    when(count.clone().lt(lit(2.0)))
+        .then(lit(NULL).cast(DataType::Float64))
+        .otherwise((compensated_sum(squares) / (count - lit(1.0))).sqrt())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Describable, DescribeOptions};
+
+    #[test]
+    fn test_neumaier_sum() {
+        let values = [1e16, 1.0, 1.0, -1e16];
+        assert_eq!(values.iter().sum::<f64>(), 0.0);
+        assert_eq!(neumaier_sum(values), 2.0);
+    }
+
+    #[test]
+    fn test_high_precision_mean() -> anyhow::Result<()> {
+        // One huge value cancelled by its negation around a million ones;
+        // pairwise summation drops most of the ones
+        let ones = 1_000_000;
+        let mut values = vec![1e16f32];
+        values.extend(std::iter::repeat_n(1.0f32, ones));
+        values.push(-1e16);
+        let df = df! { "x" => values }?;
+        let expected = ones as f64 / (ones + 2) as f64;
+
+        let mean = |opts: DescribeOptions| -> anyhow::Result<f64> {
+            let stats = df.describe_with(&opts.precision(12))?;
+            Ok(stats.column("x")?.str()?.get(2).unwrap().parse()?)
+        };
+        let default = mean(DescribeOptions::new())?;
+        let precise = mean(DescribeOptions::new().high_precision(true))?;
+        assert!((default - expected).abs() > 1e-6, "{default}");
+        assert!((precise - expected).abs() < 1e-12, "{precise}");
+        Ok(())
+    }
+
+    #[test]
+    fn test_high_precision_matches_default_on_plain_data() -> anyhow::Result<()> {
+        let df = df! {
+            "x" => [Some(1.5), None, Some(-2.0), Some(4.25)],
+            "n" => [Some(3i64), Some(1), None, Some(7)],
+            "single" => [Some(1.0), None, None, None],
+            "empty" => [Option::<f64>::None, None, None, None],
+        }?;
+        let opts = DescribeOptions::new().precision(6);
+        let precise = df.describe_with(&opts.clone().high_precision(true))?;
+        assert!(
+            precise.equals_missing(&df.describe_with(&opts)?),
+            "{precise}"
+        );
+        Ok(())
+    }
+}
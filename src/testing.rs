@@ -0,0 +1,524 @@
+//! Helpers for testing code that consumes describe output
+//!
+//! `fixture_report` builds a fully populated, internally consistent
+//! `DescribeReport` from a compact spec, so downstream tests don't have to
+//! hand-roll fake reports (or describe real data) to exercise their code.
+//!
+//! `assert_describe_close` (and the `assert_describe_close!` macro) compares
+//! two describe outputs with numeric tolerances instead of exact strings.
+
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::percentile_label;
+use crate::error::DescribeError;
+use crate::report::{ColumnSummary, DescribeReport, StatValue};
+
+/// Compact description of a fake report: a row count and its columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixtureSpec {
+    len: u64,
+    columns: Vec<FixtureColumn>,
+}
+
+impl FixtureSpec {
+    /// A spec for a frame with `len` rows and no columns yet
+    pub fn new(len: u64) -> Self {
+        Self {
+            len,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Add a column to the spec
+    pub fn column(mut self, column: FixtureColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+}
+
+/// Chosen statistics for one fixture column
+///
+/// Statistics that are not set are left null in the report, except `count`
+/// which is always derived as `len - null_count`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FixtureColumn {
+    name: String,
+    kind: FixtureKind,
+    null_count: u64,
+    mean: Option<f64>,
+    std: Option<f64>,
+    min: Option<FixtureValue>,
+    max: Option<FixtureValue>,
+    percentiles: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FixtureKind {
+    Numeric,
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum FixtureValue {
+    Number(f64),
+    Text(String),
+}
+
+impl FixtureColumn {
+    fn new(name: &str, kind: FixtureKind) -> Self {
+        Self {
+            name: name.to_string(),
+            kind,
+            null_count: 0,
+            mean: None,
+            std: None,
+            min: None,
+            max: None,
+            percentiles: Vec::new(),
+        }
+    }
+
+    /// A Float64 column
+    pub fn numeric(name: &str) -> Self {
+        Self::new(name, FixtureKind::Numeric)
+    }
+
+    /// A String column; only min/max may be set
+    pub fn text(name: &str) -> Self {
+        Self::new(name, FixtureKind::Text)
+    }
+
+    /// Number of nulls among the `len` rows of the spec
+    pub fn null_count(mut self, null_count: u64) -> Self {
+        self.null_count = null_count;
+        self
+    }
+
+    /// Mean of a numeric column
+    pub fn mean(mut self, mean: f64) -> Self {
+        self.mean = Some(mean);
+        self
+    }
+
+    /// Standard deviation of a numeric column
+    pub fn std(mut self, std: f64) -> Self {
+        self.std = Some(std);
+        self
+    }
+
+    /// Minimum of a numeric column
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(FixtureValue::Number(min));
+        self
+    }
+
+    /// Maximum of a numeric column
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(FixtureValue::Number(max));
+        self
+    }
+
+    /// Minimum of a text column
+    pub fn min_str(mut self, min: &str) -> Self {
+        self.min = Some(FixtureValue::Text(min.to_string()));
+        self
+    }
+
+    /// Maximum of a text column
+    pub fn max_str(mut self, max: &str) -> Self {
+        self.max = Some(FixtureValue::Text(max.to_string()));
+        self
+    }
+
+    /// Value of the percentile `p` (between 0.0 and 1.0)
+    pub fn percentile(mut self, p: f64, value: f64) -> Self {
+        self.percentiles.push((p, value));
+        self
+    }
+}
+
+/// Build a `DescribeReport` from `spec`, checking that it is internally
+/// consistent
+///
+/// # Errors
+/// Returns an error when the spec describes an impossible report, e.g.
+/// `null_count > len`, `min > max`, a percentile outside [min, max],
+/// percentiles that decrease as `p` grows, a mean outside [min, max], a
+/// negative std, statistics on a column with no non-null values, or numeric
+/// statistics on a text column.
+pub fn fixture_report(spec: FixtureSpec) -> Result<DescribeReport> {
+    let mut columns = Vec::with_capacity(spec.columns.len());
+    for column in spec.columns {
+        validate_column(&column, spec.len)?;
+        columns.push(summary_from_column(column, spec.len));
+    }
+    Ok(DescribeReport {
+        columns,
+        skipped_columns: 0,
+    })
+}
+
+fn validate_column(column: &FixtureColumn, len: u64) -> Result<()> {
+    let name = &column.name;
+    let fail = |msg: String| {
+        Err(DescribeError::InvalidArgument(format!("fixture column '{name}': {msg}")).into())
+    };
+
+    if column.null_count > len {
+        return fail(format!(
+            "null_count {} exceeds the row count {len}",
+            column.null_count
+        ));
+    }
+
+    let has_stats = column.mean.is_some()
+        || column.std.is_some()
+        || column.min.is_some()
+        || column.max.is_some()
+        || !column.percentiles.is_empty();
+    if len == column.null_count && has_stats {
+        return fail("statistics set on a column without non-null values".to_string());
+    }
+
+    if column.kind == FixtureKind::Text {
+        if column.mean.is_some() || column.std.is_some() || !column.percentiles.is_empty() {
+            return fail("mean, std and percentiles only apply to numeric columns".to_string());
+        }
+        return match (&column.min, &column.max) {
+            (Some(FixtureValue::Text(min)), Some(FixtureValue::Text(max))) if min > max => {
+                fail(format!("min '{min}' is greater than max '{max}'"))
+            }
+            (Some(FixtureValue::Number(_)), _) | (_, Some(FixtureValue::Number(_))) => {
+                fail("text columns need min_str/max_str".to_string())
+            }
+            _ => Ok(()),
+        };
+    }
+
+    let number = |value: &Option<FixtureValue>| -> Result<Option<f64>> {
+        match value {
+            None => Ok(None),
+            Some(FixtureValue::Number(v)) => Ok(Some(*v)),
+            Some(FixtureValue::Text(_)) => Err(DescribeError::InvalidArgument(format!(
+                "fixture column '{name}': numeric columns need min/max, not min_str/max_str"
+            ))
+            .into()),
+        }
+    };
+    let min = number(&column.min)?;
+    let max = number(&column.max)?;
+
+    if let (Some(min), Some(max)) = (min, max)
+        && min > max
+    {
+        return fail(format!("min {min} is greater than max {max}"));
+    }
+    let in_range = |v: f64| min.is_none_or(|min| min <= v) && max.is_none_or(|max| v <= max);
+
+    if let Some(mean) = column.mean
+        && !in_range(mean)
+    {
+        return fail(format!("mean {mean} lies outside [min, max]"));
+    }
+    if let Some(std) = column.std
+        && std < 0.0
+    {
+        return fail(format!("std {std} is negative"));
+    }
+
+    let mut percentiles = column.percentiles.clone();
+    percentiles.sort_by(|a, b| a.0.total_cmp(&b.0));
+    for (p, value) in &percentiles {
+        if !(0.0..=1.0).contains(p) {
+            return fail(format!("percentile {p} is outside the range [0, 1]"));
+        }
+        if !in_range(*value) {
+            return fail(format!(
+                "percentile {p} value {value} lies outside [min, max]"
+            ));
+        }
+    }
+    for pair in percentiles.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            return fail(format!("percentile {} is set more than once", pair[0].0));
+        }
+        if pair[0].1 > pair[1].1 {
+            return fail(format!(
+                "percentile {} value {} is greater than percentile {} value {}",
+                pair[0].0, pair[0].1, pair[1].0, pair[1].1
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn summary_from_column(column: FixtureColumn, len: u64) -> ColumnSummary {
+    let render = |value: Option<FixtureValue>| {
+        value.map(|value| match value {
+            FixtureValue::Number(v) => StatValue::Float(v),
+            FixtureValue::Text(s) => StatValue::Str(s),
+        })
+    };
+
+    let percentiles: BTreeMap<String, f64> = column
+        .percentiles
+        .iter()
+        .map(|(p, value)| (percentile_label(*p), *value))
+        .collect();
+
+    ColumnSummary {
+        dtype: match column.kind {
+            FixtureKind::Numeric => "f64".to_string(),
+            FixtureKind::Text => "str".to_string(),
+        },
+        name: column.name,
+        count: len - column.null_count,
+        null_count: column.null_count,
+        mean: column.mean,
+        std: column.std,
+        min: render(column.min),
+        max: render(column.max),
+        percentiles,
+        ..ColumnSummary::default()
+    }
+}
+
+/// Assert that two `describe()` outputs match statistic by statistic
+///
+/// Values that parse as numbers on both sides are equal when
+/// `|actual - expected| <= abs_tol + rel_tol * |expected|`; everything else
+/// (min/max strings, dtypes) must be equal. A null on one side only is a
+/// mismatch, as is a column or statistic present on one side only.
+///
+/// # Panics
+/// Panics with a report listing every diverging column/statistic.
+#[track_caller]
+pub fn assert_describe_close(actual: &DataFrame, expected: &DataFrame, rel_tol: f64, abs_tol: f64) {
+    let mismatches = describe_mismatches(actual, expected, rel_tol, abs_tol);
+    assert!(
+        mismatches.is_empty(),
+        "describe outputs differ (rel_tol = {rel_tol}, abs_tol = {abs_tol}):\n  {}",
+        mismatches.join("\n  ")
+    );
+}
+
+/// Assert that two `describe()` outputs match within tolerances
+///
+/// `assert_describe_close!(actual, expected)` uses a relative tolerance of
+/// `1e-9`; pass `rel_tol, abs_tol` explicitly to loosen it. See
+/// `testing::assert_describe_close`.
+#[macro_export]
+macro_rules! assert_describe_close {
+    ($actual:expr, $expected:expr $(,)?) => {
+        $crate::testing::assert_describe_close(&$actual, &$expected, 1e-9, 0.0)
+    };
+    ($actual:expr, $expected:expr, $rel_tol:expr, $abs_tol:expr $(,)?) => {
+        $crate::testing::assert_describe_close(&$actual, &$expected, $rel_tol, $abs_tol)
+    };
+}
+
+/// One line per diverging value, in the order of `expected`
+fn describe_mismatches(
+    actual: &DataFrame,
+    expected: &DataFrame,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> Vec<String> {
+    let (Some(actual_rows), Some(expected_rows)) =
+        (statistic_labels(actual), statistic_labels(expected))
+    else {
+        return vec!["both frames need a String `statistic` column".to_string()];
+    };
+
+    let mut mismatches = Vec::new();
+    for label in &actual_rows {
+        if !expected_rows.contains(label) {
+            mismatches.push(format!("unexpected statistic '{label}'"));
+        }
+    }
+    for label in &expected_rows {
+        if !actual_rows.contains(label) {
+            mismatches.push(format!("missing statistic '{label}'"));
+        }
+    }
+    for name in actual.get_column_names() {
+        if expected.column(name).is_err() {
+            mismatches.push(format!("unexpected column '{name}'"));
+        }
+    }
+
+    for expected_col in expected.get_columns() {
+        let name = expected_col.name();
+        if name == "statistic" {
+            continue;
+        }
+        let Ok(actual_col) = actual.column(name) else {
+            mismatches.push(format!("missing column '{name}'"));
+            continue;
+        };
+        let (Ok(actual_values), Ok(expected_values)) = (actual_col.str(), expected_col.str())
+        else {
+            mismatches.push(format!("column '{name}' is not a String column"));
+            continue;
+        };
+
+        for (expected_idx, label) in expected_rows.iter().enumerate() {
+            let Some(actual_idx) = actual_rows.iter().position(|l| l == label) else {
+                continue;
+            };
+            let a = actual_values.get(actual_idx);
+            let e = expected_values.get(expected_idx);
+            if let Some(problem) = compare_values(a, e, rel_tol, abs_tol) {
+                mismatches.push(format!("{name} / {label}: {problem}"));
+            }
+        }
+    }
+    mismatches
+}
+
+fn statistic_labels(df: &DataFrame) -> Option<Vec<String>> {
+    let labels = df.column("statistic").ok()?.str().ok()?;
+    Some(
+        labels
+            .into_iter()
+            .map(|l| l.unwrap_or("null").to_string())
+            .collect(),
+    )
+}
+
+/// Describe why `actual` doesn't match `expected`, if it doesn't
+///
+/// describe() renders nulls as the string "null", so that counts as a null
+/// here too.
+fn compare_values(
+    actual: Option<&str>,
+    expected: Option<&str>,
+    rel_tol: f64,
+    abs_tol: f64,
+) -> Option<String> {
+    let actual = actual.filter(|v| *v != "null");
+    let expected = expected.filter(|v| *v != "null");
+    match (actual, expected) {
+        (None, None) => None,
+        (None, Some(e)) => Some(format!("expected {e}, got null")),
+        (Some(a), None) => Some(format!("expected null, got {a}")),
+        (Some(a), Some(e)) => match (a.parse::<f64>(), e.parse::<f64>()) {
+            (Ok(x), Ok(y)) if x.is_nan() && y.is_nan() => None,
+            (Ok(x), Ok(y)) => {
+                let diff = (x - y).abs();
+                (diff > abs_tol + rel_tol * y.abs() && x != y)
+                    .then(|| format!("expected {e}, got {a} (off by {diff})"))
+            }
+            _ => (a != e).then(|| format!("expected '{e}', got '{a}'")),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn spec() -> FixtureSpec {
+        FixtureSpec::new(10)
+            .column(
+                FixtureColumn::numeric("price")
+                    .null_count(2)
+                    .mean(12.5)
+                    .std(3.0)
+                    .min(5.0)
+                    .percentile(0.25, 10.0)
+                    .percentile(0.5, 12.0)
+                    .percentile(0.75, 15.0)
+                    .max(20.0),
+            )
+            .column(FixtureColumn::text("name").min_str("apple").max_str("pear"))
+    }
+
+    #[test]
+    fn test_fixture_report_renders_all_formats() -> Result<()> {
+        let report = fixture_report(spec())?;
+
+        let price = report.column("price").unwrap();
+        assert_eq!(price.count, 8);
+        assert_eq!(price.percentiles["50%"], 12.0);
+
+        let df = report.to_dataframe()?;
+        assert_eq!(df.shape(), (9, 3));
+        let prices = df.column("price")?.str()?;
+        assert_eq!(prices.get(0), Some("8"));
+        assert_eq!(prices.get(4), Some("5.0"));
+        assert_eq!(df.column("name")?.str()?.get(8), Some("pear"));
+
+        #[cfg(feature = "serde")]
+        {
+            let json = serde_json::to_string(&report)?;
+            let parsed: DescribeReport = serde_json::from_str(&json)?;
+            assert_eq!(parsed, report);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_assert_describe_close_within_tolerance() -> Result<()> {
+        let actual = df! { "x" => [1.0, 2.0, 3.0], "s" => ["a", "b", "c"] }?.describe(None)?;
+        let expected = df! { "x" => [1.0, 2.0, 3.001], "s" => ["a", "b", "c"] }?.describe(None)?;
+
+        assert_describe_close(&actual, &expected, 1e-3, 0.0);
+        crate::assert_describe_close!(actual, actual.clone());
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic(expected = "x / mean: expected 3.0, got 2.0 (off by 1)")]
+    fn test_assert_describe_close_reports_divergence() {
+        let actual = df! { "x" => [1.0, 2.0, 3.0] }
+            .unwrap()
+            .describe(None)
+            .unwrap();
+        let expected = df! { "x" => [2.0, 3.0, 4.0] }
+            .unwrap()
+            .describe(None)
+            .unwrap();
+        crate::assert_describe_close!(actual, expected, 0.0, 0.5);
+    }
+
+    #[test]
+    fn test_describe_mismatches_nulls_and_columns() -> Result<()> {
+        let actual = df! { "x" => [Some(1.0), None] }?.describe(None)?;
+        let expected = df! { "x" => [1.0, 3.0], "y" => [1, 2] }?.describe(None)?;
+
+        let mismatches = describe_mismatches(&actual, &expected, 0.0, 0.0);
+        assert!(mismatches.contains(&"missing column 'y'".to_string()));
+        assert!(mismatches.contains(&"x / std: expected 1.414214, got null".to_string()));
+        assert!(mismatches.contains(&"x / null_count: expected 0, got 1 (off by 1)".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_fixture_report_rejects_inconsistent_specs() {
+        let min_above_max =
+            FixtureSpec::new(5).column(FixtureColumn::numeric("x").min(3.0).max(1.0));
+        let err = fixture_report(min_above_max).unwrap_err();
+        assert!(err.to_string().contains("min 3 is greater than max 1"));
+
+        let decreasing = FixtureSpec::new(5).column(
+            FixtureColumn::numeric("x")
+                .percentile(0.25, 4.0)
+                .percentile(0.75, 2.0),
+        );
+        assert!(fixture_report(decreasing).is_err());
+
+        let too_many_nulls = FixtureSpec::new(5).column(FixtureColumn::numeric("x").null_count(6));
+        assert!(fixture_report(too_many_nulls).is_err());
+
+        let text_min_above_max =
+            FixtureSpec::new(5).column(FixtureColumn::text("s").min_str("z").max_str("a"));
+        assert!(fixture_report(text_min_above_max).is_err());
+    }
+}
@@ -0,0 +1,357 @@
+//! Data contracts checked against describe statistics
+//!
+//! `Expectations` collects rules such as "the mean of price lies between 10
+//! and 500"; `validate()` describes the columns the rules name in one pass
+//! and reports every rule with its observed value. Rules never error on bad
+//! data: a violated rule, or one naming a column the frame doesn't have, is
+//! a failed entry of the `ValidationReport`.
+
+use std::fmt;
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_report_impl;
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+use crate::report::{ColumnSummary, StatValue};
+
+/// Rules on the describe statistics of one or more columns
+///
+/// `col` selects the column the following rules apply to.
+///
+/// # Example
+/// ```rust
+/// use polars::prelude::*;
+/// use describe_df::{Describable, Expectations};
+///
+/// # fn main() -> anyhow::Result<()> {
+/// let df = df! { "price" => [12.0, 48.5, 230.0], "user_id" => [1, 2, 3] }?;
+/// let expectations = Expectations::new()
+///     .col("price")
+///     .mean_between(10.0, 500.0)
+///     .col("user_id")
+///     .max_null_pct(0.0);
+///
+/// let report = df.validate(&expectations)?;
+/// assert!(report.is_ok(), "{report}");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Expectations {
+    current: Option<String>,
+    rules: Vec<(Option<String>, Rule)>,
+}
+
+/// A single check on one statistic
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Rule {
+    MeanBetween(f64, f64),
+    StdBetween(f64, f64),
+    MinAtLeast(f64),
+    MaxAtMost(f64),
+    MinCount(u64),
+    MaxNullCount(u64),
+    MaxNullPct(f64),
+}
+
+impl Rule {
+    /// Observed value of the checked statistic, or why there is none
+    fn observe(self, summary: &ColumnSummary) -> std::result::Result<f64, String> {
+        let numeric = |value: Option<f64>, stat: &str| {
+            value.ok_or_else(|| format!("no {stat} for a {} column", summary.dtype))
+        };
+        match self {
+            Rule::MeanBetween(..) => numeric(summary.mean, "mean"),
+            Rule::StdBetween(..) => numeric(summary.std, "std"),
+            Rule::MinAtLeast(_) => numeric(summary.min.as_ref().and_then(stat_f64), "numeric min"),
+            Rule::MaxAtMost(_) => numeric(summary.max.as_ref().and_then(stat_f64), "numeric max"),
+            Rule::MinCount(_) => Ok(summary.count as f64),
+            Rule::MaxNullCount(_) => Ok(summary.null_count as f64),
+            Rule::MaxNullPct(_) => {
+                let rows = summary.count + summary.null_count;
+                if rows == 0 {
+                    Ok(0.0)
+                } else {
+                    Ok(summary.null_count as f64 / rows as f64 * 100.0)
+                }
+            }
+        }
+    }
+
+    fn holds(self, observed: f64) -> bool {
+        match self {
+            Rule::MeanBetween(low, high) | Rule::StdBetween(low, high) => {
+                (low..=high).contains(&observed)
+            }
+            Rule::MinAtLeast(low) => observed >= low,
+            Rule::MaxAtMost(high) => observed <= high,
+            Rule::MinCount(min) => observed >= min as f64,
+            Rule::MaxNullCount(max) => observed <= max as f64,
+            Rule::MaxNullPct(max) => observed <= max,
+        }
+    }
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::MeanBetween(low, high) => write!(f, "mean between {low} and {high}"),
+            Rule::StdBetween(low, high) => write!(f, "std between {low} and {high}"),
+            Rule::MinAtLeast(low) => write!(f, "min at least {low}"),
+            Rule::MaxAtMost(high) => write!(f, "max at most {high}"),
+            Rule::MinCount(min) => write!(f, "count at least {min}"),
+            Rule::MaxNullCount(max) => write!(f, "null_count at most {max}"),
+            Rule::MaxNullPct(max) => write!(f, "null_pct at most {max}%"),
+        }
+    }
+}
+
+/// Numeric value of a min/max, None for non-numeric kinds
+fn stat_f64(value: &StatValue) -> Option<f64> {
+    match value {
+        StatValue::Int(v) => Some(*v as f64),
+        StatValue::UInt(v) => Some(*v as f64),
+        StatValue::Float(v) => Some(*v),
+        _ => None,
+    }
+}
+
+impl Expectations {
+    /// Expectations without any rule, which every frame passes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply the following rules to the column `name`
+    pub fn col(mut self, name: &str) -> Self {
+        self.current = Some(name.to_string());
+        self
+    }
+
+    /// The mean lies in `[low, high]`
+    pub fn mean_between(self, low: f64, high: f64) -> Self {
+        self.rule(Rule::MeanBetween(low, high))
+    }
+
+    /// The sample std lies in `[low, high]`
+    pub fn std_between(self, low: f64, high: f64) -> Self {
+        self.rule(Rule::StdBetween(low, high))
+    }
+
+    /// The min of a numeric column is at least `low`
+    pub fn min_at_least(self, low: f64) -> Self {
+        self.rule(Rule::MinAtLeast(low))
+    }
+
+    /// The max of a numeric column is at most `high`
+    pub fn max_at_most(self, high: f64) -> Self {
+        self.rule(Rule::MaxAtMost(high))
+    }
+
+    /// At least `min` non-null values
+    pub fn min_count(self, min: u64) -> Self {
+        self.rule(Rule::MinCount(min))
+    }
+
+    /// At most `max` nulls
+    pub fn max_null_count(self, max: u64) -> Self {
+        self.rule(Rule::MaxNullCount(max))
+    }
+
+    /// At most `max_pct` percent of the rows are null; an empty column has
+    /// 0% nulls
+    pub fn max_null_pct(self, max_pct: f64) -> Self {
+        self.rule(Rule::MaxNullPct(max_pct))
+    }
+
+    fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push((self.current.clone(), rule));
+        self
+    }
+}
+
+/// Outcome of one rule of `Expectations`
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuleResult {
+    /// Column the rule checks
+    pub column: String,
+    /// Human-readable rule, e.g. `mean between 10 and 500`
+    pub rule: String,
+    /// Value of the checked statistic; None when it couldn't be computed
+    pub observed: Option<f64>,
+    /// Whether the observed value satisfies the rule; false when it
+    /// couldn't be checked
+    pub passed: bool,
+    /// Why the rule couldn't be checked (missing column, non-numeric
+    /// column), None otherwise
+    pub message: Option<String>,
+}
+
+/// Result of `Describable::validate`, one entry per rule in the order the
+/// rules were added
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ValidationReport {
+    pub results: Vec<RuleResult>,
+}
+
+impl ValidationReport {
+    /// Whether every rule passed
+    pub fn is_ok(&self) -> bool {
+        self.results.iter().all(|result| result.passed)
+    }
+
+    /// The rules that failed
+    pub fn failures(&self) -> impl Iterator<Item = &RuleResult> {
+        self.results.iter().filter(|result| !result.passed)
+    }
+}
+
+/// One line per rule, e.g. `FAIL price: mean between 10 and 500 (observed 612.5)`
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for result in &self.results {
+            let status = if result.passed { "PASS" } else { "FAIL" };
+            write!(f, "{status} {}: {}", result.column, result.rule)?;
+            match (&result.message, result.observed) {
+                (Some(message), _) => writeln!(f, " ({message})")?,
+                (None, Some(observed)) => writeln!(f, " (observed {observed})")?,
+                (None, None) => writeln!(f)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+pub(crate) fn validate_impl(
+    lazy_frame: &LazyFrame,
+    expectations: &Expectations,
+) -> Result<ValidationReport> {
+    if let Some((_, rule)) = expectations
+        .rules
+        .iter()
+        .find(|(column, _)| column.is_none())
+    {
+        return Err(DescribeError::InvalidArgument(format!(
+            "expectation '{rule}' has no column; call col() before adding rules"
+        ))
+        .into());
+    }
+
+    let schema = lazy_frame.clone().collect_schema()?;
+    let mut described: Vec<&str> = Vec::new();
+    for (column, _) in &expectations.rules {
+        let column = column.as_deref().unwrap_or_default();
+        if schema.contains(column) && !described.contains(&column) {
+            described.push(column);
+        }
+    }
+    let report = if described.is_empty() {
+        Default::default()
+    } else {
+        // Rules only read the moment statistics, percentiles aren't needed
+        let opts = DescribeOptions::new()
+            .percentiles(vec![])
+            .columns(described);
+        describe_report_impl(lazy_frame, &opts)?
+    };
+
+    let results = expectations
+        .rules
+        .iter()
+        .map(|(column, rule)| {
+            let column = column.clone().unwrap_or_default();
+            let observed = match report.column(&column) {
+                Some(summary) => rule.observe(summary),
+                None => Err(format!("column '{column}' not found")),
+            };
+            let (observed, message) = match observed {
+                Ok(value) => (Some(value), None),
+                Err(message) => (None, Some(message)),
+            };
+            RuleResult {
+                passed: observed.is_some_and(|value| rule.holds(value)),
+                column,
+                rule: rule.to_string(),
+                observed,
+                message,
+            }
+        })
+        .collect();
+    Ok(ValidationReport { results })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn sample() -> Result<DataFrame> {
+        Ok(df! {
+            "price" => [Some(10.0), Some(20.0), Some(60.0), None],
+            "user_id" => [1, 2, 3, 4],
+            "city" => ["Oslo", "Bergen", "Oslo", "Tromsø"],
+        }?)
+    }
+
+    #[test]
+    fn test_validate_passing_and_failing_rules() -> Result<()> {
+        let expectations = Expectations::new()
+            .col("price")
+            .mean_between(10.0, 500.0)
+            .max_null_pct(10.0)
+            .max_at_most(100.0)
+            .col("user_id")
+            .max_null_pct(0.0)
+            .min_count(5);
+        let report = sample()?.validate(&expectations)?;
+
+        let passed: Vec<bool> = report.results.iter().map(|r| r.passed).collect();
+        assert_eq!(passed, [true, false, true, true, false]);
+        assert!(!report.is_ok());
+        assert_eq!(report.results[0].observed, Some(30.0));
+        assert_eq!(report.results[1].observed, Some(25.0));
+        assert_eq!(report.results[1].rule, "null_pct at most 10%");
+        let failed: Vec<&str> = report.failures().map(|r| r.column.as_str()).collect();
+        assert_eq!(failed, ["price", "user_id"]);
+        assert!(
+            report
+                .to_string()
+                .contains("FAIL user_id: count at least 5 (observed 4)")
+        );
+
+        let ok = sample()?
+            .lazy()
+            .validate(&Expectations::new().col("price").min_at_least(10.0))?;
+        assert!(ok.is_ok());
+        Ok(())
+    }
+
+    #[test]
+    fn test_validate_missing_and_non_numeric_columns() -> Result<()> {
+        let expectations = Expectations::new()
+            .col("prices")
+            .max_null_count(0)
+            .col("city")
+            .mean_between(0.0, 1.0);
+        let report = sample()?.validate(&expectations)?;
+
+        assert!(!report.is_ok());
+        let [missing, city] = &report.results[..] else {
+            panic!("expected two results, got {report:?}");
+        };
+        assert_eq!(missing.observed, None);
+        assert_eq!(
+            missing.message.as_deref(),
+            Some("column 'prices' not found")
+        );
+        assert_eq!(city.message.as_deref(), Some("no mean for a str column"));
+
+        let err = sample()?
+            .validate(&Expectations::new().max_null_count(0))
+            .unwrap_err();
+        assert!(err.to_string().contains("call col()"), "{err}");
+        Ok(())
+    }
+}
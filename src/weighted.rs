@@ -0,0 +1,221 @@
+//! Weighted mean, std and percentiles for `describe_weighted()`
+//!
+//! Weights come from a numeric column of the described frame. Null weights
+//! count as zero, so those rows don't contribute to the weighted statistics;
+//! negative weights, or weights summing to zero, are an error. The weight column itself is not described.
+
+use anyhow::Result;
+use polars::prelude::*;
+
+use crate::describe::describe_lazy_impl;
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+pub(crate) fn describe_weighted_impl(
+    lazy_frame: &LazyFrame,
+    weight_col: &str,
+    percentiles: Option<Vec<f64>>,
+) -> Result<DataFrame> {
+    let mut opts = DescribeOptions::from_percentiles(percentiles);
+    opts.weight_col = Some(weight_col.to_string());
+    describe_lazy_impl(lazy_frame, &opts)
+}
+
+/// Check the weight column exists and is numeric
+pub(crate) fn validate_weight_column(schema: &Schema, weight_col: &str) -> Result<()> {
+    match schema.get(weight_col) {
+        None => Err(DescribeError::ColumnNotFound(weight_col.to_string()).into()),
+        Some(dtype) if !dtype.is_numeric() => Err(DescribeError::UnsupportedDtype {
+            column: weight_col.to_string(),
+            dtype: dtype.clone(),
+            expected: "numeric",
+        }
+        .into()),
+        Some(_) => Ok(()),
+    }
+}
+
+const NEGATIVE_WEIGHTS_ALIAS: &str = "__negative_weights";
+const ZERO_WEIGHTS_ALIAS: &str = "__zero_weights";
+
+/// Expressions flagging a negative weight anywhere in the weight column and
+/// a non-empty frame whose weights sum to zero, run once with the metrics
+pub(crate) fn weight_check_exprs(weight_col: &str) -> [Expr; 2] {
+    let total = col(weight_col)
+        .cast(DataType::Float64)
+        .fill_null(lit(0.0))
+        .sum();
+    [
+        col(weight_col)
+            .lt(lit(0))
+            .any(true)
+            .alias(NEGATIVE_WEIGHTS_ALIAS),
+        total
+            .lt_eq(lit(0.0))
+            .and(len().gt(lit(0)))
+            .alias(ZERO_WEIGHTS_ALIAS),
+    ]
+}
+
+/// Fail on what `weight_check_exprs` found
+pub(crate) fn check_weights(df_metrics: &DataFrame, weight_col: &str) -> Result<()> {
+    let flagged = |alias: &str| -> Result<bool> {
+        Ok(df_metrics.column(alias)?.get(0)?.extract_bool() == Some(true))
+    };
+    if flagged(NEGATIVE_WEIGHTS_ALIAS)? {
+        return Err(DescribeError::NegativeWeights(weight_col.to_string()).into());
+    }
+    if flagged(ZERO_WEIGHTS_ALIAS)? {
+        return Err(DescribeError::ZeroWeights(weight_col.to_string()).into());
+    }
+    Ok(())
+}
+
+/// The non-null values of a column paired with their positive weights
+pub(crate) struct WeightedValues {
+    values: Expr,
+    weights: Expr,
+}
+
+impl WeightedValues {
+    pub(crate) fn new(values: Expr, weight_col: &str) -> Self {
+        let values = values.cast(DataType::Float64);
+        let weights = col(weight_col).cast(DataType::Float64).fill_null(lit(0.0));
+        let valid = values
+            .clone()
+            .is_not_null()
+            .and(weights.clone().gt(lit(0.0)));
+        Self {
+            values: values.filter(valid.clone()),
+            weights: weights.filter(valid),
+        }
+    }
+
+    fn total(&self) -> Expr {
+        self.weights.clone().sum()
+    }
+
+    /// `expr` when the total weight exceeds `min_total`, null otherwise
+    fn when_total_above(&self, min_total: f64, expr: Expr) -> Expr {
+        when(self.total().gt(lit(min_total)))
+            .then(expr)
+            .otherwise(lit(NULL).cast(DataType::Float64))
+    }
+
+    /// Σ w·x / Σ w
+    pub(crate) fn mean(&self) -> Expr {
+        let weighted_sum = (self.values.clone() * self.weights.clone()).sum();
+        self.when_total_above(0.0, weighted_sum / self.total())
+    }
+
+    /// Standard deviation treating weights as frequencies:
+    /// √(Σ w·(x − mean)² / (Σ w − 1)), which equals `std(1)` for unit weights
+    pub(crate) fn std(&self) -> Expr {
+        let diff = self.values.clone() - self.mean();
+        let squares = (self.weights.clone() * diff.clone() * diff).sum();
+        self.when_total_above(1.0, (squares / (self.total() - lit(1.0))).sqrt())
+    }
+
+    /// Smallest value whose cumulative weight reaches `p` of the total
+    pub(crate) fn quantile(&self, p: f64) -> Expr {
+        let sorted_values = self.values.clone().sort(SortOptions::default());
+        let cumulative = self
+            .weights
+            .clone()
+            .sort_by([self.values.clone()], SortMultipleOptions::default())
+            .cum_sum(false);
+        let reached = cumulative.gt_eq(lit(p) * self.total());
+        self.when_total_above(0.0, sorted_values.filter(reached).first())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    fn value(stats: &DataFrame, statistic: &str, column: &str) -> Result<f64> {
+        let idx = stats
+            .column("statistic")?
+            .str()?
+            .iter()
+            .position(|s| s == Some(statistic))
+            .unwrap();
+        Ok(stats.column(column)?.str()?.get(idx).unwrap().parse()?)
+    }
+
+    #[test]
+    fn test_describe_weighted_matches_hand_computation() -> Result<()> {
+        let df = df! {
+            "x" => [Some(1.0), Some(2.0), Some(3.0), Some(4.0), None],
+            "w" => [Some(1), Some(3), None, Some(4), Some(2)],
+            "label" => ["a", "b", "c", "d", "e"],
+        }?;
+
+        let stats = df.describe_weighted("w", None)?;
+        assert_eq!(stats.get_column_names(), ["statistic", "x", "label"]);
+
+        // Rows with a null weight or value drop out: (1, 1), (2, 3), (4, 4)
+        assert_eq!(value(&stats, "mean", "x")?, 23.0 / 8.0);
+        let std = (10.875_f64 / 7.0).sqrt();
+        assert!((value(&stats, "std", "x")? - std).abs() < 1e-5);
+        assert_eq!(value(&stats, "25%", "x")?, 2.0);
+        assert_eq!(value(&stats, "50%", "x")?, 2.0);
+        assert_eq!(value(&stats, "75%", "x")?, 4.0);
+        assert_eq!(value(&stats, "count", "x")?, 4.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_weighted_unit_weights_match_mean() -> Result<()> {
+        let df = df! { "x" => [1.0, 2.0, 6.0], "w" => [1, 1, 1] }?;
+        let weighted = df.describe_weighted("w", Some(vec![0.5]))?;
+        let plain = df.select(["x"])?.describe(Some(vec![0.5]))?;
+        for statistic in ["mean", "std", "50%"] {
+            assert!(
+                (value(&weighted, statistic, "x")? - value(&plain, statistic, "x")?).abs() < 1e-5
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_weighted_rejects_bad_weights() -> Result<()> {
+        let df = df! { "x" => [1.0, 2.0], "w" => [1, -1], "s" => ["a", "b"] }?;
+        let err = df.describe_weighted("w", None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::NegativeWeights("w".to_string()))
+        );
+        let err = df.clone().lazy().describe_weighted("w", None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::NegativeWeights("w".to_string()))
+        );
+
+        let df_zero = df! { "x" => [1.0, 2.0], "w" => [Some(0.0), None] }?;
+        let err = df_zero.describe_weighted("w", None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::ZeroWeights("w".to_string()))
+        );
+        let err = df_zero.lazy().describe_weighted("w", None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::ZeroWeights("w".to_string()))
+        );
+
+        let err = df.describe_weighted("missing", None).unwrap_err();
+        assert_eq!(
+            err.downcast_ref::<DescribeError>(),
+            Some(&DescribeError::ColumnNotFound("missing".to_string()))
+        );
+        let err = df.describe_weighted("s", None).unwrap_err();
+        assert!(matches!(
+            err.downcast_ref::<DescribeError>(),
+            Some(DescribeError::UnsupportedDtype { column, .. }) if column == "s"
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,139 @@
+//! Describe statistics per time window
+//!
+//! `describe_over()` runs the describe aggregations inside a lazy
+//! `group_by_dynamic`, so every window is described in the same pass, and
+//! stacks the per-window describe outputs behind a `window_start` column.
+
+use anyhow::{Context, Result};
+use polars::prelude::*;
+
+use crate::describe::{
+    Metrics, column_metric_exprs, metric_rows, render_grouped_metrics, resolved_percentiles,
+    select_columns_with_gap,
+};
+use crate::error::DescribeError;
+use crate::options::DescribeOptions;
+
+pub(crate) fn describe_over_impl(
+    lazy_frame: &LazyFrame,
+    time_col: &str,
+    every: Duration,
+    opts: &DescribeOptions,
+) -> Result<DataFrame> {
+    let full_schema = lazy_frame.clone().collect_schema()?;
+    let time_dtype = full_schema
+        .get(time_col)
+        .ok_or_else(|| DescribeError::ColumnNotFound(time_col.to_string()))?;
+    if !matches!(time_dtype, DataType::Date | DataType::Datetime(..)) {
+        return Err(DescribeError::UnsupportedDtype {
+            column: time_col.to_string(),
+            dtype: time_dtype.clone(),
+            expected: "a Date or Datetime column",
+        }
+        .into());
+    }
+
+    let (_, schema, mut gap) = select_columns_with_gap(lazy_frame, opts)?;
+    let mut described = (*schema).clone();
+    if described.index_of(time_col).is_some_and(|i| i < gap.head) {
+        gap.head -= 1;
+    }
+    described.shift_remove(time_col);
+    if described.is_empty() {
+        return Err(DescribeError::EmptySchema)
+            .with_context(|| format!("only the time column '{time_col}' to describe"));
+    }
+    let percentiles = resolved_percentiles(opts)?;
+
+    let exprs: Vec<Expr> = described
+        .iter()
+        .flat_map(|(name, dtype)| {
+            column_metric_exprs(col(name.clone()), name, dtype, &percentiles, opts)
+        })
+        .collect();
+    let options = DynamicGroupOptions {
+        every,
+        period: every,
+        offset: Duration::parse("0ns"),
+        ..Default::default()
+    };
+    let windows = opts.engine.collect(
+        lazy_frame
+            .clone()
+            .sort([time_col], SortMultipleOptions::default())
+            .group_by_dynamic(col(time_col), [], options)
+            .agg(exprs),
+    )?;
+
+    let template = Metrics {
+        schema: Arc::new(described),
+        rows: metric_rows(&percentiles, opts)?,
+        percentiles,
+        frame: DataFrame::empty(),
+        gap,
+    };
+    let windows = windows
+        .lazy()
+        .rename([time_col], ["window_start"], true)
+        .collect()?;
+    render_grouped_metrics(&windows, &["window_start"], &template, opts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Describable;
+
+    #[test]
+    fn test_describe_over_daily_means() -> Result<()> {
+        let hour = 3_600_000i64;
+        let df = df! {
+            "ts" => [0, 6 * hour, 12 * hour, 24 * hour, 30 * hour],
+            "value" => [Some(1.0), Some(2.0), Some(3.0), Some(10.0), None],
+            "label" => ["a", "b", "c", "d", "e"],
+        }?
+        .lazy()
+        .with_column(col("ts").cast(DataType::Datetime(TimeUnit::Milliseconds, None)))
+        .collect()?;
+
+        let stats = df.describe_over("ts", Duration::parse("1d"), None)?;
+        assert_eq!(
+            stats.get_column_names(),
+            ["window_start", "statistic", "value", "label"]
+        );
+        // Two windows of nine statistic rows each
+        assert_eq!(stats.height(), 18);
+
+        let row = |window: i64, statistic: &str| -> Result<DataFrame> {
+            Ok(stats
+                .clone()
+                .lazy()
+                .filter(
+                    col("window_start")
+                        .cast(DataType::Int64)
+                        .eq(lit(window))
+                        .and(col("statistic").eq(lit(statistic))),
+                )
+                .collect()?)
+        };
+        let value = |window: i64, statistic: &str| -> Result<String> {
+            let row = row(window, statistic)?;
+            Ok(row.column("value")?.str()?.get(0).unwrap().to_string())
+        };
+        assert_eq!(value(0, "mean")?, "2.0");
+        assert_eq!(value(0, "count")?, "3");
+        assert_eq!(value(24 * hour, "mean")?, "10.0");
+        assert_eq!(value(24 * hour, "null_count")?, "1");
+        Ok(())
+    }
+
+    #[test]
+    fn test_describe_over_rejects_non_temporal_time_column() -> Result<()> {
+        let df = df! { "t" => [1, 2], "x" => [1.0, 2.0] }?;
+        let err = df
+            .describe_over("t", Duration::parse("1d"), None)
+            .unwrap_err();
+        assert!(err.to_string().contains("must be a Date or Datetime"));
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Runs the `describe-df` binary against the fixture files
+#![cfg(feature = "cli")]
+
+use std::fs::File;
+use std::path::PathBuf;
+
+use assert_cmd::Command;
+use polars::prelude::*;
+
+use describe_df::{Describable, DescribeOptions};
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn describe_df() -> Command {
+    Command::cargo_bin("describe-df").unwrap()
+}
+
+fn stdout(command: &mut Command) -> String {
+    let output = command.assert().success().get_output().stdout.clone();
+    String::from_utf8(output).unwrap()
+}
+
+#[test]
+fn test_csv_output_matches_library() -> anyhow::Result<()> {
+    let out = stdout(describe_df().arg(fixture("sample.csv")).args([
+        "--output",
+        "csv",
+        "--percentiles",
+        "0.1,0.5,0.9",
+    ]));
+
+    let df = CsvReadOptions::default()
+        .try_into_reader_with_file_path(Some(fixture("sample.csv")))?
+        .finish()?;
+    let mut expected =
+        df.describe_with(&DescribeOptions::new().percentiles(vec![0.1, 0.5, 0.9]))?;
+    let mut buffer = Vec::new();
+    CsvWriter::new(&mut buffer).finish(&mut expected)?;
+    assert_eq!(out.trim_end(), String::from_utf8(buffer)?.trim_end());
+    Ok(())
+}
+
+#[test]
+fn test_table_output() {
+    let out = stdout(describe_df().arg(fixture("sample.csv")));
+    assert!(out.contains("statistic"), "{out}");
+    assert!(out.contains("null_count"), "{out}");
+    assert!(out.contains("2.5"), "{out}");
+}
+
+#[test]
+fn test_ndjson_json_output() -> anyhow::Result<()> {
+    let out = stdout(describe_df().arg(fixture("sample.ndjson")).args([
+        "--output",
+        "json",
+        "--columns",
+        "id,city",
+    ]));
+    let json: serde_json::Value = serde_json::from_str(&out)?;
+    assert_eq!(json["id"]["mean"], serde_json::json!(2.5));
+    assert_eq!(json["city"]["null_count"], serde_json::json!(1));
+    assert!(json.get("price").is_none());
+    Ok(())
+}
+
+#[test]
+fn test_parquet_with_explicit_format_and_markdown() -> anyhow::Result<()> {
+    let mut df = df! { "id" => [1, 2, 3, 4], "price" => [10.5, 2.0, 7.25, 3.0] }?;
+    let path = std::env::temp_dir().join(format!("describe_df_cli_{}.data", std::process::id()));
+    ParquetWriter::new(File::create(&path)?).finish(&mut df)?;
+
+    let out = stdout(describe_df().arg(&path).args([
+        "--format",
+        "parquet",
+        "--output",
+        "markdown",
+        "--columns",
+        "price",
+    ]));
+    std::fs::remove_file(&path)?;
+    assert!(out.starts_with("| statistic | price |"), "{out}");
+    assert!(!out.contains("| id"), "{out}");
+    Ok(())
+}
+
+#[test]
+fn test_transposed_csv() {
+    let out = stdout(describe_df().arg(fixture("sample.csv")).args([
+        "--output",
+        "csv",
+        "--transpose",
+        "--percentiles",
+        "0.5",
+    ]));
+    let mut lines = out.lines();
+    assert_eq!(
+        lines.next(),
+        Some("column,count,null_count,mean,std,min,50%,max")
+    );
+    assert!(lines.next().unwrap().starts_with("id,4,0,2.5,"));
+    assert_eq!(lines.count(), 2);
+}
+
+#[test]
+fn test_snake_labels() {
+    let out = stdout(describe_df().arg(fixture("sample.csv")).args([
+        "--output",
+        "csv",
+        "--transpose",
+        "--labels",
+        "snake",
+        "--percentiles",
+        "0.5,0.999",
+    ]));
+    assert_eq!(
+        out.lines().next(),
+        Some("column,count,null_count,mean,std,min,p50,p99_9,max")
+    );
+}
+
+#[test]
+fn test_missing_file_fails_with_path() {
+    let path = fixture("does_not_exist.csv");
+    describe_df()
+        .arg(&path)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(path.display().to_string()));
+}
+
+#[test]
+fn test_bad_percentiles_fail() {
+    describe_df()
+        .arg(fixture("sample.csv"))
+        .args(["--percentiles", "0.1,half"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("half"));
+    describe_df()
+        .arg(fixture("sample.csv"))
+        .args(["--percentiles", "1.5"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("outside the range"));
+}